@@ -143,6 +143,7 @@ fn bench_cache_performance(c: &mut Criterion) {
                 long_functions: vec![],
                 import_count: 5,
                 function_count: 10,
+                import_sources: vec![],
             },
         );
     }
@@ -3,12 +3,15 @@
 //! This module provides the AnalysisResult struct that collects all analysis
 //! data needed for scoring and reporting.
 
+use crate::analyzer::dead_rules::ImportSources;
 use crate::autofix::Violation;
+use crate::blame::BlameInfo;
 use crate::circular::CircularDependency;
 use crate::config::ArchPattern;
 use crate::metrics::{ComplexityStats, HealthScore, LayerStats};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 /// Category of a violation for severity classification
@@ -41,6 +44,15 @@ pub struct CategorizedViolation {
     pub category: ViolationCategory,
     /// Optional suggestion for fixing
     pub suggestion: Option<String>,
+    /// Security severity score (0.0-10.0) when the violation comes from the security audit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_severity: Option<f64>,
+    /// Last commit that touched the offending line (filled by `--blame`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blame: Option<BlameInfo>,
+    /// Owners of the file according to CODEOWNERS
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
 }
 
 impl CategorizedViolation {
@@ -49,8 +61,22 @@ impl CategorizedViolation {
             violation,
             category,
             suggestion: None,
+            security_severity: None,
+            blame: None,
+            owners: Vec::new(),
         }
     }
+
+    /// Mark this violation as a security finding with the given score
+    pub fn with_security_severity(mut self, score: f64) -> Self {
+        self.security_severity = Some(score);
+        self
+    }
+
+    /// Whether this violation was reported by the security audit
+    pub fn is_security(&self) -> bool {
+        self.security_severity.is_some()
+    }
 }
 
 /// Long function detected during complexity analysis
@@ -91,6 +117,12 @@ pub struct AnalysisResult {
     pub complexity_stats: ComplexityStats,
     /// Computed health score (set after calculation)
     pub health_score: Option<HealthScore>,
+    /// Generated or vendored files (skipped or downweighted, see `generated_code`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generated_files: Vec<PathBuf>,
+    /// Import sources of each analyzed file, for dead-rule detection
+    #[serde(skip)]
+    pub import_sources: ImportSources,
 }
 
 impl AnalysisResult {
@@ -107,6 +139,8 @@ impl AnalysisResult {
             layer_stats: LayerStats::default(),
             complexity_stats: ComplexityStats::default(),
             health_score: None,
+            generated_files: Vec::new(),
+            import_sources: ImportSources::new(),
         }
     }
 
@@ -175,6 +209,26 @@ impl AnalysisResult {
         });
     }
 
+    /// Violations grouped by owning team (CODEOWNERS).
+    ///
+    /// A violation in a file with several owners appears under each of them;
+    /// files without owners are grouped under `(unowned)`.
+    pub fn violations_by_team(&self) -> BTreeMap<&str, Vec<&CategorizedViolation>> {
+        let mut groups: BTreeMap<&str, Vec<&CategorizedViolation>> = BTreeMap::new();
+        for cv in &self.violations {
+            if cv.owners.is_empty() {
+                groups
+                    .entry(crate::codeowners::UNOWNED)
+                    .or_default()
+                    .push(cv);
+            }
+            for owner in &cv.owners {
+                groups.entry(owner.as_str()).or_default().push(cv);
+            }
+        }
+        groups
+    }
+
     /// Get pattern as display string
     pub fn pattern_display(&self) -> &str {
         match &self.pattern {
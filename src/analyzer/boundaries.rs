@@ -0,0 +1,295 @@
+//! Module boundaries
+//!
+//! A directory listed in `modules` is only reachable from outside through its
+//! public entry point (`index.{ts,tsx,js,jsx,mjs,cjs}` or `__init__.py` at the
+//! module root) and the files listed in `exports`. Importing any other file of
+//! the module from outside it is a violation; files inside the module can
+//! import each other freely.
+
+use crate::autofix::Violation;
+use crate::config::{ForbiddenRule, LinterContext, ModuleBoundary, Severity};
+use crate::gitpattern::GitPattern;
+use crate::parsers::resolve::resolve_import;
+use crate::parsers::Import;
+use std::path::{Path, PathBuf};
+
+/// Entry points that are always public
+const DEFAULT_ENTRIES: [&str; 7] = [
+    "index.ts",
+    "index.tsx",
+    "index.js",
+    "index.jsx",
+    "index.mjs",
+    "index.cjs",
+    "__init__.py",
+];
+
+/// A configured module with its patterns compiled
+#[derive(Debug, Clone)]
+struct CompiledModule {
+    config: ModuleBoundary,
+    path: GitPattern,
+    exports: Vec<GitPattern>,
+}
+
+/// The `modules` section of architect.json, ready to be evaluated
+#[derive(Debug, Clone)]
+pub struct ModuleBoundaries {
+    /// Directory module paths are relative to
+    pub root: PathBuf,
+    modules: Vec<CompiledModule>,
+}
+
+/// The module a file belongs to
+struct ModuleMatch<'a> {
+    module: &'a CompiledModule,
+    /// Module directory, relative to the root
+    dir: String,
+    /// File path relative to the module directory (empty for the directory itself)
+    inner: String,
+}
+
+impl ModuleBoundaries {
+    /// Compile the configured modules. None when there are none.
+    pub fn new(root: &Path, modules: &[ModuleBoundary]) -> Option<Self> {
+        let modules: Vec<CompiledModule> = modules
+            .iter()
+            .filter_map(|config| {
+                let path =
+                    GitPattern::parse(&format!("/{}", config.path.trim().trim_matches('/')))?;
+                let exports = config
+                    .exports
+                    .iter()
+                    .filter_map(|export| {
+                        GitPattern::parse(&format!("/{}", export.trim().trim_start_matches("./")))
+                    })
+                    .collect();
+                Some(CompiledModule {
+                    config: config.clone(),
+                    path,
+                    exports,
+                })
+            })
+            .collect();
+        if modules.is_empty() {
+            return None;
+        }
+        Some(Self {
+            root: root.canonicalize().unwrap_or_else(|_| root.to_path_buf()),
+            modules,
+        })
+    }
+
+    /// The configured modules
+    pub fn modules(&self) -> impl Iterator<Item = &ModuleBoundary> {
+        self.modules.iter().map(|m| &m.config)
+    }
+
+    /// `path` relative to the root, `/`-separated
+    fn relative(&self, path: &Path) -> Option<String> {
+        let rel = match path.strip_prefix(&self.root) {
+            Ok(rel) => rel.to_path_buf(),
+            Err(_) => path
+                .canonicalize()
+                .ok()?
+                .strip_prefix(&self.root)
+                .ok()?
+                .to_path_buf(),
+        };
+        Some(rel.to_string_lossy().replace('\\', "/"))
+    }
+
+    /// Every module containing `rel` (outermost first)
+    fn modules_of(&self, rel: &str) -> Vec<ModuleMatch<'_>> {
+        let parts: Vec<&str> = rel.split('/').filter(|s| !s.is_empty()).collect();
+        let mut found = Vec::new();
+        for n in 1..=parts.len() {
+            let dir = parts[..n].join("/");
+            for module in &self.modules {
+                if module.path.matches(&dir, true) {
+                    found.push(ModuleMatch {
+                        module,
+                        dir,
+                        inner: parts[n..].join("/"),
+                    });
+                    break;
+                }
+            }
+        }
+        found
+    }
+
+    /// Whether `inner` (relative to the module directory) is part of the module's public API
+    fn is_public(module: &CompiledModule, inner: &str) -> bool {
+        if inner.is_empty() || DEFAULT_ENTRIES.contains(&inner) {
+            return true;
+        }
+        module
+            .exports
+            .iter()
+            .any(|export| export.matches_path_or_parent(inner, false))
+    }
+
+    /// Check the imports of a file against the module boundaries
+    pub fn find_violations(
+        &self,
+        file_path: &Path,
+        source_code: &str,
+        imports: &[Import],
+    ) -> Vec<Violation> {
+        let Some(file_rel) = self.relative(file_path) else {
+            return Vec::new();
+        };
+
+        let mut violations = Vec::new();
+        for import in imports {
+            let Some(target) = resolve_import(file_path, &import.source, &self.root) else {
+                continue;
+            };
+            let Some(target_rel) = self.relative(&target) else {
+                continue;
+            };
+
+            let breached = self.modules_of(&target_rel).into_iter().find(|m| {
+                let inside = file_rel
+                    .strip_prefix(&m.dir)
+                    .is_some_and(|rest| rest.starts_with('/'));
+                !inside && !Self::is_public(m.module, &m.inner)
+            });
+            if let Some(breached) = breached {
+                violations.push(Violation {
+                    file_path: file_path.to_path_buf(),
+                    file_content: source_code.to_string(),
+                    offensive_import: import.raw_statement.clone(),
+                    rule: boundary_rule(breached.module, &breached.dir),
+                    line_number: import.line_number,
+                });
+            }
+        }
+        violations
+    }
+}
+
+/// The rule reported for an import that bypasses the entry point of `dir`
+fn boundary_rule(module: &CompiledModule, dir: &str) -> ForbiddenRule {
+    let mut entries = "index / __init__.py".to_string();
+    for export in &module.config.exports {
+        entries.push_str(", ");
+        entries.push_str(export);
+    }
+    ForbiddenRule {
+        from: format!("!{}/", dir),
+        to: format!("{}/**", dir),
+        severity: Some(module.config.severity.unwrap_or(Severity::Error)),
+        reason: Some(module.config.reason.clone().unwrap_or_else(|| {
+            format!(
+                "Deep import into module '{}': import it through its public entry point ({})",
+                dir, entries
+            )
+        })),
+    }
+}
+
+/// Check the imports of a file against the module boundaries of `ctx`
+pub fn find_boundary_violations(
+    file_path: &Path,
+    source_code: &str,
+    imports: &[Import],
+    ctx: &LinterContext,
+) -> Vec<Violation> {
+    match ctx.boundaries.as_ref() {
+        Some(boundaries) => boundaries.find_violations(file_path, source_code, imports),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn import(source: &str) -> Import {
+        Import {
+            source: source.to_string(),
+            line_number: 1,
+            raw_statement: format!("import x from '{}';", source),
+        }
+    }
+
+    #[test]
+    fn test_deep_imports_are_violations() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        for file in [
+            "src/modules/billing/index.ts",
+            "src/modules/billing/invoice.ts",
+            "src/modules/users/user.ts",
+        ] {
+            std::fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            std::fs::write(root.join(file), "").unwrap();
+        }
+        let boundaries = ModuleBoundaries::new(
+            &root,
+            &[ModuleBoundary {
+                path: "src/modules/*".to_string(),
+                ..Default::default()
+            }],
+        )
+        .unwrap();
+
+        let outside = root.join("src/modules/users/user.ts");
+        let found = boundaries.find_violations(
+            &outside,
+            "",
+            &[
+                import("../billing"),
+                import("../billing/index"),
+                import("../billing/invoice"),
+            ],
+        );
+        assert_eq!(found.len(), 1);
+        assert!(found[0].offensive_import.contains("../billing/invoice"));
+        assert_eq!(found[0].rule.to, "src/modules/billing/**");
+
+        // Files inside the module may import each other
+        let inside = root.join("src/modules/billing/index.ts");
+        assert!(boundaries
+            .find_violations(&inside, "", &[import("./invoice")])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_explicit_exports_extend_default_entry() {
+        let boundaries = ModuleBoundaries::new(
+            Path::new("/repo"),
+            &[ModuleBoundary {
+                path: "app/billing".to_string(),
+                exports: vec!["api.py".to_string(), "public/".to_string()],
+                ..Default::default()
+            }],
+        )
+        .unwrap();
+        let module = &boundaries.modules[0];
+
+        assert!(ModuleBoundaries::is_public(module, "api.py"));
+        assert!(ModuleBoundaries::is_public(module, "public/schemas.py"));
+        assert!(ModuleBoundaries::is_public(module, "__init__.py"));
+        assert!(!ModuleBoundaries::is_public(module, "models.py"));
+    }
+
+    #[test]
+    fn test_trailing_double_star_module_excludes_its_parent() {
+        let boundaries = ModuleBoundaries::new(
+            Path::new("/repo"),
+            &[ModuleBoundary {
+                path: "src/features/**".to_string(),
+                ..Default::default()
+            }],
+        )
+        .unwrap();
+
+        let found = boundaries.modules_of("src/features/cart/api.ts");
+        assert_eq!(found[0].dir, "src/features/cart");
+        assert_eq!(found[0].inner, "api.ts");
+    }
+}
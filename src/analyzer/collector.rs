@@ -2,24 +2,40 @@
 
 use crate::analysis_result::{AnalysisResult, CategorizedViolation, ViolationCategory};
 use crate::cache::{self, AnalysisCache, FileCacheEntry};
-use crate::config::{ArchPattern, LinterContext};
+use crate::config::{ArchPattern, GeneratedCodeMode, LinterContext};
 use crate::metrics::ComplexityStats;
 use indicatif::{ProgressBar, ProgressStyle};
 use miette::Result;
 use rayon::prelude::*;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+use super::dead_rules::file_import_sources;
+use super::generated::{GeneratedCodeDetector, GeneratedKind};
 use super::metrics::{count_functions, count_imports, find_long_functions};
-use super::swc_parser::collect_violations_from_file;
+use super::swc_parser::collect_findings_from_file;
 
 /// Result of analyzing a single file
-struct FileAnalysis {
-    violations: Vec<CategorizedViolation>,
-    long_functions: Vec<crate::analysis_result::LongFunction>,
-    import_count: usize,
-    function_count: usize,
+#[derive(Debug, Clone, Default)]
+pub struct FileAnalysis {
+    pub violations: Vec<CategorizedViolation>,
+    pub long_functions: Vec<crate::analysis_result::LongFunction>,
+    pub import_count: usize,
+    pub function_count: usize,
+    /// Sources of the file's imports, for dead-rule detection. Only collected
+    /// when there are `forbidden_imports`
+    pub import_sources: Vec<String>,
+    /// Set when the file is generated or vendored code
+    pub generated: Option<GeneratedKind>,
+}
+
+impl FileAnalysis {
+    /// Generated code left out of the analysis (`generated_code.mode = skip`)
+    pub fn is_skipped(&self, ctx: &LinterContext) -> bool {
+        self.generated.is_some() && ctx.generated_code.mode == GeneratedCodeMode::Skip
+    }
 }
 
 /// Analyzes all files and returns a complete AnalysisResult for scoring.
@@ -29,11 +45,29 @@ pub fn analyze_all_files(
     project_root: &Path,
     pattern: ArchPattern,
     ctx: &LinterContext,
-    mut analysis_cache: Option<&mut AnalysisCache>,
+    analysis_cache: Option<&mut AnalysisCache>,
 ) -> Result<AnalysisResult> {
-    tracing::info!("Starting file analysis for {} files", files.len());
-    tracing::debug!("Project root: {}", project_root.display());
+    let mut result = empty_result(files, project_root, pattern, ctx);
+
+    // Merge all file results into the main result
+    for (file_path, file_analysis) in analyze_files(files, project_root, ctx, analysis_cache) {
+        merge_file_analysis(&mut result, ctx, file_path, file_analysis);
+    }
+
+    // Update blocked_violations count
+    result.layer_stats.blocked_violations = result.blocked_count();
 
+    Ok(result)
+}
+
+/// Result for `files` before any file is merged in: project name, file
+/// count and thresholds
+pub fn empty_result(
+    files: &[PathBuf],
+    project_root: &Path,
+    pattern: ArchPattern,
+    ctx: &LinterContext,
+) -> AnalysisResult {
     // Get project name from directory
     let project_name = project_root
         .file_name()
@@ -50,6 +84,19 @@ pub fn analyze_all_files(
         long_functions: 0,
         max_lines_threshold: ctx.max_lines,
     };
+    result
+}
+
+/// Analyzes files in parallel and returns the analysis of each readable file.
+/// When a cache is provided, unchanged files are served from cache.
+pub fn analyze_files(
+    files: &[PathBuf],
+    project_root: &Path,
+    ctx: &LinterContext,
+    mut analysis_cache: Option<&mut AnalysisCache>,
+) -> Vec<(PathBuf, FileAnalysis)> {
+    tracing::info!("Starting file analysis for {} files", files.len());
+    tracing::debug!("Project root: {}", project_root.display());
 
     // Initialize progress bar
     let pb = if files.len() > 10 {
@@ -76,88 +123,25 @@ pub fn analyze_all_files(
         .as_mut()
         .map(|cache| Mutex::new((**cache).clone()));
 
+    let detector = (ctx.generated_code.mode != GeneratedCodeMode::Analyze)
+        .then(|| GeneratedCodeDetector::new(&ctx.generated_code));
+
     // Process files in parallel
-    let file_results: Vec<(PathBuf, Option<String>, FileAnalysis)> = files
+    let file_results: Vec<(PathBuf, FileAnalysis)> = files
         .par_iter()
         .filter_map(|file_path| {
             // Update progress bar
             if let Some(ref p) = pb {
                 p.inc(1);
             }
-
-            // Read file content for hashing
-            let file_bytes = match fs::read(file_path) {
-                Ok(b) => b,
-                Err(_) => return None,
-            };
-            let content_hash = cache::hash_content(&file_bytes);
-            let cache_key = AnalysisCache::normalize_path(file_path, project_root);
-
-            // Try to get from cache (thread-safe)
-            if let Some(ref mutex) = cache_mutex {
-                if let Ok(guard) = mutex.lock() {
-                    if let Some(entry) = guard.get(&cache_key, &content_hash) {
-                        // Cache hit — use stored data
-                        return Some((
-                            file_path.clone(),
-                            Some(cache_key),
-                            FileAnalysis {
-                                violations: entry.violations.clone(),
-                                long_functions: entry.long_functions.clone(),
-                                import_count: entry.import_count,
-                                function_count: entry.function_count,
-                            },
-                        ));
-                    }
-                }
-            }
-
-            // Cache miss — run full analysis
-            let mut file_violations = Vec::new();
-            if let Ok(violations) = collect_violations_from_file(file_path, ctx) {
-                for violation in violations {
-                    let category = match violation.rule.get_severity() {
-                        crate::config::Severity::Error => ViolationCategory::Blocked,
-                        crate::config::Severity::Warning => ViolationCategory::Warning,
-                        crate::config::Severity::Info => ViolationCategory::Info,
-                    };
-                    let categorized = CategorizedViolation::new(violation, category);
-                    file_violations.push(categorized);
-                }
-            }
-
-            let mut file_long_functions = Vec::new();
-            if let Ok(long_funcs) = find_long_functions(file_path, ctx.max_lines) {
-                file_long_functions = long_funcs;
-            }
-
-            let import_count = count_imports(file_path).unwrap_or(0);
-            let function_count = count_functions(file_path).unwrap_or(0);
-
-            let analysis = FileAnalysis {
-                violations: file_violations.clone(),
-                long_functions: file_long_functions.clone(),
-                import_count,
-                function_count,
-            };
-
-            // Store in cache (thread-safe)
-            if let Some(ref mutex) = cache_mutex {
-                if let Ok(mut guard) = mutex.lock() {
-                    guard.insert(
-                        cache_key.clone(),
-                        FileCacheEntry {
-                            content_hash,
-                            violations: file_violations,
-                            long_functions: file_long_functions,
-                            import_count,
-                            function_count,
-                        },
-                    );
-                }
-            }
-
-            Some((file_path.clone(), None, analysis))
+            analyze_file(
+                file_path,
+                project_root,
+                ctx,
+                detector.as_ref(),
+                cache_mutex.as_ref(),
+            )
+            .map(|analysis| (file_path.clone(), analysis))
         })
         .collect();
 
@@ -180,20 +164,214 @@ pub fn analyze_all_files(
         }
     }
 
-    // Merge all file results into the main result
-    for (_file_path, _cache_key, file_analysis) in file_results {
-        for cv in file_analysis.violations {
-            result.add_violation(cv);
+    file_results
+}
+
+/// Analyze one file. Unchanged files are served from the cache and
+/// generated code is skipped before parsing when `generated_code.mode`
+/// says so. Returns None when the file cannot be read.
+pub fn analyze_file(
+    file_path: &Path,
+    project_root: &Path,
+    ctx: &LinterContext,
+    detector: Option<&GeneratedCodeDetector>,
+    cache_mutex: Option<&Mutex<AnalysisCache>>,
+) -> Option<FileAnalysis> {
+    // Read file content for hashing
+    let file_bytes = fs::read(file_path).ok()?;
+    let content_hash = cache::hash_content(&file_bytes);
+    let cache_key = AnalysisCache::normalize_path(file_path, project_root);
+
+    // Generated and vendored code is skipped before any parsing
+    let generated = detector.and_then(|d| d.detect(&cache_key, &file_bytes));
+    if generated.is_some() && ctx.generated_code.mode == GeneratedCodeMode::Skip {
+        return Some(FileAnalysis {
+            generated,
+            ..Default::default()
+        });
+    }
+
+    // Try to get from cache (thread-safe)
+    if let Some(mutex) = cache_mutex {
+        if let Ok(guard) = mutex.lock() {
+            if let Some(entry) = guard.get(&cache_key, &content_hash) {
+                // Cache hit — use stored data
+                return Some(FileAnalysis {
+                    violations: entry.violations.clone(),
+                    long_functions: entry.long_functions.clone(),
+                    import_count: entry.import_count,
+                    function_count: entry.function_count,
+                    import_sources: entry.import_sources.clone(),
+                    generated,
+                });
+            }
+        }
+    }
+
+    // Cache miss — run full analysis
+    let mut file_violations = Vec::new();
+    if let Ok((violations, security_findings)) = collect_findings_from_file(file_path, ctx) {
+        for violation in violations {
+            file_violations.push(categorize(violation));
         }
-        for func in file_analysis.long_functions {
-            result.add_long_function(func);
+        for finding in security_findings {
+            let score = finding.security_severity();
+            file_violations.push(categorize(finding.violation).with_security_severity(score));
         }
-        result.layer_stats.total_imports += file_analysis.import_count;
-        result.complexity_stats.total_functions += file_analysis.function_count;
     }
 
-    // Update blocked_violations count
+    let mut file_long_functions = Vec::new();
+    if let Ok(long_funcs) = find_long_functions(file_path, ctx.max_lines) {
+        file_long_functions = long_funcs;
+    }
+
+    let import_count = count_imports(file_path).unwrap_or(0);
+    let function_count = count_functions(file_path).unwrap_or(0);
+    let import_sources = if ctx.forbidden_imports.is_empty() {
+        Vec::new()
+    } else {
+        file_import_sources(file_path, &String::from_utf8_lossy(&file_bytes))
+    };
+
+    let analysis = FileAnalysis {
+        violations: file_violations.clone(),
+        long_functions: file_long_functions.clone(),
+        import_count,
+        function_count,
+        import_sources: import_sources.clone(),
+        generated,
+    };
+
+    // Store in cache (thread-safe)
+    if let Some(mutex) = cache_mutex {
+        if let Ok(mut guard) = mutex.lock() {
+            guard.insert(
+                cache_key,
+                FileCacheEntry {
+                    content_hash,
+                    violations: file_violations,
+                    long_functions: file_long_functions,
+                    import_count,
+                    function_count,
+                    import_sources,
+                },
+            );
+        }
+    }
+
+    Some(analysis)
+}
+
+/// `files` without the generated code the analysis skipped, for the steps
+/// that work on the whole project afterwards (cycles, dead rules)
+pub fn without_skipped(
+    files: &[PathBuf],
+    result: &AnalysisResult,
+    ctx: &LinterContext,
+) -> Vec<PathBuf> {
+    if ctx.generated_code.mode != GeneratedCodeMode::Skip {
+        return files.to_vec();
+    }
+    let skipped: HashSet<&PathBuf> = result.generated_files.iter().collect();
+    files
+        .iter()
+        .filter(|file| !skipped.contains(file))
+        .cloned()
+        .collect()
+}
+
+/// Add the findings and stats of one file to `result`. The file is assumed
+/// to be counted in `files_analyzed` already; skipped generated code is not.
+pub fn merge_file_analysis(
+    result: &mut AnalysisResult,
+    ctx: &LinterContext,
+    file_path: PathBuf,
+    file_analysis: FileAnalysis,
+) {
+    let owners_of = |cv: &mut CategorizedViolation| {
+        // Owning teams from CODEOWNERS, for grouping in dashboards and reports
+        if let Some(ref owners) = ctx.codeowners {
+            cv.owners = owners.owners_of(&cv.violation.file_path).to_vec();
+        }
+    };
+    if !file_analysis.import_sources.is_empty() {
+        result
+            .import_sources
+            .insert(file_path.clone(), file_analysis.import_sources);
+    }
+    if let Some(ref kind) = file_analysis.generated {
+        tracing::debug!("Generated code ({}): {}", kind, file_path.display());
+        result.generated_files.push(file_path);
+        if ctx.generated_code.mode == GeneratedCodeMode::Skip {
+            result.files_analyzed -= 1;
+            return;
+        }
+        // Downweighted: findings are only informative and the file does
+        // not count towards complexity or coupling
+        for mut cv in file_analysis.violations {
+            cv.category = ViolationCategory::Info;
+            owners_of(&mut cv);
+            result.add_violation(cv);
+        }
+        return;
+    }
+    for mut cv in file_analysis.violations {
+        owners_of(&mut cv);
+        result.add_violation(cv);
+    }
+    for func in file_analysis.long_functions {
+        result.add_long_function(func);
+    }
+    result.layer_stats.total_imports += file_analysis.import_count;
+    result.complexity_stats.total_functions += file_analysis.function_count;
+}
+
+/// Remove what `merge_file_analysis` added for `file_path`
+pub fn unmerge_file_analysis(
+    result: &mut AnalysisResult,
+    ctx: &LinterContext,
+    file_path: &Path,
+    file_analysis: &FileAnalysis,
+) {
+    result
+        .violations
+        .retain(|cv| cv.violation.file_path != file_path);
     result.layer_stats.blocked_violations = result.blocked_count();
+    result.import_sources.remove(file_path);
+    if file_analysis.generated.is_some() {
+        result.generated_files.retain(|f| f != file_path);
+        if ctx.generated_code.mode == GeneratedCodeMode::Skip {
+            result.files_analyzed += 1;
+        }
+        return;
+    }
+    result.long_functions.retain(|lf| lf.file_path != file_path);
+    result.complexity_stats.long_functions = result.long_functions.len();
+    result.layer_stats.total_imports -= file_analysis.import_count;
+    result.complexity_stats.total_functions -= file_analysis.function_count;
+}
 
+/// Full analysis of a project directory: discovery, rules, cycles and health score.
+/// Used to analyze snapshots of past commits (backfill, diff mode).
+pub fn analyze_project(project_root: &Path, ctx: &LinterContext) -> Result<AnalysisResult> {
+    let files = crate::discovery::collect_files(project_root, &ctx.ignored_paths);
+    let mut result = analyze_all_files(&files, project_root, ctx.pattern.clone(), ctx, None)?;
+    let files = without_skipped(&files, &result, ctx);
+    if let Ok(cycles) = crate::circular::analyze_circular_dependencies(&files, project_root) {
+        for cycle in cycles {
+            result.add_circular_dependency(cycle);
+        }
+    }
+    result.health_score = Some(crate::scoring::calculate(&result));
     Ok(result)
 }
+
+/// Map a raw violation to its category based on the rule severity
+fn categorize(violation: crate::autofix::Violation) -> CategorizedViolation {
+    let category = match violation.rule.get_severity() {
+        crate::config::Severity::Error => ViolationCategory::Blocked,
+        crate::config::Severity::Warning => ViolationCategory::Warning,
+        crate::config::Severity::Info => ViolationCategory::Info,
+    };
+    CategorizedViolation::new(violation, category)
+}
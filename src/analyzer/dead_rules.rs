@@ -0,0 +1,219 @@
+//! Dead rules
+//!
+//! A `forbidden_imports` rule is dead when it can never fire: its `from`
+//! matches none of the collected files, or its `to` matches neither a file
+//! nor the source of any import in the project. Typos in rule paths would
+//! otherwise fail silently. Each file and import is matched with the matcher
+//! of its file's parser, the same one the analysis uses, so a rule reported
+//! here is a rule the analysis ignores.
+
+use crate::codeowners::is_owner_rule;
+use crate::config::ForbiddenRule;
+use crate::parsers::{self, Language};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Import sources of each file, as collected by the analysis
+pub type ImportSources = HashMap<PathBuf, Vec<String>>;
+
+/// The side of a rule that matches nothing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadSide {
+    /// `from` matches no file
+    From,
+    /// `to` matches no file and no import
+    To,
+}
+
+impl DeadSide {
+    pub fn key(&self) -> &'static str {
+        match self {
+            DeadSide::From => "from",
+            DeadSide::To => "to",
+        }
+    }
+}
+
+/// A rule that can never fire
+#[derive(Debug, Clone)]
+pub struct DeadRule {
+    /// Position of the rule in the list it was checked from
+    pub index: usize,
+    pub rule: ForbiddenRule,
+    pub side: DeadSide,
+}
+
+impl DeadRule {
+    /// Pattern on the dead side
+    pub fn pattern(&self) -> &str {
+        match self.side {
+            DeadSide::From => &self.rule.from,
+            DeadSide::To => &self.rule.to,
+        }
+    }
+}
+
+/// Rules of `rules` that can never fire against `files`. Owner rules
+/// (`owner:@team`) are resolved through CODEOWNERS and are not checked.
+/// `imports` are the import sources the analysis already collected; without
+/// them the files are parsed when some `to` matches no file.
+pub fn find_dead_rules(
+    rules: &[ForbiddenRule],
+    files: &[PathBuf],
+    imports: Option<&ImportSources>,
+) -> Vec<DeadRule> {
+    let paths: Vec<(Language, String)> = files
+        .iter()
+        .map(|f| (language_of(f), f.to_string_lossy().to_string()))
+        .collect();
+    let matches_file = |pattern: &str| {
+        paths
+            .iter()
+            .any(|(language, p)| language.matches_pattern(p, pattern))
+    };
+
+    let mut dead = Vec::new();
+    let mut unmatched_to = Vec::new();
+    for (index, rule) in rules.iter().enumerate() {
+        if is_owner_rule(rule) {
+            continue;
+        }
+        if !matches_file(&rule.from) {
+            dead.push(DeadRule {
+                index,
+                rule: rule.clone(),
+                side: DeadSide::From,
+            });
+        } else if !matches_file(&rule.to) {
+            unmatched_to.push((index, rule));
+        }
+    }
+
+    // Package targets (`to: "axios"`) only show up in imports, so the files
+    // are parsed only when some `to` matches no file
+    if !unmatched_to.is_empty() {
+        let sources = match imports {
+            Some(imports) => imports
+                .iter()
+                .flat_map(|(file, sources)| {
+                    let language = language_of(file);
+                    sources.iter().map(move |s| (language, s.clone()))
+                })
+                .collect(),
+            None => import_sources(files),
+        };
+        for (index, rule) in unmatched_to {
+            if !sources
+                .iter()
+                .any(|(language, s)| language.matches_pattern(s, &rule.to))
+            {
+                dead.push(DeadRule {
+                    index,
+                    rule: rule.clone(),
+                    side: DeadSide::To,
+                });
+            }
+        }
+    }
+
+    dead.sort_by_key(|d| d.index);
+    dead
+}
+
+/// Language whose matcher applies to `file`. Files without a parser fall
+/// back to the TypeScript matcher.
+fn language_of(file: &Path) -> Language {
+    file.extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(Language::from_extension)
+        .unwrap_or(Language::TypeScript)
+}
+
+/// Distinct import sources of all files, with the language of the importing file
+fn import_sources(files: &[PathBuf]) -> HashSet<(Language, String)> {
+    files
+        .par_iter()
+        .flat_map_iter(|file| {
+            let language = language_of(file);
+            let content = fs::read_to_string(file).unwrap_or_default();
+            file_import_sources(file, &content)
+                .into_iter()
+                .map(move |source| (language, source))
+        })
+        .collect()
+}
+
+/// Sources of the imports in `content`, empty when `file` has no parser
+pub fn file_import_sources(file: &Path, content: &str) -> Vec<String> {
+    parsers::get_parser_for_file(file)
+        .and_then(|parser| parser.extract_imports(content, file).ok())
+        .map(|imports| imports.into_iter().map(|import| import.source).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn rule(from: &str, to: &str) -> ForbiddenRule {
+        ForbiddenRule {
+            from: from.to_string(),
+            to: to.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_finds_rules_that_match_nothing() {
+        let dir = TempDir::new().unwrap();
+        let domain = dir.path().join("src/domain/user.ts");
+        let infra = dir.path().join("src/infra/db.ts");
+        fs::create_dir_all(domain.parent().unwrap()).unwrap();
+        fs::create_dir_all(infra.parent().unwrap()).unwrap();
+        fs::write(&domain, "import axios from 'axios';\n").unwrap();
+        fs::write(&infra, "export const db = 1;\n").unwrap();
+        let files = vec![domain, infra];
+
+        let rules = vec![
+            rule("src/domain/", "src/infra/"),
+            rule("src/domian/", "src/infra/"),
+            rule("src/domain/", "axios"),
+            rule("src/domain/", "src/infrastructure/"),
+        ];
+        let dead = find_dead_rules(&rules, &files, None);
+        let found: Vec<(usize, DeadSide)> = dead.iter().map(|d| (d.index, d.side)).collect();
+        assert_eq!(found, [(1, DeadSide::From), (3, DeadSide::To)]);
+        assert_eq!(dead[1].pattern(), "src/infrastructure/");
+    }
+
+    #[test]
+    fn test_matches_each_file_with_its_parser() {
+        let dir = TempDir::new().unwrap();
+        let views = dir.path().join("apps/views.py");
+        fs::create_dir_all(views.parent().unwrap()).unwrap();
+        fs::write(&views, "from apps.models import User\n").unwrap();
+
+        // Dotted module paths only match with the Python matcher
+        let rules = vec![
+            rule("apps.views", "apps.models"),
+            rule("apps.views", "django.forms"),
+        ];
+        let dead = find_dead_rules(&rules, &[views], None);
+        let found: Vec<(usize, DeadSide)> = dead.iter().map(|d| (d.index, d.side)).collect();
+        assert_eq!(found, [(1, DeadSide::To)]);
+    }
+
+    #[test]
+    fn test_uses_collected_imports_without_parsing() {
+        // The file is never read: its imports come from the analysis
+        let file = PathBuf::from("src/domain/user.ts");
+        let imports = ImportSources::from([(file.clone(), vec!["axios".to_string()])]);
+        let rules = vec![rule("src/domain/", "axios"), rule("src/domain/", "lodash")];
+        let dead = find_dead_rules(&rules, &[file], Some(&imports));
+        let found: Vec<(usize, DeadSide)> = dead.iter().map(|d| (d.index, d.side)).collect();
+        assert_eq!(found, [(1, DeadSide::To)]);
+    }
+}
@@ -3,16 +3,22 @@
 //! This module contains all file analysis logic:
 //! - Tree-sitter parser for TypeScript/JavaScript
 //! - Pattern matching utilities
+//! - Module boundary checks (deep imports)
+//! - Dead rule detection (rules that match no file or import)
+//! - Generated and vendored code detection
 //! - Metrics collection (imports, functions)
 //! - File collection with caching
 
+pub mod boundaries;
 pub mod collector;
+pub mod dead_rules;
+pub mod generated;
 pub mod metrics;
 mod pattern_matcher;
 pub mod swc_parser;
 
 // Re-export public functions
-pub use collector::analyze_all_files;
+pub use collector::{analyze_all_files, analyze_project};
 pub use swc_parser::collect_violations_from_file;
 
 // Note: analyze_changed_files is defined in main.rs to avoid circular imports
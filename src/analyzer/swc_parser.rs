@@ -4,10 +4,11 @@
 use crate::autofix::Violation;
 use crate::config::{ArchError, LinterContext};
 use crate::parsers;
+use crate::security::SecurityFinding;
 use crate::source_span;
 use miette::{IntoDiagnostic, Result, SourceSpan};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use super::pattern_matcher::{matches_pattern, normalize_pattern};
 
@@ -17,15 +18,46 @@ pub fn analyze_file(path: &PathBuf, ctx: &LinterContext) -> Result<()> {
 
     // Try to use multi-language parser first
     if let Some(parser) = parsers::get_parser_for_file(path) {
-        let violations = parser.find_violations(&source_code, path, ctx)?;
+        let mut violations = parser.find_violations(&source_code, path, ctx)?;
 
-        if let Some(first_violation) = violations.first() {
-            return Err(create_error_from_source(
+        // Rules that need resolved imports: CODEOWNERS owners (owner:@team),
+        // module boundaries, third-party packages and workspace packages
+        if !ctx.owner_rules.is_empty()
+            || ctx.boundaries.is_some()
+            || ctx.external_imports.is_some()
+            || ctx.workspace.is_some()
+        {
+            let imports = parser.extract_imports(&source_code, path)?;
+            violations.extend(crate::codeowners::find_owner_violations(
+                path,
+                &source_code,
+                &imports,
+                ctx,
+            ));
+            violations.extend(super::boundaries::find_boundary_violations(
+                path,
+                &source_code,
+                &imports,
+                ctx,
+            ));
+            violations.extend(crate::packages::find_external_import_violations(
+                path,
+                &source_code,
+                &imports,
+                ctx,
+            ));
+            violations.extend(crate::workspace::find_workspace_violations(
+                path,
                 &source_code,
-                first_violation,
+                &imports,
+                ctx,
             ));
         }
 
+        if let Some(first_violation) = violations.first() {
+            return Err(create_error_from_source(&source_code, first_violation));
+        }
+
         // Validate method length for TypeScript/JavaScript files
         let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
         if matches!(extension, "ts" | "tsx" | "js" | "jsx") {
@@ -70,14 +102,21 @@ pub fn analyze_file(path: &PathBuf, ctx: &LinterContext) -> Result<()> {
             }
         }
 
-        // Standard NestJS rule: no Repository in Controller
-        if file_path_str.contains("controller") && source_lower.contains(".repository") {
-            let span = source_span::span_for_line(&source_code, line_number + 1);
-            return Err(create_arch_error(
-                &source_code,
-                span,
-                "MVC: Prohibido importar Repositorios en Controladores.",
-            ));
+        // Opt-in rules of the architecture pattern preset
+        for preset in ctx.preset_rules.iter().filter(|p| p.applies_to(path)) {
+            let file_matches =
+                matches_pattern(&file_path_str, &normalize_pattern(&preset.rule.from));
+            let import_matches =
+                matches_pattern(&source_lower, &normalize_pattern(&preset.rule.to));
+
+            if file_matches && import_matches {
+                let span = source_span::span_for_line(&source_code, line_number + 1);
+                return Err(create_arch_error(
+                    &source_code,
+                    span,
+                    preset.rule.reason.as_deref().unwrap_or_default(),
+                ));
+            }
         }
     }
 
@@ -100,10 +139,7 @@ pub fn validate_method_length_ts(content: &str, max_lines: usize) -> Result<()>
     check_methods_recursive(tree.root_node(), max_lines)
 }
 
-fn check_methods_recursive(
-    node: tree_sitter::Node,
-    max_lines: usize,
-) -> Result<()> {
+fn check_methods_recursive(node: tree_sitter::Node, max_lines: usize) -> Result<()> {
     if node.kind() == "method_definition" || node.kind() == "function_declaration" {
         let start_line = node.start_position().row + 1; // 1-based
         let end_line = node.end_position().row + 1;
@@ -141,14 +177,19 @@ pub fn create_error_from_source(src: &str, violation: &Violation) -> miette::Rep
     ArchError {
         src: src.to_string(),
         span,
-        message: format!(
-            "Restricción: Archivos en '{}' no pueden importar de '{}'.",
-            violation.rule.from, violation.rule.to
-        ),
+        message: violation_message(violation),
     }
     .into()
 }
 
+/// One-line description of the rule a violation breaks
+pub fn violation_message(violation: &Violation) -> String {
+    format!(
+        "Restricción: Archivos en '{}' no pueden importar de '{}'.",
+        violation.rule.from, violation.rule.to
+    )
+}
+
 /// Validate method length for a file, using Tree-sitter internally.
 pub fn validate_method_length(path: &PathBuf, ctx: &LinterContext) -> Result<()> {
     let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
@@ -162,19 +203,85 @@ pub fn validate_method_length(path: &PathBuf, ctx: &LinterContext) -> Result<()>
 /// Collect violations from a file without failing.
 /// Useful for --fix mode where we want to process all violations.
 pub fn collect_violations_from_file(path: &PathBuf, ctx: &LinterContext) -> Result<Vec<Violation>> {
-    // Try to use multi-language parser first
+    let (mut violations, security_findings) = collect_findings_from_file(path, ctx)?;
+    violations.extend(
+        security_findings
+            .into_iter()
+            .map(|finding| finding.violation),
+    );
+    Ok(violations)
+}
+
+/// Collect architecture violations and security findings separately.
+/// Returns `(architecture, security)` so reporters can tell them apart.
+pub fn collect_findings_from_file(
+    path: &Path,
+    ctx: &LinterContext,
+) -> Result<(Vec<Violation>, Vec<SecurityFinding>)> {
+    if parsers::get_parser_for_file(path).is_none() {
+        // Fallback: return empty violations for unsupported files
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let source_code = fs::read_to_string(path).into_diagnostic()?;
+    collect_findings_from_source(path, &source_code, ctx)
+}
+
+/// Same as `collect_findings_from_file`, for content that is not on disk
+/// (e.g. an unsaved editor buffer). Suppressed lines are left out.
+pub fn collect_findings_from_source(
+    path: &Path,
+    source_code: &str,
+    ctx: &LinterContext,
+) -> Result<(Vec<Violation>, Vec<SecurityFinding>)> {
     if let Some(parser) = parsers::get_parser_for_file(path) {
-        let source_code = fs::read_to_string(path).into_diagnostic()?;
-        let mut violations = parser.find_violations(&source_code, path, ctx)?;
+        let mut violations = parser.find_violations(source_code, path, ctx)?;
 
-        // Security audit (Tier Pro)
-        if let Ok(mut security_violations) = parser.audit_security(&source_code, path, ctx) {
-            violations.append(&mut security_violations);
+        // Rules that need resolved imports: CODEOWNERS owners (owner:@team),
+        // module boundaries, third-party packages and workspace packages
+        if !ctx.owner_rules.is_empty()
+            || ctx.boundaries.is_some()
+            || ctx.external_imports.is_some()
+            || ctx.workspace.is_some()
+        {
+            let imports = parser.extract_imports(source_code, path)?;
+            violations.extend(crate::codeowners::find_owner_violations(
+                path,
+                source_code,
+                &imports,
+                ctx,
+            ));
+            violations.extend(super::boundaries::find_boundary_violations(
+                path,
+                source_code,
+                &imports,
+                ctx,
+            ));
+            violations.extend(crate::packages::find_external_import_violations(
+                path,
+                source_code,
+                &imports,
+                ctx,
+            ));
+            violations.extend(crate::workspace::find_workspace_violations(
+                path,
+                source_code,
+                &imports,
+                ctx,
+            ));
         }
 
-        return Ok(violations);
+        // Security audit (Tier Pro)
+        let mut security_findings = parser
+            .audit_security(source_code, path, ctx)
+            .unwrap_or_default();
+
+        violations.retain(|v| !crate::suppression::is_suppressed(source_code, v.line_number));
+        security_findings.retain(|finding| {
+            !crate::suppression::is_suppressed(source_code, finding.violation.line_number)
+        });
+        return Ok((violations, security_findings));
     }
 
     // Fallback: return empty violations for unsupported files
-    Ok(Vec::new())
+    Ok((Vec::new(), Vec::new()))
 }
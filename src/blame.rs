@@ -0,0 +1,175 @@
+//! Git blame attribution for violations
+//!
+//! Annotates each violation with the commit that last touched the offending
+//! line, then aggregates counts per author and per owning team (CODEOWNERS)
+//! so fixes can be routed to whoever owns the code.
+
+use crate::analysis_result::AnalysisResult;
+use chrono::{DateTime, TimeZone, Utc};
+use git2::{BlameOptions, Repository};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Key used for violations without blame information (untracked or uncommitted lines)
+pub const UNCOMMITTED: &str = "(uncommitted)";
+
+/// Last commit that touched a line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlameInfo {
+    pub author: String,
+    pub email: String,
+    pub commit: String,
+    pub date: DateTime<Utc>,
+}
+
+impl BlameInfo {
+    /// `Name <email>`, used as the aggregation key
+    pub fn author_key(&self) -> String {
+        if self.email.is_empty() {
+            self.author.clone()
+        } else {
+            format!("{} <{}>", self.author, self.email)
+        }
+    }
+}
+
+/// Violation counts per author and per owning team
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Attribution {
+    pub by_author: BTreeMap<String, usize>,
+    pub by_team: BTreeMap<String, usize>,
+}
+
+impl Attribution {
+    /// Authors sorted by violation count, highest first
+    pub fn top_authors(&self) -> Vec<(&str, usize)> {
+        sorted_counts(&self.by_author)
+    }
+
+    /// Teams sorted by violation count, highest first
+    pub fn top_teams(&self) -> Vec<(&str, usize)> {
+        sorted_counts(&self.by_team)
+    }
+}
+
+/// Annotate violations with blame. Returns how many were blamed.
+///
+/// Works against the working tree: lines changed since HEAD are left
+/// without blame. Owners are filled in by the analyzer from CODEOWNERS.
+pub fn annotate(result: &mut AnalysisResult, project_root: &Path) -> usize {
+    let Ok(repo) = Repository::discover(project_root) else {
+        return 0;
+    };
+    let Some(workdir) = repo
+        .workdir()
+        .map(|w| w.canonicalize().unwrap_or_else(|_| w.into()))
+    else {
+        return 0;
+    };
+
+    let mut blames: HashMap<PathBuf, Vec<BlameHunk>> = HashMap::new();
+    let mut blamed = 0;
+    for cv in &mut result.violations {
+        let violation = &cv.violation;
+        let hunks = blames
+            .entry(violation.file_path.clone())
+            .or_insert_with(|| {
+                blame_file(
+                    &repo,
+                    &workdir,
+                    &violation.file_path,
+                    &violation.file_content,
+                )
+            });
+        let line = violation.line_number;
+        let info = hunks
+            .iter()
+            .find(|h| line >= h.start && line < h.start + h.lines)
+            .and_then(|h| h.info.clone());
+        if info.is_some() {
+            blamed += 1;
+        }
+        cv.blame = info;
+    }
+    blamed
+}
+
+/// Aggregate violation counts per author and per team.
+///
+/// A violation in a file with several owners counts once for each of them.
+pub fn attribution(result: &AnalysisResult) -> Attribution {
+    let mut attribution = Attribution::default();
+    for cv in &result.violations {
+        let author = cv
+            .blame
+            .as_ref()
+            .map(BlameInfo::author_key)
+            .unwrap_or_else(|| UNCOMMITTED.to_string());
+        *attribution.by_author.entry(author).or_default() += 1;
+    }
+    attribution.by_team = result
+        .violations_by_team()
+        .into_iter()
+        .map(|(team, violations)| (team.to_string(), violations.len()))
+        .collect();
+    attribution
+}
+
+/// Whether any violation carries blame or owner information
+pub fn has_attribution(result: &AnalysisResult) -> bool {
+    result
+        .violations
+        .iter()
+        .any(|cv| cv.blame.is_some() || !cv.owners.is_empty())
+}
+
+/// Lines `start..start + lines` (1-based) last changed by `info`
+struct BlameHunk {
+    start: usize,
+    lines: usize,
+    /// None for lines not committed yet
+    info: Option<BlameInfo>,
+}
+
+/// Blame the committed file, then re-map it onto the working tree content
+fn blame_file(repo: &Repository, workdir: &Path, path: &Path, content: &str) -> Vec<BlameHunk> {
+    let Ok(rel) = path.strip_prefix(workdir) else {
+        return Vec::new();
+    };
+    let Ok(committed) = repo.blame_file(rel, Some(BlameOptions::new().use_mailmap(true))) else {
+        return Vec::new();
+    };
+    let Ok(blame) = committed.blame_buffer(content.as_bytes()) else {
+        return Vec::new();
+    };
+
+    blame
+        .iter()
+        .map(|hunk| {
+            let info = (!hunk.final_commit_id().is_zero()).then(|| {
+                let signature = hunk.final_signature();
+                BlameInfo {
+                    author: signature.name().unwrap_or("").to_string(),
+                    email: signature.email().unwrap_or("").to_string(),
+                    commit: hunk.final_commit_id().to_string(),
+                    date: Utc
+                        .timestamp_opt(signature.when().seconds(), 0)
+                        .single()
+                        .unwrap_or_default(),
+                }
+            });
+            BlameHunk {
+                start: hunk.final_start_line(),
+                lines: hunk.lines_in_hunk(),
+                info,
+            }
+        })
+        .collect()
+}
+
+fn sorted_counts(counts: &BTreeMap<String, usize>) -> Vec<(&str, usize)> {
+    let mut sorted: Vec<(&str, usize)> = counts.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    sorted
+}
@@ -7,7 +7,7 @@ use std::fs;
 use std::io;
 use std::path::Path;
 
-const CACHE_VERSION: u32 = 1;
+const CACHE_VERSION: u32 = 2;
 const CACHE_DIR: &str = ".architect-cache";
 const CACHE_FILE: &str = "cache.json";
 
@@ -60,6 +60,7 @@ pub struct FileCacheEntry {
     pub long_functions: Vec<LongFunction>,
     pub import_count: usize,
     pub function_count: usize,
+    pub import_sources: Vec<String>,
 }
 
 /// Disk-persisted analysis cache
@@ -85,6 +86,38 @@ pub fn hash_config(ctx: &LinterContext) -> String {
         data.push_str(&rule.to);
         data.push(';');
     }
+    // Owner rules also depend on who owns what
+    for rule in &ctx.owner_rules {
+        data.push_str(&format!("{}:{};", rule.from, rule.to));
+    }
+    if let Some(owners) = ctx
+        .codeowners
+        .as_ref()
+        .filter(|_| !ctx.owner_rules.is_empty())
+    {
+        for rule in &owners.rules {
+            data.push_str(&format!(
+                "{}={};",
+                rule.pattern.source,
+                rule.owners.join(",")
+            ));
+        }
+    }
+    if let Some(boundaries) = &ctx.boundaries {
+        for module in boundaries.modules() {
+            data.push_str(&format!(
+                "module:{}={};",
+                module.path,
+                module.exports.join(",")
+            ));
+        }
+    }
+    if let Some(external) = &ctx.external_imports {
+        data.push_str(&serde_json::to_string(&external.rules).unwrap_or_default());
+    }
+    if let Some(workspace) = &ctx.workspace {
+        data.push_str(&workspace.fingerprint());
+    }
     hash_content(data.as_bytes())
 }
 
@@ -20,6 +20,8 @@ pub struct CircularDependencyAnalyzer {
     project_root: PathBuf,
     /// Grafo inverso: node -> [nodes que lo importan]
     reverse_graph: HashMap<String, Vec<String>>,
+    /// Imports relativos sin resolver: node -> [rutas a las que apuntan]
+    unresolved: HashMap<String, Vec<PathBuf>>,
 }
 
 impl CircularDependencyAnalyzer {
@@ -32,49 +34,16 @@ impl CircularDependencyAnalyzer {
             graph: HashMap::new(),
             project_root: canonical_root,
             reverse_graph: HashMap::new(),
+            unresolved: HashMap::new(),
         }
     }
 
     /// Analiza todos los archivos y construye el grafo de dependencias
     pub fn build_graph(&mut self, files: &[PathBuf]) -> Result<()> {
         for file_path in files {
-            // Extraer imports del archivo
+            // Extraer imports del archivo y agregar sus aristas
             let imports = self.extract_imports(file_path)?;
-
-            // Normalizar la ruta del archivo actual
-            let normalized_current = self.normalize_file_path(file_path);
-            let current_key = normalized_current.clone();
-
-            // Insertar en el grafo
-            self.graph
-                .entry(current_key.clone())
-                .or_insert_with(Vec::new);
-
-            // Procesar cada import
-            for import_path in imports {
-                if let Some(resolved) = self.resolve_import_path(file_path, &import_path) {
-                    let normalized_import = self.normalize_file_path(&resolved);
-
-                    // Solo agregar dependencias internas del proyecto
-                    if self.is_internal_dependency(&normalized_import) {
-                        // Evitar auto-importaciones (ciclos triviales de 1 nodo)
-                        if current_key == normalized_import {
-                            continue;
-                        }
-
-                        self.graph
-                            .entry(current_key.clone())
-                            .or_insert_with(Vec::new)
-                            .push(normalized_import.clone());
-
-                        // Actualizar grafo inverso
-                        self.reverse_graph
-                            .entry(normalized_import)
-                            .or_insert_with(Vec::new)
-                            .push(current_key.clone());
-                    }
-                }
-            }
+            self.replace_imports(file_path, imports);
         }
 
         Ok(())
@@ -133,10 +102,7 @@ impl CircularDependencyAnalyzer {
 
     /// Extrae todos los imports de un archivo usando escaneo de líneas
     fn extract_imports(&self, file_path: &Path) -> Result<Vec<String>> {
-        let extension = file_path.extension().and_then(|e| e.to_str()).unwrap_or("");
-
-        // Solo procesar archivos TypeScript/JavaScript
-        if !matches!(extension, "ts" | "tsx" | "js" | "jsx") {
+        if !is_graph_file(file_path) {
             return Ok(Vec::new());
         }
 
@@ -146,19 +112,7 @@ impl CircularDependencyAnalyzer {
 
     /// Resuelve un path de import a una ruta de archivo real
     fn resolve_import_path(&self, current_file: &Path, import_path: &str) -> Option<PathBuf> {
-        // Ignorar imports externos (node_modules, @/aliases si no se resuelven, etc.)
-        if import_path.starts_with('@')
-            || import_path.starts_with("node_modules")
-            || (!import_path.starts_with('.') && !import_path.starts_with('/'))
-        {
-            // Podríamos agregar lógica para resolver alias de TypeScript aquí
-            // Por ahora, solo procesamos imports relativos
-            return None;
-        }
-
-        // Resolver path relativo
-        let current_dir = current_file.parent()?;
-        let resolved = current_dir.join(import_path);
+        let resolved = import_target(current_file, import_path)?;
 
         // 1. Intentar el archivo exacto si existe (import './App.css')
         if resolved.exists() && resolved.is_file() {
@@ -235,15 +189,63 @@ impl CircularDependencyAnalyzer {
         desc
     }
 
+    /// Clave del nodo de un archivo en el grafo (ruta relativa a la raíz)
+    pub fn node_key(&self, file_path: &Path) -> String {
+        self.normalize_file_path(file_path)
+    }
+
     /// Actualiza un archivo específico en el grafo (para watch mode)
     pub fn update_file(&mut self, file_path: &Path) -> Result<()> {
-        let normalized_current = self.normalize_file_path(file_path);
+        let imports = self.extract_imports(file_path)?;
+        self.replace_imports(file_path, imports);
+        Ok(())
+    }
+
+    /// Actualiza un archivo con contenido que no está en disco (buffers de un editor)
+    pub fn update_file_content(&mut self, file_path: &Path, content: &str) {
+        let imports = if is_graph_file(file_path) {
+            extract_imports_from_content(content)
+        } else {
+            Vec::new()
+        };
+        self.replace_imports(file_path, imports);
+    }
 
-        // Eliminar aristas antiguas del nodo
-        self.invalidate_node(&normalized_current);
+    /// Archivos con imports sin resolver que podrían resolver a `created`
+    /// (un archivo nuevo): solo esos necesitan releer sus imports
+    pub fn files_importing(&self, created: &Path) -> Vec<PathBuf> {
+        let created = lexical_normalize(created);
+        self.unresolved
+            .iter()
+            .filter(|(_, targets)| {
+                targets
+                    .iter()
+                    .any(|target| resolution_candidates(target).contains(&created))
+            })
+            .map(|(node, _)| self.project_root.join(node))
+            .collect()
+    }
 
-        // Re-extraer imports
-        let imports = self.extract_imports(file_path)?;
+    /// Quita las aristas salientes de un archivo que no se analiza (código
+    /// generado omitido); las entrantes se conservan
+    pub fn clear_imports(&mut self, file_path: &Path) {
+        self.replace_imports(file_path, Vec::new());
+    }
+
+    /// Sustituye las aristas salientes de un archivo por las de `imports`
+    fn replace_imports(&mut self, file_path: &Path, imports: Vec<String>) {
+        let normalized_current = self.normalize_file_path(file_path);
+
+        // Eliminar las aristas salientes antiguas; las entrantes (quién
+        // importa este archivo) no cambian
+        self.unresolved.remove(&normalized_current);
+        if let Some(deps) = self.graph.remove(&normalized_current) {
+            for dep in deps {
+                if let Some(reverse_deps) = self.reverse_graph.get_mut(&dep) {
+                    reverse_deps.retain(|n| n != &normalized_current);
+                }
+            }
+        }
 
         // Reconstruir aristas
         self.graph
@@ -271,10 +273,25 @@ impl CircularDependencyAnalyzer {
                         .or_insert_with(Vec::new)
                         .push(normalized_current.clone());
                 }
+            } else if let Some(target) = import_target(file_path, &import_path) {
+                // Puede resolverse más adelante, cuando se cree el archivo
+                self.unresolved
+                    .entry(normalized_current.clone())
+                    .or_default()
+                    .push(lexical_normalize(&target));
             }
         }
+    }
 
-        Ok(())
+    /// Línea (0-based) de `content` cuyo import resuelve al nodo `target`
+    pub fn import_line_to(&self, file_path: &Path, content: &str, target: &str) -> Option<usize> {
+        content.lines().position(|line| {
+            let line = line.trim();
+            extract_es_import_path(line)
+                .or_else(|| extract_require_path(line))
+                .and_then(|import_path| self.resolve_import_path(file_path, &import_path))
+                .is_some_and(|resolved| self.normalize_file_path(&resolved) == target)
+        })
     }
 
     /// Invalida un nodo en el grafo (elimina sus aristas)
@@ -289,14 +306,20 @@ impl CircularDependencyAnalyzer {
             }
         }
         self.graph.remove(node);
+        self.unresolved.remove(node);
 
-        // Eliminar aristas entrantes del grafo inverso
+        // Eliminar aristas entrantes del grafo inverso; quien lo importaba
+        // queda con un import sin resolver hasta que el archivo vuelva
         if let Some(reverse_deps) = self.reverse_graph.get(node) {
             // Eliminar referencias en el grafo directo
             for dep in reverse_deps.clone() {
                 if let Some(forward_deps) = self.graph.get_mut(&dep) {
                     forward_deps.retain(|n| n != node);
                 }
+                self.unresolved
+                    .entry(dep)
+                    .or_default()
+                    .push(self.project_root.join(node));
             }
         }
         self.reverse_graph.remove(node);
@@ -408,6 +431,12 @@ impl CircularDependencyAnalyzer {
     }
 }
 
+/// Solo los archivos TypeScript/JavaScript forman parte del grafo
+fn is_graph_file(file_path: &Path) -> bool {
+    let extension = file_path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    matches!(extension, "ts" | "tsx" | "js" | "jsx")
+}
+
 /// Función pública para analizar dependencias cíclicas en un proyecto
 pub fn analyze_circular_dependencies(
     files: &[PathBuf],
@@ -418,6 +447,43 @@ pub fn analyze_circular_dependencies(
     Ok(analyzer.detect_cycles())
 }
 
+/// Ruta a la que apunta un import relativo, sin comprobar que exista. None
+/// para imports externos (paquetes, node_modules, alias como @/...)
+fn import_target(current_file: &Path, import_path: &str) -> Option<PathBuf> {
+    if import_path.starts_with('@')
+        || import_path.starts_with("node_modules")
+        || (!import_path.starts_with('.') && !import_path.starts_with('/'))
+    {
+        // Podríamos agregar lógica para resolver alias de TypeScript aquí
+        // Por ahora, solo procesamos imports relativos
+        return None;
+    }
+    Some(current_file.parent()?.join(import_path))
+}
+
+/// Archivos a los que `resolve_import_path` puede resolver `target`
+fn resolution_candidates(target: &Path) -> Vec<PathBuf> {
+    let mut candidates = vec![target.to_path_buf()];
+    candidates.extend(["ts", "tsx", "js", "jsx"].map(|ext| target.with_extension(ext)));
+    candidates.extend(["index.ts", "index.js"].map(|index| target.join(index)));
+    candidates
+}
+
+/// Quita los componentes `.` y `..` de una ruta sin acceder al disco
+fn lexical_normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
 /// Extrae todos los imports de un contenido de archivo usando escaneo de líneas
 fn extract_imports_from_content(content: &str) -> Vec<String> {
     let mut imports = Vec::new();
@@ -516,6 +582,48 @@ mod tests {
             file_normalized
         );
     }
+
+    #[test]
+    fn test_update_file_content_keeps_incoming_edges() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let a = dir.path().join("a.ts");
+        let b = dir.path().join("b.ts");
+        std::fs::write(&a, "import { b } from './b';\n").unwrap();
+        std::fs::write(&b, "import { a } from './a';\n").unwrap();
+
+        let mut analyzer = CircularDependencyAnalyzer::new(dir.path());
+        analyzer.build_graph(&[a.clone(), b.clone()]).unwrap();
+        assert_eq!(analyzer.detect_cycles().len(), 1);
+
+        // Unsaved buffer without the import breaks the cycle
+        analyzer.update_file_content(&b, "export const b = 1;\n");
+        assert!(analyzer.detect_cycles().is_empty());
+
+        // a -> b survived the update, so restoring the import brings it back
+        analyzer.update_file_content(&b, "import { a } from './a';\n");
+        let affected = analyzer.get_affected_nodes(&analyzer.node_key(&b));
+        assert_eq!(analyzer.detect_cycles_in_subgraph(&affected).len(), 1);
+    }
+
+    #[test]
+    fn test_files_importing_only_lists_unresolved_importers() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let a = root.join("a.ts");
+        let b = root.join("b.ts");
+        let c = root.join("c.ts");
+        std::fs::write(&a, "import { b } from './b';\n").unwrap();
+        std::fs::write(&c, "import { a } from './a';\n").unwrap();
+
+        let mut analyzer = CircularDependencyAnalyzer::new(&root);
+        analyzer.build_graph(&[a.clone(), c.clone()]).unwrap();
+        assert_eq!(analyzer.files_importing(&b), [a.clone()]);
+        assert!(analyzer.files_importing(&root.join("d.ts")).is_empty());
+
+        // Importers of a deleted file wait for it to come back
+        analyzer.invalidate_node(&analyzer.node_key(&a));
+        assert_eq!(analyzer.files_importing(&a), [c]);
+    }
 }
 
 /// Imprime un reporte de dependencias cíclicas
@@ -9,6 +9,11 @@ pub enum ReportFormat {
     Json,
     Markdown,
     CodeClimate,
+    Sarif,
+    Junit,
+    Checkstyle,
+    Rdjson,
+    Html,
 }
 
 impl ReportFormat {
@@ -17,6 +22,11 @@ impl ReportFormat {
             "json" => Some(ReportFormat::Json),
             "markdown" | "md" => Some(ReportFormat::Markdown),
             "codeclimate" | "gitlab" => Some(ReportFormat::CodeClimate),
+            "sarif" => Some(ReportFormat::Sarif),
+            "junit" => Some(ReportFormat::Junit),
+            "checkstyle" => Some(ReportFormat::Checkstyle),
+            "rdjson" | "reviewdog" => Some(ReportFormat::Rdjson),
+            "html" => Some(ReportFormat::Html),
             _ => None,
         }
     }
@@ -35,13 +45,19 @@ pub struct CliArgs {
     pub staged_mode: bool,
     /// Analizar solo archivos modificados (Git-based)
     pub incremental_mode: bool,
+    /// Comparar contra una ref base (ej. origin/main) y reportar solo las diferencias
+    pub diff_base: Option<String>,
+    /// Anotar violaciones con git blame y dueños de CODEOWNERS
+    pub blame: bool,
+    /// Agrupar violaciones por equipo dueño (CODEOWNERS) en el dashboard
+    pub group_by_team: bool,
     /// Formato de reporte para exportar (json, markdown)
     pub report_format: Option<ReportFormat>,
     /// Ruta del archivo de salida para el reporte
     pub output_path: Option<String>,
     /// Disable analysis cache
     pub no_cache: bool,
-    /// Ejecutar en segundo plano (modo daemon)
+    /// Ejecutar en segundo plano y atender peticiones JSON-RPC (modo daemon)
     pub daemon_mode: bool,
     /// Enable debug logging
     pub debug_mode: bool,
@@ -51,10 +67,41 @@ pub struct CliArgs {
     pub min_severity: crate::config::Severity,
     /// Run init wizard to generate architect.json
     pub init_mode: bool,
-    /// Overwrite existing architect.json (used with init)
-    pub init_force: bool,
+    /// Overwrite existing architect.json (used with init), or rewrite a YAML or
+    /// TOML config that only lacks `version` (used with migrate)
+    pub force: bool,
     /// Target directory for init (default: current dir)
     pub init_path: Option<String>,
+    /// Show the health score trend from .architect/history.jsonl
+    pub trend_mode: bool,
+    /// Regression threshold override for trend (points)
+    pub trend_threshold: Option<u8>,
+    /// Number of most recent history entries to show in trend
+    pub trend_limit: Option<usize>,
+    /// Fill the score history by analyzing past commits
+    pub backfill_mode: bool,
+    /// Git revision or range to backfill (default: HEAD)
+    pub backfill_range: Option<String>,
+    /// Analyze every Nth commit when backfilling
+    pub backfill_every: usize,
+    /// Only backfill tagged commits
+    pub backfill_tags: bool,
+    /// Audit declared vs imported dependencies
+    pub deps_mode: bool,
+    /// Rewrite the config file in the current format version
+    pub migrate_mode: bool,
+    /// Only show the migration diff, without writing (used with migrate)
+    pub migrate_dry_run: bool,
+    /// Explain which ignore rule excludes a path and exit
+    pub explain_ignore: Option<String>,
+    /// Run the Language Server Protocol server over stdio
+    pub lsp_mode: bool,
+    /// Send a JSON-RPC request to the running daemon (analyze, violations, score, shutdown)
+    pub rpc_method: Option<String>,
+    /// File whose violations are requested (used with rpc violations)
+    pub rpc_file: Option<String>,
+    /// Reload the config and discard the cache (used with rpc analyze)
+    pub rpc_full: bool,
 }
 
 impl Default for CliArgs {
@@ -65,6 +112,9 @@ impl Default for CliArgs {
             fix_mode: false,
             staged_mode: false,
             incremental_mode: false,
+            diff_base: None,
+            blame: false,
+            group_by_team: false,
             report_format: None,
             output_path: None,
             no_cache: false,
@@ -73,8 +123,23 @@ impl Default for CliArgs {
             check_mode: false,
             min_severity: crate::config::Severity::Info,
             init_mode: false,
-            init_force: false,
+            force: false,
             init_path: None,
+            trend_mode: false,
+            trend_threshold: None,
+            trend_limit: None,
+            backfill_mode: false,
+            backfill_range: None,
+            backfill_every: 1,
+            backfill_tags: false,
+            deps_mode: false,
+            migrate_mode: false,
+            migrate_dry_run: false,
+            explain_ignore: None,
+            lsp_mode: false,
+            rpc_method: None,
+            rpc_file: None,
+            rpc_full: false,
         }
     }
 }
@@ -99,15 +164,44 @@ pub fn print_help() {
     println!("  -f, --fix            Fix mode: suggest and apply AI-powered automatic corrections");
     println!("  -s, --staged         Analyze only git staged files");
     println!("  -i, --incremental    Analyze only modified files (Git-based)");
-    println!("  -r, --report <FMT>   Export report: json, markdown, codeclimate");
+    println!(
+        "  -d, --daemon         Run in background, answering requests on .architect/daemon.sock"
+    );
+    println!("  --diff-base <REF>    Report only violations introduced/fixed since REF");
+    println!(
+        "  --blame              Attribute violations to authors (git blame) and CODEOWNERS teams"
+    );
+    println!("  --group-by team      Group violations by owning team (CODEOWNERS)");
+    println!("  -r, --report <FMT>   Export report: json, markdown, codeclimate, sarif,");
+    println!("                       junit, checkstyle, rdjson, html");
     println!("  -o, --output <PATH>  Output file for report");
     println!("  --no-cache           Disable analysis cache");
     println!("  --debug              Enable debug logging (verbose output)");
     println!("  --check              Validate configuration only and exit");
+    println!("  --explain-ignore <FILE>  Show which ignore rule excludes FILE and exit");
     println!("  --severity <LEVEL>   Minimum severity level: error, warning, info");
     println!("  init                 Generate architect.json wizard for your project");
     println!("    --force            Overwrite existing architect.json");
     println!("    --path <DIR>       Target directory (default: current directory)");
+    println!("  trend                Show health score history (.architect/history.jsonl)");
+    println!("    --threshold <N>    Score drop flagged as regression (default: 5)");
+    println!("    --limit <N>        Show only the last N entries");
+    println!(
+        "  backfill             Fill score history from past commits (working tree untouched)"
+    );
+    println!("    --range <REV>      Revision or range, e.g. HEAD, v1.0..main (default: HEAD)");
+    println!("    --every <N>        Analyze every Nth commit (default: 1)");
+    println!("    --tags             Only analyze tagged commits");
+    println!("  deps                 Audit declared vs imported dependencies (npm, pip, composer)");
+    println!("  migrate              Rewrite the config file in the current format version");
+    println!("    --dry-run          Only show the diff, without writing the file");
+    println!("    --force            Rewrite YAML/TOML (dropping comments) even if only `version` is missing");
+    println!("  lsp                  Run the language server over stdio (for editors)");
+    println!(
+        "  rpc <METHOD>         Query the running daemon: analyze, violations, score, shutdown"
+    );
+    println!("    --file <FILE>      File whose violations are requested (violations)");
+    println!("    --full             Reload the config and discard the cache (analyze)");
     println!();
     println!("EXAMPLES:");
     println!("  architect-linter-pro                         # Interactive mode");
@@ -117,6 +211,7 @@ pub fn print_help() {
     println!("  architect-linter-pro --fix .                 # Analyze and auto-fix with AI");
     println!("  architect-linter-pro --staged .              # Analyze staged files only");
     println!("  architect-linter-pro --incremental .        # Analyze modified files only");
+    println!("  architect-linter-pro --diff-base origin/main . # PR gate: new issues only");
     println!("  architect-linter-pro --report json .         # Export JSON report to stdout");
     println!(
         "  architect-linter-pro -r markdown -o report.md . # Export Markdown report to file"
@@ -124,9 +219,25 @@ pub fn print_help() {
     println!("  architect-linter-pro init                  # Run wizard in current directory");
     println!("  architect-linter-pro init --force          # Overwrite existing config");
     println!("  architect-linter-pro init --path ./backend # Run wizard in subdirectory");
+    println!("  architect-linter-pro trend --limit 30 .    # Score trend of the last 30 runs");
+    println!("  architect-linter-pro backfill --every 20 .  # Score every 20th commit on HEAD");
+    println!("  architect-linter-pro deps --report json .  # Dependency audit as JSON");
+    println!("  architect-linter-pro migrate --dry-run .   # Preview the config migration");
+    println!("  architect-linter-pro --explain-ignore src/api.gen.ts . # Why a file is skipped");
+    println!("  architect-linter-pro lsp                   # Language server for editors");
+    println!("  architect-linter-pro --daemon .            # Keep the project analyzed in memory");
+    println!("  architect-linter-pro rpc violations --file src/app.ts . # Ask the daemon");
     println!();
     println!("INTERACTIVE WATCH MODE:");
-    println!("  When running with --watch, type a command + Enter:");
+    println!("  In a terminal, --watch opens a live dashboard (logs go to .architect/watch.log):");
+    println!("    ↑↓ / j k   Move through violations or cycles");
+    println!("    Tab        Switch between violations and the cycle explorer");
+    println!("    ← → / h l  Walk the steps of the selected cycle");
+    println!("    /          Filter violations; t switches between file and rule");
+    println!("    Enter / e  Open $EDITOR at the violation's line");
+    println!("    a r m f    Analyze, JSON report, Markdown report, AI fix");
+    println!("    q / Esc    Quit");
+    println!("  When stdin or stdout is not a terminal, type a command + Enter:");
     println!("    f  Fix: auto-fix violations with AI");
     println!("    r  Report: generate JSON report");
     println!("    m  Markdown: generate Markdown report");
@@ -163,6 +274,9 @@ pub fn process_args() -> Option<CliArgs> {
     let mut fix_mode = false;
     let mut staged_mode = false;
     let mut incremental_mode = false;
+    let mut diff_base: Option<String> = None;
+    let mut blame = false;
+    let mut group_by_team = false;
     let mut no_cache = false;
     let mut daemon_mode = false;
     let mut debug_mode = false;
@@ -172,8 +286,23 @@ pub fn process_args() -> Option<CliArgs> {
     let mut min_severity = crate::config::Severity::Info;
     let mut project_path: Option<String> = None;
     let mut init_mode = false;
-    let mut init_force = false;
+    let mut force = false;
     let mut init_path: Option<String> = None;
+    let mut trend_mode = false;
+    let mut trend_threshold: Option<u8> = None;
+    let mut trend_limit: Option<usize> = None;
+    let mut backfill_mode = false;
+    let mut backfill_range: Option<String> = None;
+    let mut backfill_every: usize = 1;
+    let mut backfill_tags = false;
+    let mut deps_mode = false;
+    let mut migrate_mode = false;
+    let mut migrate_dry_run = false;
+    let mut explain_ignore: Option<String> = None;
+    let mut lsp_mode = false;
+    let mut rpc_method: Option<String> = None;
+    let mut rpc_file: Option<String> = None;
+    let mut rpc_full = false;
 
     // Procesar argumentos
     let mut i = 1;
@@ -199,6 +328,33 @@ pub fn process_args() -> Option<CliArgs> {
             "--incremental" | "-i" => {
                 incremental_mode = true;
             }
+            "--diff-base" => {
+                if i + 1 < args.len() {
+                    i += 1;
+                    diff_base = Some(args[i].clone());
+                } else {
+                    eprintln!("Error: --diff-base requiere una ref git (ej. origin/main)");
+                    return None;
+                }
+            }
+            "--blame" => {
+                blame = true;
+            }
+            "--group-by" => {
+                if i + 1 < args.len() {
+                    i += 1;
+                    match args[i].to_lowercase().as_str() {
+                        "team" | "owner" => group_by_team = true,
+                        _ => {
+                            eprintln!("Error: Agrupación inválida '{}'. Usa 'team'.", args[i]);
+                            return None;
+                        }
+                    }
+                } else {
+                    eprintln!("Error: --group-by requiere un criterio (team)");
+                    return None;
+                }
+            }
             "--no-cache" => {
                 no_cache = true;
             }
@@ -219,7 +375,7 @@ pub fn process_args() -> Option<CliArgs> {
                         report_format = Some(fmt);
                     } else {
                         eprintln!(
-                            "Error: Formato de reporte inválido '{}'. Usa 'json', 'markdown' o 'codeclimate'.",
+                            "Error: Formato de reporte inválido '{}'. Usa 'json', 'markdown', 'codeclimate', 'sarif', 'junit', 'checkstyle', 'rdjson' o 'html'.",
                             args[i]
                         );
                         return None;
@@ -262,8 +418,117 @@ pub fn process_args() -> Option<CliArgs> {
             "init" => {
                 init_mode = true;
             }
+            "trend" => {
+                trend_mode = true;
+            }
+            "--threshold" => {
+                if i + 1 < args.len() {
+                    i += 1;
+                    match args[i].parse::<u8>() {
+                        Ok(n) if n <= 100 => trend_threshold = Some(n),
+                        _ => {
+                            eprintln!("Error: --threshold requiere un número entre 0 y 100");
+                            return None;
+                        }
+                    }
+                } else {
+                    eprintln!("Error: --threshold requiere un número de puntos");
+                    return None;
+                }
+            }
+            "--limit" => {
+                if i + 1 < args.len() {
+                    i += 1;
+                    match args[i].parse::<usize>() {
+                        Ok(n) if n > 0 => trend_limit = Some(n),
+                        _ => {
+                            eprintln!("Error: --limit requiere un número mayor que 0");
+                            return None;
+                        }
+                    }
+                } else {
+                    eprintln!("Error: --limit requiere un número de entradas");
+                    return None;
+                }
+            }
+            "backfill" => {
+                backfill_mode = true;
+            }
+            "--range" => {
+                if i + 1 < args.len() {
+                    i += 1;
+                    backfill_range = Some(args[i].clone());
+                } else {
+                    eprintln!("Error: --range requiere una revisión o rango git (ej. v1.0..HEAD)");
+                    return None;
+                }
+            }
+            "--every" => {
+                if i + 1 < args.len() {
+                    i += 1;
+                    match args[i].parse::<usize>() {
+                        Ok(n) if n > 0 => backfill_every = n,
+                        _ => {
+                            eprintln!("Error: --every requiere un número mayor que 0");
+                            return None;
+                        }
+                    }
+                } else {
+                    eprintln!("Error: --every requiere un número de commits");
+                    return None;
+                }
+            }
+            "--tags" => {
+                backfill_tags = true;
+            }
+            "deps" => {
+                deps_mode = true;
+            }
+            "migrate" => {
+                migrate_mode = true;
+            }
+            "lsp" => {
+                lsp_mode = true;
+            }
+            "rpc" => {
+                if i + 1 < args.len() {
+                    i += 1;
+                    rpc_method = Some(args[i].clone());
+                } else {
+                    eprintln!(
+                        "Error: rpc requiere un método: analyze, violations, score, shutdown"
+                    );
+                    return None;
+                }
+            }
+            "--file" => {
+                if i + 1 < args.len() {
+                    i += 1;
+                    rpc_file = Some(args[i].clone());
+                } else {
+                    eprintln!("Error: --file requiere una ruta de archivo");
+                    return None;
+                }
+            }
+            "--full" => {
+                rpc_full = true;
+            }
+            // Added by LSP clients that launch servers over stdio
+            "--stdio" if lsp_mode => {}
+            "--dry-run" => {
+                migrate_dry_run = true;
+            }
+            "--explain-ignore" => {
+                if i + 1 < args.len() {
+                    i += 1;
+                    explain_ignore = Some(args[i].clone());
+                } else {
+                    eprintln!("Error: --explain-ignore requiere la ruta de un archivo");
+                    return None;
+                }
+            }
             "--force" => {
-                init_force = true;
+                force = true;
             }
             "--path" => {
                 if i + 1 < args.len() {
@@ -290,6 +555,9 @@ pub fn process_args() -> Option<CliArgs> {
         fix_mode,
         staged_mode,
         incremental_mode,
+        diff_base,
+        blame,
+        group_by_team,
         no_cache,
         daemon_mode,
         debug_mode,
@@ -298,7 +566,22 @@ pub fn process_args() -> Option<CliArgs> {
         output_path,
         min_severity,
         init_mode,
-        init_force,
+        force,
         init_path,
+        trend_mode,
+        trend_threshold,
+        trend_limit,
+        backfill_mode,
+        backfill_range,
+        backfill_every,
+        backfill_tags,
+        deps_mode,
+        migrate_mode,
+        migrate_dry_run,
+        explain_ignore,
+        lsp_mode,
+        rpc_method,
+        rpc_file,
+        rpc_full,
     })
 }
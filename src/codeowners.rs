@@ -0,0 +1,183 @@
+//! CODEOWNERS parsing
+//!
+//! Reads the first of `.github/CODEOWNERS`, `CODEOWNERS` and `docs/CODEOWNERS`
+//! (GitHub's lookup order) from the project root or, failing that, from the
+//! root of the git repository. As on GitHub, the last matching line wins.
+//!
+//! Rules in `forbidden_imports` can target owners instead of paths on either
+//! side with `owner:@org/team`, e.g. `{ "from": "owner:@org/payments",
+//! "to": "owner:@org/identity" }`.
+
+use crate::autofix::Violation;
+use crate::config::{ForbiddenRule, LinterContext};
+use crate::gitpattern::GitPattern;
+use crate::parsers::resolve::resolve_import;
+use crate::parsers::Import;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Team key for violations in files no CODEOWNERS line matches
+pub const UNOWNED: &str = "(unowned)";
+
+/// Prefix that makes a rule side refer to a CODEOWNERS owner instead of a path
+pub const OWNER_PREFIX: &str = "owner:";
+
+/// One `pattern @owner ...` line
+#[derive(Debug, Clone)]
+pub struct OwnerRule {
+    pub pattern: GitPattern,
+    pub owners: Vec<String>,
+}
+
+/// Parsed CODEOWNERS file
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    /// Directory the patterns are relative to
+    pub root: PathBuf,
+    pub rules: Vec<OwnerRule>,
+}
+
+impl CodeOwners {
+    /// Parse CODEOWNERS content; `root` is the directory patterns are relative to
+    pub fn parse(content: &str, root: &Path) -> Self {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let line = line.split(" #").next().unwrap_or(line);
+                let mut parts = line.split_whitespace();
+                let pattern = GitPattern::parse(parts.next()?)?;
+                Some(OwnerRule {
+                    pattern,
+                    owners: parts.map(String::from).collect(),
+                })
+            })
+            .collect();
+        Self {
+            root: root.to_path_buf(),
+            rules,
+        }
+    }
+
+    /// Find and parse the CODEOWNERS file for a project
+    pub fn load(project_root: &Path) -> Option<Self> {
+        let project_root = project_root
+            .canonicalize()
+            .unwrap_or_else(|_| project_root.to_path_buf());
+        let mut roots = vec![project_root.clone()];
+        if let Ok(repo) = git2::Repository::discover(&project_root) {
+            if let Some(workdir) = repo.workdir() {
+                let workdir = workdir
+                    .canonicalize()
+                    .unwrap_or_else(|_| workdir.to_path_buf());
+                if workdir != project_root {
+                    roots.push(workdir);
+                }
+            }
+        }
+
+        roots.into_iter().find_map(|root| {
+            LOCATIONS.iter().find_map(|location| {
+                let content = fs::read_to_string(root.join(location)).ok()?;
+                Some(Self::parse(&content, &root))
+            })
+        })
+    }
+
+    /// Owners of `path` (absolute, or relative to the CODEOWNERS root).
+    /// Empty when no line matches or the last match has no owners.
+    pub fn owners_of(&self, path: &Path) -> &[String] {
+        let rel = path.strip_prefix(&self.root).unwrap_or(path);
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.pattern.matches_path_or_parent(&rel, false))
+            .map(|rule| rule.owners.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Whether `owner` (case-insensitive) is one of the owners of `path`
+    pub fn is_owned_by(&self, path: &Path, owner: &str) -> bool {
+        self.owners_of(path)
+            .iter()
+            .any(|o| o.eq_ignore_ascii_case(owner))
+    }
+}
+
+/// The owner a rule side refers to (`owner:@org/team` → `@org/team`)
+pub fn owner_ref(side: &str) -> Option<&str> {
+    side.strip_prefix(OWNER_PREFIX).map(str::trim)
+}
+
+/// Whether the rule targets owners on either side
+pub fn is_owner_rule(rule: &ForbiddenRule) -> bool {
+    owner_ref(&rule.from).is_some() || owner_ref(&rule.to).is_some()
+}
+
+/// Check the imports of a file against the owner-targeted rules of `ctx`
+pub fn find_owner_violations(
+    file_path: &Path,
+    source_code: &str,
+    imports: &[Import],
+    ctx: &LinterContext,
+) -> Vec<Violation> {
+    let Some(owners) = ctx.codeowners.as_ref() else {
+        return Vec::new();
+    };
+    let file_str = file_path.to_string_lossy();
+
+    let mut violations = Vec::new();
+    for rule in &ctx.owner_rules {
+        let file_matches = match owner_ref(&rule.from) {
+            Some(owner) => owners.is_owned_by(file_path, owner),
+            None => crate::parsers::typescript::matches_pattern(&file_str, &rule.from),
+        };
+        if !file_matches {
+            continue;
+        }
+
+        for import in imports {
+            let import_matches = match owner_ref(&rule.to) {
+                Some(owner) => resolve_import(file_path, &import.source, &owners.root)
+                    .is_some_and(|target| owners.is_owned_by(&target, owner)),
+                None => crate::parsers::typescript::matches_pattern(&import.source, &rule.to),
+            };
+            if import_matches {
+                violations.push(Violation {
+                    file_path: file_path.to_path_buf(),
+                    file_content: source_code.to_string(),
+                    offensive_import: import.raw_statement.clone(),
+                    rule: rule.clone(),
+                    line_number: import.line_number,
+                });
+            }
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_match_wins() {
+        let owners = CodeOwners::parse(
+            "# default\n*       @org/platform\n/apps/payments/  @org/payments @alice\n/apps/payments/README.md\n",
+            Path::new("/repo"),
+        );
+        assert_eq!(
+            owners.owners_of(Path::new("/repo/src/main.ts")),
+            ["@org/platform"]
+        );
+        assert_eq!(
+            owners.owners_of(Path::new("apps/payments/api/charge.ts")),
+            ["@org/payments", "@alice"]
+        );
+        assert!(owners
+            .owners_of(Path::new("apps/payments/README.md"))
+            .is_empty());
+    }
+}
@@ -0,0 +1,549 @@
+//! Diagnósticos de la configuración
+//!
+//! Los problemas de la configuración se acumulan como `ConfigProblem` (una
+//! ruta JSON y un mensaje) y se convierten en diagnósticos de miette con el
+//! archivo original como `source_code`, de modo que cada error subraya la
+//! clave o el valor exacto, como hace el compilador.
+//!
+//! `check_config` (usado por `--check`) junta todos los problemas en una sola
+//! pasada: esquema, valores, patrones mal escritos y reglas muertas (su
+//! `from` o su `to` no coincide con nada del proyecto).
+
+use jsonschema::error::ValidationErrorKind;
+use jsonschema::JSONSchema;
+use miette::{Diagnostic, LabeledSpan, NamedSource, Result, Severity, SourceCode, SourceSpan};
+use serde_json::Value;
+use std::fmt::Display;
+use std::path::Path;
+use thiserror::Error;
+
+use super::formats::{find_config, ConfigSource};
+use super::loader::ConfigError;
+use super::types::{ArchPattern, ForbiddenRule};
+use crate::analyzer::dead_rules::{find_dead_rules, DeadSide};
+
+/// Similitud mínima (Jaro-Winkler) para sugerir "¿quisiste decir ...?"
+const SUGGESTION_THRESHOLD: f64 = 0.8;
+
+/// Patrones de arquitectura con soporte propio
+const KNOWN_PATTERNS: [&str; 4] = ["Hexagonal", "Clean", "MVC", "Ninguno"];
+
+/// Un problema de la configuración, ubicado por su ruta JSON
+#[derive(Debug, Clone)]
+pub struct ConfigProblem {
+    /// Ruta JSON del elemento (`/forbidden_imports/0/severity`); vacía para la raíz
+    pub pointer: String,
+    pub message: String,
+    /// Texto junto al subrayado
+    pub label: String,
+    pub help: Option<String>,
+    pub severity: Severity,
+    /// Subrayar el valor en lugar de la clave
+    pub on_value: bool,
+}
+
+impl ConfigProblem {
+    pub fn error(pointer: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            pointer: pointer.into(),
+            message: message.into(),
+            label: String::new(),
+            help: None,
+            severity: Severity::Error,
+            on_value: true,
+        }
+    }
+
+    pub fn warning(pointer: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::error(pointer, message)
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Subrayar la clave (p. ej. claves desconocidas)
+    pub fn on_key(mut self) -> Self {
+        self.on_value = false;
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+/// Un problema con su posición en el archivo de configuración
+#[derive(Debug, Error)]
+#[error("{message}")]
+pub struct ConfigDiagnostic {
+    pub message: String,
+    pub help: Option<String>,
+    pub severity: Severity,
+    label: String,
+    span: Option<SourceSpan>,
+    src: Option<NamedSource<String>>,
+}
+
+impl ConfigDiagnostic {
+    /// Ubica el problema en `source`; sin `source` (problema heredado de una
+    /// base o configuración construida en memoria) el diagnóstico no tiene
+    /// fragmento de código
+    pub fn new(problem: ConfigProblem, source: Option<&ConfigSource>) -> Self {
+        let span = source
+            .and_then(|s| s.span(&problem.pointer, problem.on_value))
+            .map(SourceSpan::from);
+        // Sin posición, la ruta JSON indica dónde está el problema
+        let message = match (span, problem.pointer.is_empty()) {
+            (None, false) => format!("{} (en {})", problem.message, problem.pointer),
+            _ => problem.message,
+        };
+        Self {
+            message,
+            help: problem.help,
+            severity: problem.severity,
+            label: problem.label,
+            span,
+            src: source.map(|s| NamedSource::new(s.name(), s.content.clone())),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Diagnostic for ConfigDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(if self.is_error() {
+            "config::invalid"
+        } else {
+            "config::warning"
+        }))
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(self.severity)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn Display>)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.src.as_ref().map(|src| src as &dyn SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let span = self.span?;
+        let label = (!self.label.is_empty()).then(|| self.label.clone());
+        Some(Box::new(std::iter::once(LabeledSpan::new_with_span(
+            label, span,
+        ))))
+    }
+}
+
+/// Todos los problemas de un archivo de configuración, como un solo reporte
+#[derive(Debug, Error, Diagnostic)]
+#[error("{summary}")]
+#[diagnostic(
+    code(config::invalid),
+    help("Corrige los problemas indicados. architect-linter-pro --check los lista todos sin analizar el proyecto.")
+)]
+pub struct ConfigReport {
+    pub summary: String,
+    #[related]
+    pub problems: Vec<ConfigDiagnostic>,
+}
+
+impl ConfigReport {
+    pub fn new(file: &str, problems: Vec<ConfigDiagnostic>) -> Self {
+        let errors = problems.iter().filter(|p| p.is_error()).count();
+        let warnings = problems.len() - errors;
+        let summary = match (errors, warnings) {
+            (0, 0) => format!("{} es válido", file),
+            (0, w) => format!("{} es válido, con {} advertencia(s)", file, w),
+            (e, 0) => format!("{} tiene {} error(es)", file, e),
+            (e, w) => format!("{} tiene {} error(es) y {} advertencia(s)", file, e, w),
+        };
+        Self { summary, problems }
+    }
+
+    pub fn from_problems(
+        file: &str,
+        problems: Vec<ConfigProblem>,
+        source: Option<&ConfigSource>,
+    ) -> Self {
+        let mut problems: Vec<ConfigDiagnostic> = problems
+            .into_iter()
+            .map(|problem| ConfigDiagnostic::new(problem, source))
+            .collect();
+        // En el orden del archivo; los que no se pudieron ubicar, al final
+        problems.sort_by_key(|p| p.span.map_or(usize::MAX, |span| span.offset()));
+        Self::new(file, problems)
+    }
+
+    /// Como `from_problems` para una configuración con `extends`: `problems`
+    /// se refieren a `merged` (el archivo fusionado con sus bases). Los que
+    /// están en las claves del propio archivo (`own`) se ubican en él; los que
+    /// vienen de una base se muestran con su ruta JSON.
+    pub fn from_extended(
+        file: &str,
+        problems: Vec<ConfigProblem>,
+        source: &ConfigSource,
+        merged: &Value,
+        own: &Value,
+    ) -> Self {
+        let mut problems: Vec<ConfigDiagnostic> = problems
+            .into_iter()
+            .map(
+                |mut problem| match own_pointer(&problem.pointer, merged, own) {
+                    Some(pointer) => {
+                        problem.pointer = pointer;
+                        ConfigDiagnostic::new(problem, Some(source))
+                    }
+                    None => ConfigDiagnostic::new(problem, None),
+                },
+            )
+            .collect();
+        problems.sort_by_key(|p| p.span.map_or(usize::MAX, |span| span.offset()));
+        Self::new(file, problems)
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.problems.iter().any(ConfigDiagnostic::is_error)
+    }
+}
+
+/// Ruta en `own` del valor al que apunta `pointer` en `merged`, o None si
+/// viene de una base. Las claves se conservan al fusionar; los elementos de
+/// una lista cambian de posición (las reglas de la base van antes), así que
+/// se buscan por su valor.
+fn own_pointer(pointer: &str, merged: &Value, own: &Value) -> Option<String> {
+    let mut merged = merged;
+    let mut own = own;
+    let mut translated = String::new();
+    for segment in pointer.split('/').skip(1) {
+        match (merged, own) {
+            (Value::Object(merged_map), Value::Object(own_map)) => {
+                let key = segment.replace("~1", "/").replace("~0", "~");
+                merged = merged_map.get(&key)?;
+                own = own_map.get(&key)?;
+                translated.push('/');
+                translated.push_str(segment);
+            }
+            (Value::Array(merged_items), Value::Array(own_items)) => {
+                merged = merged_items.get(segment.parse::<usize>().ok()?)?;
+                let index = own_items.iter().position(|item| item == merged)?;
+                own = &own_items[index];
+                translated.push_str(&format!("/{}", index));
+            }
+            _ => return None,
+        }
+    }
+    Some(translated)
+}
+
+/// Candidato más parecido a `word` (sin distinguir mayúsculas), si lo hay
+pub fn did_you_mean<'a>(
+    word: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let word = word.to_lowercase();
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            let score = strsim::jaro_winkler(&word, &candidate.to_lowercase());
+            (candidate, score)
+        })
+        .filter(|(_, score)| *score >= SUGGESTION_THRESHOLD)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(candidate, _)| candidate)
+}
+
+/// Problemas del esquema JSON: claves desconocidas (con sugerencia), campos
+/// obligatorios, tipos y valores fuera de los permitidos
+pub fn schema_problems(json: &Value) -> Result<Vec<ConfigProblem>> {
+    let schema: Value =
+        serde_json::from_str(super::loader::ARCHITECT_SCHEMA).map_err(|e| internal_error(&e))?;
+    let compiled = JSONSchema::compile(&schema).map_err(|e| internal_error(&e))?;
+
+    let mut problems = Vec::new();
+    let Err(errors) = compiled.validate(json) else {
+        return Ok(problems);
+    };
+    for error in errors {
+        let pointer = error.instance_path.to_string();
+        match &error.kind {
+            ValidationErrorKind::AdditionalProperties { unexpected } => {
+                // Las claves válidas son las `properties` del nodo del esquema
+                let schema_path = error.schema_path.to_string();
+                let parent = schema_path.rsplit_once('/').map_or("", |(p, _)| p);
+                let known: Vec<&str> = schema
+                    .pointer(parent)
+                    .and_then(|node| node.get("properties"))
+                    .and_then(Value::as_object)
+                    .map(|props| props.keys().map(String::as_str).collect())
+                    .unwrap_or_default();
+                for key in unexpected {
+                    let mut problem = ConfigProblem::error(
+                        format!("{}/{}", pointer, escape_pointer(key)),
+                        format!("Clave desconocida: '{}'", key),
+                    )
+                    .label("no forma parte del esquema")
+                    .on_key();
+                    problem = match did_you_mean(key, known.iter().copied()) {
+                        Some(suggestion) => {
+                            problem.help(format!("¿Quisiste decir '{}'?", suggestion))
+                        }
+                        None if !known.is_empty() => {
+                            problem.help(format!("Claves válidas aquí: {}", known.join(", ")))
+                        }
+                        None => problem,
+                    };
+                    problems.push(problem);
+                }
+            }
+            ValidationErrorKind::Required { property } => {
+                let property = property
+                    .as_str()
+                    .map_or_else(|| property.to_string(), str::to_string);
+                problems.push(
+                    ConfigProblem::error(
+                        pointer,
+                        format!("Falta el campo obligatorio '{}'", property),
+                    )
+                    .label("este objeto")
+                    .on_key(),
+                );
+            }
+            ValidationErrorKind::Enum { options } => {
+                let allowed: Vec<&str> = options
+                    .as_array()
+                    .map(|values| values.iter().filter_map(Value::as_str).collect())
+                    .unwrap_or_default();
+                let value = error.instance.as_str().unwrap_or_default();
+                let help = match did_you_mean(value, allowed.iter().copied()) {
+                    Some(suggestion) => format!("¿Quisiste decir '{}'?", suggestion),
+                    None => format!("Valores permitidos: {}", allowed.join(", ")),
+                };
+                problems.push(
+                    ConfigProblem::error(
+                        pointer,
+                        format!("Valor no permitido: {}", error.instance),
+                    )
+                    .label("valor no permitido")
+                    .help(help),
+                );
+            }
+            _ => problems.push(
+                ConfigProblem::error(pointer, "No cumple con el esquema esperado")
+                    .label(error.to_string()),
+            ),
+        }
+    }
+    Ok(problems)
+}
+
+/// Advierte de un `architecture_pattern` que parece un patrón conocido mal
+/// escrito (los patrones personalizados son válidos)
+pub fn pattern_problems(json: &Value) -> Vec<ConfigProblem> {
+    let Some(name) = json.get("architecture_pattern").and_then(Value::as_str) else {
+        return Vec::new();
+    };
+    let pattern: ArchPattern = serde_json::from_value(Value::from(name)).unwrap_or_default();
+    if !matches!(pattern, ArchPattern::Custom(_)) {
+        return Vec::new();
+    }
+    did_you_mean(name, KNOWN_PATTERNS)
+        .map(|suggestion| {
+            ConfigProblem::warning(
+                "/architecture_pattern",
+                format!("Patrón de arquitectura desconocido: '{}'", name),
+            )
+            .label("se tratará como un patrón personalizado")
+            .help(format!("¿Quisiste decir '{}'?", suggestion))
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Reglas muertas de forbidden_imports: su `from` no coincide con ningún
+/// archivo o su `to` con ningún archivo ni import (ver
+/// `analyzer::dead_rules`). Trabaja sobre el JSON para poder revisarlas
+/// aunque haya errores de esquema.
+pub fn rule_problems(root: &Path, json: &Value) -> Vec<ConfigProblem> {
+    let Some(rules) = json.get("forbidden_imports").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    let ignored_paths: Vec<String> = match json.get("ignored_paths").and_then(Value::as_array) {
+        Some(paths) => paths
+            .iter()
+            .filter_map(|p| p.as_str().map(str::to_string))
+            .collect(),
+        None => super::default_ignored_paths(),
+    };
+
+    // Las reglas inválidas o incompletas ya se reportan como errores
+    let (positions, rules): (Vec<usize>, Vec<ForbiddenRule>) = rules
+        .iter()
+        .enumerate()
+        .filter_map(|(i, rule)| Some((i, serde_json::from_value(rule.clone()).ok()?)))
+        .filter(|(_, rule): &(usize, ForbiddenRule)| {
+            !rule.from.trim().is_empty() && !rule.to.trim().is_empty()
+        })
+        .unzip();
+    let files = crate::discovery::collect_files(root, &ignored_paths);
+
+    find_dead_rules(&rules, &files, None)
+        .into_iter()
+        .map(|dead| {
+            let (label, help) = match dead.side {
+                DeadSide::From => (
+                    "0 archivos",
+                    "Revisa la ruta (es relativa a la raíz del proyecto) o elimina la regla.",
+                ),
+                DeadSide::To => (
+                    "0 archivos e imports",
+                    "Ningún archivo ni import coincide: revisa la ruta o el nombre del paquete.",
+                ),
+            };
+            ConfigProblem::warning(
+                format!(
+                    "/forbidden_imports/{}/{}",
+                    positions[dead.index],
+                    dead.side.key()
+                ),
+                format!(
+                    "Regla muerta: {} '{}' no coincide con nada",
+                    dead.side.key(),
+                    dead.pattern()
+                ),
+            )
+            .label(label)
+            .help(help)
+        })
+        .collect()
+}
+
+/// Valida la configuración de `root` sin analizar el proyecto y devuelve
+/// todos los problemas encontrados
+pub fn check_config(root: &Path) -> Result<ConfigReport> {
+    let path = find_config(root).ok_or_else(|| {
+        ConfigError::new(
+            "No se encontró architect.json".to_string(),
+            format!(
+                "Crea architect.json (o architect.yaml, architect.toml o [tool.architect] en pyproject.toml) en: {}",
+                root.display()
+            ),
+        )
+    })?;
+    let source = ConfigSource::read(&path)?;
+    let value = source.parse()?;
+
+    let migrated = super::migration::migrate(value)?;
+    let mut problems: Vec<ConfigProblem> = migrated
+        .warning(&source.name())
+        .map(|warning| {
+            ConfigProblem::warning("", warning)
+                .help("architect-linter-pro migrate --dry-run muestra los cambios sin escribirlos.")
+        })
+        .into_iter()
+        .collect();
+
+    // Tras una migración las rutas ya no se corresponden con el archivo; con
+    // extends se traducen a las claves del propio archivo
+    let located = !migrated.is_outdated();
+    let own = migrated
+        .value
+        .get("extends")
+        .is_some()
+        .then(|| migrated.value.clone());
+    let value = super::extends::resolve_extends(root, migrated.value)?;
+
+    problems.extend(rule_problems(root, &value));
+    let merged = own.as_ref().map(|_| value.clone());
+    problems.extend(super::loader::validate_config(value)?.1);
+    Ok(match (located, own, merged) {
+        (true, Some(own), Some(merged)) => {
+            ConfigReport::from_extended(&source.name(), problems, &source, &merged, &own)
+        }
+        (located, _, _) => {
+            ConfigReport::from_problems(&source.name(), problems, located.then_some(&source))
+        }
+    })
+}
+
+/// Escapa una clave para usarla en una ruta JSON
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn internal_error(e: &dyn Display) -> miette::Report {
+    ConfigError::new(
+        format!("Error interno al compilar el esquema: {}", e),
+        "Este es un error en el linter, por favor repórtalo.".to_string(),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_problems_suggest_keys_and_values() {
+        let problems = schema_problems(&json!({
+            "max_lines_per_functon": 40,
+            "architecture_pattern": "MVC",
+            "forbidden_imports": [{ "from": "a", "to": "b", "severity": "eror" }]
+        }))
+        .unwrap();
+
+        let unknown = problems
+            .iter()
+            .find(|p| p.pointer == "/max_lines_per_functon")
+            .unwrap();
+        assert_eq!(
+            unknown.help.as_deref(),
+            Some("¿Quisiste decir 'max_lines_per_function'?")
+        );
+        assert!(!unknown.on_value);
+
+        let severity = problems
+            .iter()
+            .find(|p| p.pointer == "/forbidden_imports/0/severity")
+            .unwrap();
+        assert_eq!(severity.help.as_deref(), Some("¿Quisiste decir 'error'?"));
+
+        assert!(problems
+            .iter()
+            .any(|p| p.message.contains("'max_lines_per_function'")));
+    }
+
+    #[test]
+    fn test_pattern_typos_are_warnings() {
+        let problems = pattern_problems(&json!({ "architecture_pattern": "Hexagnal" }));
+        assert_eq!(problems.len(), 1);
+        assert!(!problems[0].is_error());
+        assert_eq!(
+            problems[0].help.as_deref(),
+            Some("¿Quisiste decir 'Hexagonal'?")
+        );
+
+        assert!(pattern_problems(&json!({ "architecture_pattern": "Atomic Design" })).is_empty());
+        assert!(pattern_problems(&json!({ "architecture_pattern": "mvc" })).is_empty());
+    }
+}
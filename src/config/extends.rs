@@ -0,0 +1,183 @@
+//! Herencia de configuración (`extends`)
+//!
+//! `"extends"` acepta una ruta o una lista, aplicadas en orden:
+//!
+//! - `./base.architect.json`: archivo relativo al architect.json que lo declara
+//! - `preset:nestjs-hexagonal`: plantilla incluida (ver `init::templates`)
+//! - `@org/architect-config`: paquete npm con un architect.json (o .yaml,
+//!   .toml) en su raíz (se busca en `node_modules` subiendo desde el proyecto)
+//!
+//! Cada base puede tener su propio `extends`. El resultado se fusiona con
+//! `config::merge` (la configuración que declara `extends` gana), así que el
+//! orden de la lista es el orden de prioridad: las últimas bases pisan a las
+//! primeras. Cada archivo se migra al formato actual antes de fusionarlo.
+
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+use super::formats::{find_config, ConfigSource};
+use super::loader::ConfigError;
+use super::merge::merge;
+use super::migration::migrate;
+use miette::Result;
+
+/// Prefijo de los presets incluidos
+pub const PRESET_PREFIX: &str = "preset:";
+
+/// Resuelve el `extends` de una configuración leída de `dir` y devuelve la
+/// configuración completa, sin la clave `extends`
+pub fn resolve_extends(dir: &Path, value: Value) -> Result<Value> {
+    resolve(dir, value, &mut Vec::new())
+}
+
+fn resolve(dir: &Path, value: Value, chain: &mut Vec<String>) -> Result<Value> {
+    // Cada archivo puede tener su propia versión del formato
+    let mut value = migrate(value)?.value;
+    let entries = match value.as_object_mut().and_then(|o| o.remove("extends")) {
+        None => return Ok(value),
+        Some(Value::String(entry)) => vec![entry],
+        Some(Value::Array(entries)) => entries
+            .into_iter()
+            .map(|e| match e {
+                Value::String(entry) => Ok(entry),
+                other => Err(invalid_entry(&other.to_string())),
+            })
+            .collect::<Result<_>>()?,
+        Some(other) => return Err(invalid_entry(&other.to_string())),
+    };
+
+    let mut base = Value::Object(Default::default());
+    for entry in entries {
+        let (key, base_dir, content) = load_base(dir, &entry)?;
+        if chain.contains(&key) {
+            chain.push(key);
+            return Err(ConfigError::new(
+                format!("Herencia circular en extends: {}", chain.join(" → ")),
+                "Elimina una de las referencias para romper el ciclo.".to_string(),
+            )
+            .into());
+        }
+        chain.push(key);
+        let resolved = resolve(&base_dir, content, chain)?;
+        chain.pop();
+        base = merge(base, resolved);
+    }
+    Ok(merge(base, value))
+}
+
+/// Carga una base de `extends`: (identificador, directorio base, contenido)
+fn load_base(dir: &Path, entry: &str) -> Result<(String, PathBuf, Value)> {
+    if let Some(name) = entry.strip_prefix(PRESET_PREFIX) {
+        let Some(config) = crate::init::templates::get_preset(name.trim()) else {
+            return Err(ConfigError::new(
+                format!("Preset desconocido en extends: '{}'", entry),
+                format!(
+                    "Presets disponibles: {}",
+                    crate::init::templates::preset_names()
+                        .iter()
+                        .map(|n| format!("{}{}", PRESET_PREFIX, n))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+            .into());
+        };
+        let value = serde_json::to_value(config).map_err(|e| {
+            ConfigError::new(
+                format!("No se pudo cargar el preset '{}': {}", entry, e),
+                "Este es un error en el linter, por favor repórtalo.".to_string(),
+            )
+        })?;
+        return Ok((entry.to_string(), dir.to_path_buf(), value));
+    }
+
+    let path = locate(dir, entry).ok_or_else(|| {
+        ConfigError::new(
+            format!("No se encontró la configuración base '{}'", entry),
+            format!(
+                "Las rutas son relativas a {}; los paquetes npm deben estar instalados en node_modules.",
+                dir.display()
+            ),
+        )
+    })?;
+    let value = ConfigSource::read(&path)?.parse()?;
+    let key = path
+        .canonicalize()
+        .unwrap_or_else(|_| path.clone())
+        .display()
+        .to_string();
+    let base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+    Ok((key, base_dir, value))
+}
+
+/// Archivo al que apunta una entrada de `extends` que no es un preset
+fn locate(dir: &Path, entry: &str) -> Option<PathBuf> {
+    let is_path = entry.starts_with('.') || Path::new(entry).is_absolute();
+    if is_path {
+        let path = dir.join(entry);
+        return path.is_file().then_some(path);
+    }
+
+    // Paquete npm: node_modules/<paquete>[/<archivo>] subiendo desde `dir`
+    dir.ancestors().find_map(|ancestor| {
+        let package = ancestor.join("node_modules").join(entry);
+        if package.is_file() {
+            Some(package)
+        } else {
+            find_config(&package)
+        }
+    })
+}
+
+fn invalid_entry(entry: &str) -> miette::Report {
+    ConfigError::new(
+        format!("Entrada inválida en extends: {}", entry),
+        "extends acepta una ruta o una lista de rutas y presets (\"preset:nestjs-hexagonal\")."
+            .to_string(),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_extends_chain_and_cycles() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("shared")).unwrap();
+        fs::write(
+            root.join("shared/org.json"),
+            r#"{ "max_lines_per_function": 30, "ignored_paths": ["dist/"] }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("base.architect.json"),
+            r#"{ "extends": "./shared/org.json", "max_lines_per_function": 40, "ignored_paths": ["build/"] }"#,
+        )
+        .unwrap();
+
+        let resolved = resolve_extends(
+            root,
+            json!({ "extends": ["./base.architect.json"], "ignored_paths": ["tmp/"] }),
+        )
+        .unwrap();
+        assert_eq!(
+            resolved,
+            json!({
+                "version": crate::config::migration::CURRENT_VERSION,
+                "max_lines_per_function": 40,
+                "ignored_paths": ["dist/", "build/", "tmp/"]
+            })
+        );
+
+        fs::write(root.join("a.json"), r#"{ "extends": "./b.json" }"#).unwrap();
+        fs::write(root.join("b.json"), r#"{ "extends": "./a.json" }"#).unwrap();
+        let err = resolve_extends(root, json!({ "extends": "./a.json" })).unwrap_err();
+        assert!(err.to_string().contains("circular"));
+    }
+}
@@ -0,0 +1,317 @@
+//! Formatos del archivo de configuración
+//!
+//! Además de `architect.json` se aceptan `architect.yaml`/`architect.yml`,
+//! `architect.toml` y la sección `[tool.architect]` de `pyproject.toml`, que
+//! admiten comentarios. Todos comparten el esquema de architect.json: se
+//! convierten a JSON antes de validar.
+//!
+//! Los errores del esquema se ubican con las posiciones que da el parser de
+//! cada formato (ver `spans`), para poder indicar línea y columna en el
+//! archivo original.
+
+use miette::Result;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use super::loader::ConfigError;
+use super::spans::SpanIndex;
+
+/// Archivos de configuración, en orden de prioridad
+pub const CONFIG_FILES: [&str; 5] = [
+    "architect.json",
+    "architect.yaml",
+    "architect.yml",
+    "architect.toml",
+    "pyproject.toml",
+];
+
+/// Sección de pyproject.toml con la configuración
+const PYPROJECT_SECTION: &str = "[tool.architect]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+    /// `[tool.architect]` dentro de pyproject.toml
+    Pyproject,
+}
+
+impl ConfigFormat {
+    /// Formato según el nombre del archivo (JSON si la extensión no es conocida)
+    pub fn of_path(path: &Path) -> Self {
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        match path.extension().and_then(|e| e.to_str()).unwrap_or("") {
+            _ if name == "pyproject.toml" => ConfigFormat::Pyproject,
+            "yaml" | "yml" => ConfigFormat::Yaml,
+            "toml" => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+}
+
+/// Primer archivo de configuración presente en `dir`. pyproject.toml solo
+/// cuenta si tiene una sección `[tool.architect]`.
+pub fn find_config(dir: &Path) -> Option<PathBuf> {
+    CONFIG_FILES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| match ConfigFormat::of_path(path) {
+            ConfigFormat::Pyproject => fs::read_to_string(path)
+                .is_ok_and(|content| content.lines().any(|l| l.trim() == PYPROJECT_SECTION)),
+            _ => path.is_file(),
+        })
+}
+
+/// Archivo de configuración leído de disco
+#[derive(Debug, Clone)]
+pub struct ConfigSource {
+    pub path: PathBuf,
+    pub format: ConfigFormat,
+    pub content: String,
+    /// Posiciones de claves y valores, calculadas la primera vez que se piden
+    spans: OnceLock<SpanIndex>,
+}
+
+impl ConfigSource {
+    pub fn new(path: &Path, content: String) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            format: ConfigFormat::of_path(path),
+            content,
+            spans: OnceLock::new(),
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            ConfigError::new(
+                format!("No se pudo leer {}: {}", file_name(path), e),
+                format!("Asegúrate de que el archivo existe en: {}", path.display()),
+            )
+        })?;
+        Ok(Self::new(path, content))
+    }
+
+    /// Nombre del archivo, para los mensajes
+    pub fn name(&self) -> String {
+        file_name(&self.path)
+    }
+
+    /// Convierte el contenido a JSON. Los errores de sintaxis indican línea y columna.
+    pub fn parse(&self) -> Result<Value> {
+        let syntax_error = |message: String, position: Option<(usize, usize)>, help: &str| {
+            let location = position
+                .map(|(line, column)| format!("{}:{}:{}", self.name(), line, column))
+                .unwrap_or_else(|| self.name());
+            ConfigError::new(
+                format!("Sintaxis inválida en {}: {}", location, message),
+                help.to_string(),
+            )
+        };
+
+        let value = match self.format {
+            ConfigFormat::Json => serde_json::from_str::<Value>(&self.content).map_err(|e| {
+                syntax_error(
+                    e.to_string(),
+                    Some((e.line(), e.column())),
+                    "Verifica que el archivo tenga sintaxis JSON válida. Si necesitas comentarios, usa architect.yaml o architect.toml.",
+                )
+            })?,
+            ConfigFormat::Yaml => serde_norway::from_str::<Value>(&self.content).map_err(|e| {
+                syntax_error(
+                    e.to_string(),
+                    e.location().map(|l| (l.line(), l.column())),
+                    "Verifica la indentación y que las listas usen '- '.",
+                )
+            })?,
+            ConfigFormat::Toml | ConfigFormat::Pyproject => {
+                let table: toml::Table = self.content.parse().map_err(|e: toml::de::Error| {
+                    syntax_error(
+                        e.message().to_string(),
+                        e.span().map(|span| self.line_col(span.start)),
+                        "Verifica la sintaxis TOML (claves, comillas y [[tablas]]).",
+                    )
+                })?;
+                let table = if self.format == ConfigFormat::Pyproject {
+                    table
+                        .get("tool")
+                        .and_then(|t| t.get("architect"))
+                        .cloned()
+                        .ok_or_else(|| {
+                            ConfigError::new(
+                                "pyproject.toml no tiene una sección [tool.architect]".to_string(),
+                                "Añade la configuración bajo [tool.architect].".to_string(),
+                            )
+                        })?
+                } else {
+                    toml::Value::Table(table)
+                };
+                serde_json::to_value(table).map_err(|e| {
+                    ConfigError::new(
+                        format!("No se pudo convertir {}: {}", self.name(), e),
+                        "Las fechas TOML no están soportadas en la configuración.".to_string(),
+                    )
+                })?
+            }
+        };
+
+        if value.is_null() {
+            return Err(ConfigError::new(
+                format!("{} está vacío", self.name()),
+                "Indica al menos max_lines_per_function, architecture_pattern y forbidden_imports."
+                    .to_string(),
+            )
+            .into());
+        }
+        Ok(value)
+    }
+
+    /// Línea y columna (desde 1) de un offset en bytes
+    pub fn line_col(&self, offset: usize) -> (usize, usize) {
+        let before = &self.content[..offset.min(self.content.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        (line, column)
+    }
+
+    /// Línea y columna del valor apuntado por una ruta JSON (`/a/0/b`)
+    #[allow(dead_code)]
+    pub fn position(&self, pointer: &str) -> Option<(usize, usize)> {
+        self.locate(pointer).map(|offset| self.line_col(offset))
+    }
+
+    /// Offset de la entrada apuntada por una ruta JSON (`/a/0/b`): su clave
+    /// o, en los elementos de una lista, su valor
+    pub fn locate(&self, pointer: &str) -> Option<usize> {
+        let located = self.spans().get(pointer)?;
+        Some(located.key.as_ref().unwrap_or(&located.value).start)
+    }
+
+    /// Rango (offset, longitud) que se subraya para una ruta JSON: la clave o,
+    /// con `on_value`, el valor que la sigue. Los elementos de una lista no
+    /// tienen clave y siempre marcan el valor; un valor en bloque (en las
+    /// líneas siguientes) se marca por su clave.
+    pub fn span(&self, pointer: &str, on_value: bool) -> Option<(usize, usize)> {
+        if pointer.is_empty() {
+            return None;
+        }
+        let located = self.spans().get(pointer)?;
+        let range = match &located.key {
+            Some(key) if !on_value => key,
+            Some(key)
+                if located.value.is_empty()
+                    || self.line_col(located.value.start).0 != self.line_col(key.start).0 =>
+            {
+                key
+            }
+            _ => &located.value,
+        };
+        Some((range.start, range.len()))
+    }
+
+    fn spans(&self) -> &SpanIndex {
+        self.spans
+            .get_or_init(|| SpanIndex::build(self.format, &self.content))
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(name: &str, content: &str) -> ConfigSource {
+        ConfigSource::new(Path::new(name), content.to_string())
+    }
+
+    #[test]
+    fn test_locate_pointer_in_each_format() {
+        let json = source(
+            "architect.json",
+            "{\n  \"forbidden_imports\": [\n    { \"from\": \"a\", \"to\": \"b\" },\n    { \"from\": \"c\", \"to\": \"d\", \"severity\": \"fatal\" }\n  ]\n}\n",
+        );
+        assert_eq!(
+            json.position("/forbidden_imports/1/severity"),
+            Some((4, 31))
+        );
+
+        let yaml = source(
+            "architect.yaml",
+            "# reglas\nforbidden_imports:\n  - from: a\n    to: b\n  - from: c\n    to: d\n    severity: fatal\n",
+        );
+        assert_eq!(yaml.position("/forbidden_imports/1/severity"), Some((7, 5)));
+
+        let toml = source(
+            "architect.toml",
+            "max_lines_per_function = 40\n\n[[forbidden_imports]]\nfrom = \"a\"\nto = \"b\"\n\n[[forbidden_imports]]\nfrom = \"c\"\nseverity = \"fatal\"\n",
+        );
+        assert_eq!(toml.position("/forbidden_imports/1/severity"), Some((9, 1)));
+
+        let pyproject = source(
+            "pyproject.toml",
+            "[project]\nname = \"x\"\n\n[tool.architect]\nmax_lines_per_function = 0\n",
+        );
+        assert_eq!(pyproject.position("/max_lines_per_function"), Some((5, 1)));
+    }
+
+    #[test]
+    fn test_span_marks_key_or_value() {
+        let json = source(
+            "architect.json",
+            "{\n  \"forbidden_imports\": [\n    { \"from\": \"a\", \"severity\": \"fatal\" }\n  ]\n}\n",
+        );
+        let text = |(offset, len): (usize, usize)| &json.content[offset..offset + len];
+        let pointer = "/forbidden_imports/0/severity";
+        assert_eq!(json.span(pointer, false).map(text), Some("\"severity\""));
+        assert_eq!(json.span(pointer, true).map(text), Some("\"fatal\""));
+        assert_eq!(
+            json.span("/forbidden_imports/0", true).map(text),
+            Some("{ \"from\": \"a\", \"severity\": \"fatal\" }")
+        );
+
+        let yaml = source(
+            "architect.yaml",
+            "max_lines_per_function: 0 # nunca\nignored_paths:\n  - dist/\n",
+        );
+        let text = |(offset, len): (usize, usize)| &yaml.content[offset..offset + len];
+        assert_eq!(
+            yaml.span("/max_lines_per_function", true).map(text),
+            Some("0")
+        );
+        assert_eq!(yaml.span("/ignored_paths/0", true).map(text), Some("dist/"));
+        assert_eq!(
+            yaml.span("/ignored_paths", true).map(text),
+            Some("ignored_paths")
+        );
+    }
+
+    #[test]
+    fn test_parse_yaml_and_pyproject() {
+        let yaml = source(
+            "architect.yml",
+            "max_lines_per_function: 40 # límite\narchitecture_pattern: Hexagonal\nforbidden_imports: []\n",
+        );
+        assert_eq!(yaml.parse().unwrap()["max_lines_per_function"], 40);
+
+        let pyproject = source(
+            "pyproject.toml",
+            "[tool.architect]\nmax_lines_per_function = 40\n\n[[tool.architect.forbidden_imports]]\nfrom = \"a\"\nto = \"b\"\n",
+        );
+        assert_eq!(
+            pyproject.parse().unwrap()["forbidden_imports"][0]["to"],
+            "b"
+        );
+
+        let broken = source("architect.yaml", "forbidden_imports:\n  - from: [a\n");
+        let err = broken.parse().unwrap_err().to_string();
+        assert!(err.contains("architect.yaml:"), "{}", err);
+    }
+}
@@ -54,12 +54,20 @@ impl ConfigGenerator {
         let build_command = crate::detector::get_build_command_suggestion(&primary_framework);
 
         Ok(ConfigFile {
+            version: Some(crate::config::migration::CURRENT_VERSION),
             max_lines_per_function: max_lines,
             architecture_pattern: pattern,
             forbidden_imports,
             ignored_paths: crate::config::default_ignored_paths(),
             build_command,
             ai_fix_retries: 3,
+            presets: Default::default(),
+            history: Default::default(),
+            modules: Vec::new(),
+            external_imports: Vec::new(),
+            dependency_audit: Default::default(),
+            workspace: Default::default(),
+            generated_code: Default::default(),
         })
     }
 
@@ -1,18 +1,26 @@
 #![allow(unused_assignments)]
-use jsonschema::JSONSchema;
 use miette::{Diagnostic, IntoDiagnostic, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 use thiserror::Error;
 
-const ARCHITECT_SCHEMA: &str = include_str!("../../schemas/architect.schema.json");
+pub(super) const ARCHITECT_SCHEMA: &str = include_str!("../../schemas/architect.schema.json");
 
-use super::types::{AIConfig, ArchPattern, ForbiddenRule, Framework, LinterContext};
+use super::diagnostics::{ConfigProblem, ConfigReport};
+use super::formats::ConfigSource;
+use super::presets::{self, PresetConfig};
+use super::types::{
+    AIConfig, ArchPattern, DependencyAuditConfig, ExternalImportRule, ForbiddenRule, Framework,
+    GeneratedCodeConfig, HistoryConfig, LinterContext, ModuleBoundary, WorkspaceConfig,
+};
 
 /// Estructura para mapear el architect.json tal cual está en el disco
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ConfigFile {
+    /// Versión del formato (ver `config::migration`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
     pub max_lines_per_function: usize,
     pub architecture_pattern: ArchPattern,
     pub forbidden_imports: Vec<ForbiddenRule>,
@@ -22,6 +30,20 @@ pub struct ConfigFile {
     pub build_command: Option<String>,
     #[serde(default = "default_ai_fix_retries")]
     pub ai_fix_retries: usize,
+    #[serde(default, skip_serializing_if = "PresetConfig::is_default")]
+    pub presets: PresetConfig,
+    #[serde(default, skip_serializing_if = "HistoryConfig::is_default")]
+    pub history: HistoryConfig,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modules: Vec<ModuleBoundary>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_imports: Vec<ExternalImportRule>,
+    #[serde(default, skip_serializing_if = "DependencyAuditConfig::is_default")]
+    pub dependency_audit: DependencyAuditConfig,
+    #[serde(default, skip_serializing_if = "WorkspaceConfig::is_default")]
+    pub workspace: WorkspaceConfig,
+    #[serde(default, skip_serializing_if = "GeneratedCodeConfig::is_default")]
+    pub generated_code: GeneratedCodeConfig,
 }
 
 fn default_ai_fix_retries() -> usize {
@@ -52,44 +74,76 @@ impl ConfigError {
     }
 }
 
-/// CARGA SILENCIOSA: Lee architect.json y .architect.ai.json y los convierte en contexto
+/// CARGA SILENCIOSA: Lee la configuración (architect.json, architect.yaml/.yml,
+/// architect.toml o [tool.architect] en pyproject.toml) y .architect.ai.json
+/// y los convierte en contexto
 pub fn load_config(root: &Path) -> Result<LinterContext> {
-    let config_path = root.join("architect.json");
-
-    // Leer el archivo de reglas
-    let content = fs::read_to_string(&config_path).map_err(|e| {
+    let config_path = super::formats::find_config(root).ok_or_else(|| {
         ConfigError::new(
-            format!("No se pudo leer architect.json: {}", e),
+            "No se encontró architect.json".to_string(),
             format!(
-                "Asegúrate de que el archivo existe en: {}",
-                config_path.display()
+                "Crea architect.json (o architect.yaml, architect.toml o [tool.architect] en pyproject.toml) en: {}",
+                root.display()
             ),
         )
     })?;
 
-    // Validar que es JSON válido
-    let mut json_value: serde_json::Value = serde_json::from_str(&content)
-        .map_err(|e| ConfigError::new(
-            format!("JSON inválido: {}", e),
-            "Verifica que el archivo architect.json tenga sintaxis JSON válida. Usa un validador JSON online si es necesario.".to_string()
-        ))?;
+    // Leer el archivo de reglas y validar su sintaxis
+    let source = ConfigSource::read(&config_path)?;
+    let json_value = source.parse()?;
 
-    // Aplicar migraciones si es necesario
-    json_value = super::migration::migrate_config(json_value);
+    load_config_source(root, json_value, Some(&source))
+}
 
-    // Validar el esquema antes de deserializar
-    validate_schema(&json_value)?;
+/// Construye el contexto a partir del JSON de configuración ya leído
+/// (p. ej. la configuración de un paquete fusionada con la raíz)
+pub fn load_config_value(root: &Path, json_value: serde_json::Value) -> Result<LinterContext> {
+    load_config_source(root, json_value, None)
+}
 
-    // Ahora sí deserializar con mejor manejo de errores
-    let config: ConfigFile = serde_json::from_value(json_value).map_err(|e| {
-        ConfigError::new(
-            format!("Error en la estructura: {}", e),
-            "Revisa que todos los campos tengan el tipo correcto.".to_string(),
-        )
-    })?;
+/// Como `load_config_value`; con `source` los errores del esquema indican
+/// línea y columna en el archivo original
+fn load_config_source(
+    root: &Path,
+    json_value: serde_json::Value,
+    source: Option<&ConfigSource>,
+) -> Result<LinterContext> {
+    // Llevar la configuración al formato actual (las bases de extends se
+    // migran al cargarlas)
+    let migrated = super::migration::migrate(json_value)?;
+    if let Some(warning) = source.and_then(|s| migrated.warning(&s.name())) {
+        eprintln!("⚠️  {}", warning);
+    }
 
-    // Validar los valores
-    validate_config_values(&config)?;
+    // Resolver la herencia (extends) antes de validar el resultado completo.
+    // Tras una migración las rutas del esquema ya no se corresponden con el
+    // archivo; con extends se traducen a las claves del propio archivo y solo
+    // los problemas que vienen de una base quedan sin ubicar
+    let source = source.filter(|_| !migrated.is_outdated());
+    let own = migrated
+        .value
+        .get("extends")
+        .is_some()
+        .then(|| migrated.value.clone());
+    let json_value = super::extends::resolve_extends(root, migrated.value)?;
+    let merged = own.as_ref().map(|_| json_value.clone());
+
+    // Validar esquema y valores, juntando todos los problemas
+    let (config, problems) = validate_config(json_value)?;
+    let file = source.map_or_else(|| "architect.json".to_string(), |s| s.name());
+    let report = match (source, own, merged) {
+        (Some(source), Some(own), Some(merged)) => {
+            ConfigReport::from_extended(&file, problems, source, &merged, &own)
+        }
+        (source, _, _) => ConfigReport::from_problems(&file, problems, source),
+    };
+    let config = match config {
+        Some(config) if !report.has_errors() => config,
+        _ => return Err(report.into()),
+    };
+    if !report.problems.is_empty() {
+        eprintln!("{:?}", miette::Report::new(report));
+    }
 
     // Cargar configuración de IA (si existe, es opcional)
     let ai_config_path = root.join(".architect.ai.json");
@@ -111,89 +165,219 @@ pub fn load_config(root: &Path) -> Result<LinterContext> {
     // Re-detectamos el framework para el contexto actual
     let framework = crate::detector::detect_framework(root);
 
+    let preset_rules = presets::resolve(&config.architecture_pattern, &config.presets);
+
+    // Las reglas por dueño (owner:@equipo) se evalúan aparte contra CODEOWNERS
+    let codeowners = crate::codeowners::CodeOwners::load(root);
+    let (owner_rules, forbidden_imports): (Vec<ForbiddenRule>, Vec<ForbiddenRule>) = config
+        .forbidden_imports
+        .into_iter()
+        .partition(crate::codeowners::is_owner_rule);
+    if !owner_rules.is_empty() && codeowners.is_none() {
+        return Err(ConfigError::new(
+            "Hay reglas con owner: pero no se encontró un archivo CODEOWNERS".to_string(),
+            "Crea .github/CODEOWNERS (o CODEOWNERS en la raíz) o usa rutas en from/to.".to_string(),
+        )
+        .into());
+    }
+
     Ok(LinterContext {
         max_lines: config.max_lines_per_function,
         framework,
         pattern: config.architecture_pattern,
-        forbidden_imports: config.forbidden_imports,
+        forbidden_imports,
         ignored_paths: config.ignored_paths,
         ai_configs,
         build_command: config.build_command,
         ai_fix_retries: config.ai_fix_retries,
+        preset_rules,
+        history: config.history,
+        owner_rules,
+        codeowners,
+        boundaries: crate::analyzer::boundaries::ModuleBoundaries::new(root, &config.modules),
+        external_imports: crate::packages::ExternalImportRules::new(root, &config.external_imports),
+        dependency_audit: config.dependency_audit,
+        workspace: None,
+        generated_code: config.generated_code,
     })
 }
 
-/// Valida que el JSON siga el esquema oficial
-fn validate_schema(json: &serde_json::Value) -> Result<()> {
-    let schema_json: serde_json::Value = serde_json::from_str(ARCHITECT_SCHEMA).into_diagnostic()?;
-    let compiled = JSONSchema::compile(&schema_json).map_err(|e| {
-        ConfigError::new(
-            format!("Error interno al compilar el esquema: {}", e),
-            "Este es un error en el linter, por favor repórtalo.".to_string(),
-        )
-    })?;
+/// Valida el JSON contra el esquema oficial y, si lo cumple, los valores de
+/// la configuración. Devuelve todos los problemas encontrados; sin errores
+/// también devuelve la configuración deserializada.
+pub(super) fn validate_config(
+    json: serde_json::Value,
+) -> Result<(Option<ConfigFile>, Vec<ConfigProblem>)> {
+    let mut problems = super::diagnostics::schema_problems(&json)?;
+    problems.extend(super::diagnostics::pattern_problems(&json));
+    if problems.iter().any(ConfigProblem::is_error) {
+        return Ok((None, problems));
+    }
 
-    if let Err(errors) = compiled.validate(json) {
-        let mut error_messages = Vec::new();
-        for error in errors {
-            error_messages.push(format!("- {}: {}", error.instance_path, error));
+    let config: ConfigFile = match serde_json::from_value(json) {
+        Ok(config) => config,
+        Err(e) => {
+            problems.push(
+                ConfigProblem::error("", format!("Error en la estructura: {}", e))
+                    .help("Revisa que todos los campos tengan el tipo correcto."),
+            );
+            return Ok((None, problems));
         }
+    };
 
-        return Err(ConfigError::new(
-            "El archivo architect.json no cumple con el esquema esperado".to_string(),
-            format!(
-                "Problemas detectados:\n{}\n\nRevisa la documentación o usa el esquema para autocompletado.",
-                error_messages.join("\n")
-            ),
-        )
-        .into());
-    }
-
-    Ok(())
+    problems.extend(validate_config_values(&config));
+    Ok((Some(config), problems))
 }
 
 /// Valida los valores de la configuración (rangos, lógica, etc.)
-fn validate_config_values(config: &ConfigFile) -> Result<()> {
+fn validate_config_values(config: &ConfigFile) -> Vec<ConfigProblem> {
+    let mut problems = Vec::new();
+
     // Validar que max_lines_per_function esté en un rango razonable
     if config.max_lines_per_function == 0 {
-        return Err(ConfigError::new(
-            "max_lines_per_function no puede ser 0".to_string(),
-            "Usa un valor entre 10 y 500. Recomendado: 20-60 según tu framework.".to_string(),
-        )
-        .into());
+        problems.push(
+            ConfigProblem::error(
+                "/max_lines_per_function",
+                "max_lines_per_function no puede ser 0",
+            )
+            .label("debe ser mayor que 0")
+            .help("Usa un valor entre 10 y 500. Recomendado: 20-60 según tu framework."),
+        );
     }
 
     if config.max_lines_per_function > 1000 {
-        return Err(ConfigError::new(
-            format!(
-                "max_lines_per_function es muy alto: {}",
-                config.max_lines_per_function
-            ),
-            "Un valor tan alto desactiva efectivamente esta validación. Máximo recomendado: 500"
-                .to_string(),
-        )
-        .into());
+        problems.push(
+            ConfigProblem::error(
+                "/max_lines_per_function",
+                format!(
+                    "max_lines_per_function es muy alto: {}",
+                    config.max_lines_per_function
+                ),
+            )
+            .label("máximo recomendado: 500")
+            .help("Un valor tan alto desactiva efectivamente esta validación. Máximo recomendado: 500"),
+        );
     }
 
     // Validar que forbidden_imports tenga reglas únicas (no duplicadas)
-    for (i, rule1) in config.forbidden_imports.iter().enumerate() {
-        for (j, rule2) in config.forbidden_imports.iter().enumerate() {
-            if i != j && rule1.from == rule2.from && rule1.to == rule2.to {
-                return Err(ConfigError::new(
-                    format!("Regla duplicada: from '{}' to '{}'", rule1.from, rule1.to),
-                    "Elimina una de las reglas duplicadas en forbidden_imports.".to_string(),
+    for (i, rule) in config.forbidden_imports.iter().enumerate() {
+        if let Some(first) = config.forbidden_imports[..i]
+            .iter()
+            .position(|other| other.from == rule.from && other.to == rule.to)
+        {
+            problems.push(
+                ConfigProblem::error(
+                    format!("/forbidden_imports/{}", i),
+                    format!("Regla duplicada: from '{}' to '{}'", rule.from, rule.to),
                 )
-                .into());
-            }
+                .label(format!("igual a la regla {}", first + 1))
+                .help("Elimina una de las reglas duplicadas en forbidden_imports."),
+            );
         }
     }
 
+    // Validar que los módulos tengan ruta y no se repitan
+    for (i, module) in config.modules.iter().enumerate() {
+        let path = module.path.trim().trim_matches('/');
+        if path.is_empty() {
+            problems.push(
+                ConfigProblem::error(format!("/modules/{}/path", i), "Módulo sin ruta en modules")
+                    .label("ruta vacía")
+                    .help("Cada módulo necesita un path, p. ej. \"src/modules/billing\" o \"src/modules/*\"."),
+            );
+        } else if config.modules[..i]
+            .iter()
+            .any(|other| other.path.trim().trim_matches('/') == path)
+        {
+            problems.push(
+                ConfigProblem::error(
+                    format!("/modules/{}/path", i),
+                    format!("Módulo duplicado: '{}'", module.path),
+                )
+                .label("ya declarado")
+                .help("Elimina una de las entradas duplicadas en modules."),
+            );
+        }
+    }
+
+    // Validar que cada regla de paquetes tenga deny o allow
+    for (i, rule) in config.external_imports.iter().enumerate() {
+        if rule.deny.is_empty() && rule.allow.is_none() {
+            problems.push(
+                ConfigProblem::error(
+                    format!("/external_imports/{}", i),
+                    format!(
+                        "Regla de external_imports sin deny ni allow (from '{}')",
+                        rule.from.as_deref().unwrap_or("*")
+                    ),
+                )
+                .label("falta deny o allow")
+                .help(
+                    "Añade \"deny\": [\"paquete\"] o \"allow\": [...] (lista blanca) a la regla.",
+                ),
+            );
+        }
+        if rule
+            .from
+            .as_deref()
+            .is_some_and(|from| from.trim().is_empty())
+        {
+            problems.push(
+                ConfigProblem::error(
+                    format!("/external_imports/{}/from", i),
+                    "Regla de external_imports con from vacío",
+                )
+                .label("from vacío")
+                .help("Elimina from para aplicar la regla a todo el proyecto o indica una ruta."),
+            );
+        }
+    }
+
+    // Validar que las reglas entre paquetes tengan from y to
+    for (i, rule) in config.workspace.rules.iter().enumerate() {
+        if rule.from.trim().is_empty() || rule.to.trim().is_empty() {
+            problems.push(
+                ConfigProblem::error(
+                    format!("/workspace/rules/{}", i),
+                    "Regla de workspace.rules con from o to vacío",
+                )
+                .label("from y to son obligatorios")
+                .help("Indica el paquete origen y destino, p. ej. { \"from\": \"libs/*\", \"to\": \"apps/*\" }."),
+            );
+        }
+    }
+
+    // Validar que los ids de presets desactivados/sobreescritos existan
+    let unknown = presets::unknown_rule_ids(&config.architecture_pattern, &config.presets);
+    if !unknown.is_empty() {
+        let available: Vec<&str> = presets::preset_rules(&config.architecture_pattern)
+            .iter()
+            .map(|r| r.id)
+            .collect();
+        problems.push(
+            ConfigProblem::error(
+                "/presets",
+                format!("Reglas de preset desconocidas: {}", unknown.join(", ")),
+            )
+            .on_key()
+            .help(if available.is_empty() {
+                "El architecture_pattern configurado no tiene preset. Elimina la sección presets.disabled/overrides.".to_string()
+            } else {
+                format!("Reglas disponibles para este patrón: {}", available.join(", "))
+            }),
+        );
+    }
+
     // Advertencia si no hay reglas (aunque técnicamente válido)
-    if config.forbidden_imports.is_empty() {
+    if config.forbidden_imports.is_empty()
+        && config.modules.is_empty()
+        && config.external_imports.is_empty()
+        && !config.presets.enabled
+    {
         eprintln!("⚠️  Advertencia: No hay reglas en forbidden_imports. El linter solo validará la longitud de funciones.");
     }
 
-    Ok(())
+    problems
 }
 
 /// Detecta el framework del proyecto (wrapper público)
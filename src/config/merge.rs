@@ -0,0 +1,102 @@
+//! Fusión de configuraciones
+//!
+//! Se usa para heredar la configuración raíz en los paquetes de un monorepo.
+//! La fusión es determinista y se hace sobre el JSON antes de validar:
+//!
+//! - Objetos: se fusionan clave a clave (recursivo)
+//! - `forbidden_imports`: se concatenan; una regla del overlay con el mismo
+//!   `from`/`to` reemplaza a la de la base en su posición original
+//! - Resto de arrays (`ignored_paths`, `modules`, ...): unión sin duplicados,
+//!   primero los de la base
+//! - Escalares: gana el overlay
+
+use serde_json::Value;
+
+/// Fusiona `overlay` sobre `base`
+pub fn merge(base: Value, overlay: Value) -> Value {
+    merge_value(None, base, overlay)
+}
+
+fn merge_value(key: Option<&str>, base: Value, overlay: Value) -> Value {
+    match (base, overlay) {
+        (Value::Object(mut base), Value::Object(overlay)) => {
+            for (k, value) in overlay {
+                let merged = match base.remove(&k) {
+                    Some(existing) => merge_value(Some(&k), existing, value),
+                    None => value,
+                };
+                base.insert(k, merged);
+            }
+            Value::Object(base)
+        }
+        (Value::Array(base), Value::Array(overlay)) if key == Some("forbidden_imports") => {
+            Value::Array(merge_rules(base, overlay))
+        }
+        (Value::Array(mut base), Value::Array(overlay)) => {
+            for value in overlay {
+                if !base.contains(&value) {
+                    base.push(value);
+                }
+            }
+            Value::Array(base)
+        }
+        (_, overlay) => overlay,
+    }
+}
+
+/// Reglas identificadas por (`from`, `to`)
+fn merge_rules(mut base: Vec<Value>, overlay: Vec<Value>) -> Vec<Value> {
+    let rule_key = |rule: &Value| (rule.get("from").cloned(), rule.get("to").cloned());
+    for rule in overlay {
+        match base.iter().position(|r| rule_key(r) == rule_key(&rule)) {
+            Some(index) => base[index] = rule,
+            None => base.push(rule),
+        }
+    }
+    base
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_is_deterministic() {
+        let base = json!({
+            "max_lines_per_function": 40,
+            "architecture_pattern": "Hexagonal",
+            "forbidden_imports": [
+                { "from": "domain", "to": "infrastructure" },
+                { "from": "domain", "to": "axios", "severity": "error" }
+            ],
+            "ignored_paths": ["node_modules/", "dist/"],
+            "history": { "enabled": true, "regression_threshold": 5 }
+        });
+        let overlay = json!({
+            "max_lines_per_function": 60,
+            "forbidden_imports": [
+                { "from": "domain", "to": "axios", "severity": "warning" },
+                { "from": "ui", "to": "db" }
+            ],
+            "ignored_paths": ["dist/", "storybook-static/"],
+            "history": { "regression_threshold": 3 }
+        });
+
+        let merged = merge(base, overlay);
+        assert_eq!(
+            merged,
+            json!({
+                "max_lines_per_function": 60,
+                "architecture_pattern": "Hexagonal",
+                "forbidden_imports": [
+                    { "from": "domain", "to": "infrastructure" },
+                    { "from": "domain", "to": "axios", "severity": "warning" },
+                    { "from": "ui", "to": "db" }
+                ],
+                "ignored_paths": ["node_modules/", "dist/", "storybook-static/"],
+                "history": { "enabled": true, "regression_threshold": 3 }
+            })
+        );
+    }
+}
@@ -1,32 +1,446 @@
-use serde_json::Value;
+//! Migraciones de la configuración
+//!
+//! `version` indica el formato de la configuración; sin `version` se asume
+//! la versión 1. Cada migración pasa de una versión a la siguiente y se
+//! aplican en cadena hasta `CURRENT_VERSION`, así que la configuración de
+//! cualquier versión anterior se sigue cargando:
+//!
+//! - v2: claves renombradas (`max_lines`, `pattern`, `ignore`, `rules`)
+//! - v3: `forbidden_imports` abreviados (`"a -> b"`, `{ "a": "b" }`, reglas
+//!   con `pattern`/`forbidden` o con varios `to`) pasan a reglas `{ from, to }`
+//!
+//! El comando `migrate` reescribe el archivo con el resultado, mostrando
+//! antes las diferencias.
 
-/// Maneja la migración de archivos de configuración antiguos al formato actual.
-/// Por ahora, el formato es estable, pero esta herramienta está preparada
-/// para futuras actualizaciones de esquema.
-pub fn migrate_config(mut config: Value) -> Value {
-    let mut modified = false;
+use miette::Result;
+use serde_json::{json, Map, Value};
+use similar::TextDiff;
 
-    // Ejemplo: Si en el futuro cambiamos 'max_lines_per_function' a 'max_lines'
-    // if let Some(old_val) = config.get_mut("max_lines_per_function") {
-    //     // ... lógica de migración
-    // }
+use super::formats::{ConfigFormat, ConfigSource};
+use super::loader::ConfigError;
 
-    // Asegurar que ignored_paths existe si no estaba
-    if config.get("ignored_paths").is_none() {
-        config["ignored_paths"] = serde_json::json!([
-            "node_modules/",
-            ".git/",
-            "dist/",
-            "build/",
-            "target/",
-            "coverage/"
-        ]);
-        modified = true;
+/// Versión actual del formato de la configuración
+pub const CURRENT_VERSION: u64 = 3;
+
+/// Una migración de la versión `to - 1` a `to`
+struct Migration {
+    to: u64,
+    description: &'static str,
+    /// Devuelve si cambió algo
+    apply: fn(&mut Map<String, Value>) -> bool,
+}
+
+const MIGRATIONS: [Migration; 2] = [
+    Migration {
+        to: 2,
+        description: "claves renombradas (max_lines, pattern, ignore, rules)",
+        apply: rename_legacy_keys,
+    },
+    Migration {
+        to: 3,
+        description: "forbidden_imports abreviados convertidos en reglas { from, to }",
+        apply: expand_forbidden_imports,
+    },
+];
+
+/// Claves antiguas y su nombre actual
+const RENAMED_KEYS: [(&str, &str); 4] = [
+    ("max_lines", "max_lines_per_function"),
+    ("pattern", "architecture_pattern"),
+    ("ignore", "ignored_paths"),
+    ("rules", "forbidden_imports"),
+];
+
+/// Claves antiguas de las reglas y su nombre actual
+const RENAMED_RULE_KEYS: [(&str, &str); 3] = [
+    ("pattern", "from"),
+    ("forbidden", "to"),
+    ("message", "reason"),
+];
+
+/// Resultado de migrar una configuración
+#[derive(Debug, Clone)]
+pub struct Migrated {
+    /// Configuración en el formato actual, con `version`
+    pub value: Value,
+    /// Versión declarada (1 si no tenía)
+    pub from_version: u64,
+    /// Descripción de las migraciones que cambiaron algo
+    pub applied: Vec<&'static str>,
+}
+
+impl Migrated {
+    /// La configuración usa un formato antiguo (alguna migración cambió algo)
+    pub fn is_outdated(&self) -> bool {
+        !self.applied.is_empty()
+    }
+
+    /// Aviso para el usuario cuando la configuración está desactualizada
+    pub fn warning(&self, file: &str) -> Option<String> {
+        self.is_outdated().then(|| {
+            format!(
+                "{} usa el formato de configuración v{} (actual: v{}): {}. Ejecuta `architect-linter-pro migrate` para actualizarlo.",
+                file,
+                self.from_version,
+                CURRENT_VERSION,
+                self.applied.join("; ")
+            )
+        })
+    }
+}
+
+/// Lleva una configuración al formato actual aplicando las migraciones
+/// pendientes en orden
+pub fn migrate(mut value: Value) -> Result<Migrated> {
+    let Some(config) = value.as_object_mut() else {
+        // El esquema reportará que no es un objeto
+        return Ok(Migrated {
+            value,
+            from_version: CURRENT_VERSION,
+            applied: Vec::new(),
+        });
+    };
+
+    let from_version = match config.get("version") {
+        None => 1,
+        Some(v) => v.as_u64().filter(|v| *v >= 1).ok_or_else(|| {
+            ConfigError::new(
+                format!("version inválida: {}", v),
+                format!(
+                    "version es un número entero; la versión actual es {}.",
+                    CURRENT_VERSION
+                ),
+            )
+        })?,
+    };
+    if from_version > CURRENT_VERSION {
+        return Err(ConfigError::new(
+            format!(
+                "La configuración es de la versión {}, pero este linter solo conoce hasta la {}",
+                from_version, CURRENT_VERSION
+            ),
+            "Actualiza architect-linter-pro para usar esta configuración.".to_string(),
+        )
+        .into());
+    }
+
+    let mut applied = Vec::new();
+    for migration in MIGRATIONS.iter().filter(|m| m.to > from_version) {
+        if (migration.apply)(config) {
+            applied.push(migration.description);
+        }
+    }
+    config.insert("version".to_string(), json!(CURRENT_VERSION));
+
+    Ok(Migrated {
+        value,
+        from_version,
+        applied,
+    })
+}
+
+/// v2: renombra las claves antiguas si la actual no está ya presente
+fn rename_legacy_keys(config: &mut Map<String, Value>) -> bool {
+    let mut changed = false;
+    for (old, new) in RENAMED_KEYS {
+        if config.contains_key(new) {
+            continue;
+        }
+        if let Some(value) = config.remove(old) {
+            config.insert(new.to_string(), value);
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// v3: convierte las formas abreviadas de forbidden_imports en reglas
+fn expand_forbidden_imports(config: &mut Map<String, Value>) -> bool {
+    let Some(rules) = config.get_mut("forbidden_imports") else {
+        return false;
+    };
+
+    let mut changed = false;
+    // { "src/domain/": "src/infra/" } o { "src/domain/": ["a", "b"] }
+    if let Value::Object(map) = rules {
+        let expanded = map
+            .iter()
+            .flat_map(|(from, to)| targets(to).into_iter().map(move |to| rule(from, &to)))
+            .collect();
+        *rules = Value::Array(expanded);
+        changed = true;
+    }
+
+    let Value::Array(items) = rules else {
+        return changed;
+    };
+    let mut expanded = Vec::with_capacity(items.len());
+    for item in items.drain(..) {
+        match item {
+            // "src/domain/ -> src/infra/"
+            Value::String(text) => match split_arrow(&text) {
+                Some((from, to)) => {
+                    expanded.push(rule(from, to));
+                    changed = true;
+                }
+                None => expanded.push(Value::String(text)),
+            },
+            Value::Object(mut fields) => {
+                for (old, new) in RENAMED_RULE_KEYS {
+                    if !fields.contains_key(new) {
+                        if let Some(value) = fields.remove(old) {
+                            fields.insert(new.to_string(), value);
+                            changed = true;
+                        }
+                    }
+                }
+                // { "from": "a", "to": ["b", "c"] }: una regla por destino
+                match fields.get("to") {
+                    Some(Value::Array(tos)) => {
+                        for to in tos.clone() {
+                            let mut single = fields.clone();
+                            single.insert("to".to_string(), to);
+                            expanded.push(Value::Object(single));
+                        }
+                        changed = true;
+                    }
+                    _ => expanded.push(Value::Object(fields)),
+                }
+            }
+            other => expanded.push(other),
+        }
+    }
+    *items = expanded;
+    changed
+}
+
+fn rule(from: &str, to: &str) -> Value {
+    json!({ "from": from.trim(), "to": to.trim() })
+}
+
+fn targets(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(to) => vec![to.clone()],
+        Value::Array(tos) => tos
+            .iter()
+            .filter_map(|t| t.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn split_arrow(text: &str) -> Option<(&str, &str)> {
+    ["->", "→", "=>"]
+        .iter()
+        .find_map(|arrow| text.split_once(arrow))
+        .filter(|(from, to)| !from.trim().is_empty() && !to.trim().is_empty())
+}
+
+/// Reescritura de un archivo de configuración al formato actual
+#[derive(Debug, Clone)]
+pub struct FileMigration {
+    pub source: ConfigSource,
+    pub migrated: Migrated,
+    /// Contenido nuevo del archivo
+    pub content: String,
+}
+
+impl FileMigration {
+    /// Calcula el contenido migrado de `source` (sin resolver `extends`: solo
+    /// se reescribe el propio archivo). Las claves conservan el orden del
+    /// archivo original; YAML y TOML pierden los comentarios.
+    pub fn plan(source: ConfigSource) -> Result<Self> {
+        if source.format == ConfigFormat::Pyproject {
+            return Err(ConfigError::new(
+                "migrate no reescribe pyproject.toml".to_string(),
+                "Actualiza la sección [tool.architect] a mano o mueve la configuración a architect.toml."
+                    .to_string(),
+            )
+            .into());
+        }
+        let migrated = migrate(source.parse()?)?;
+        let ordered = ordered(&migrated.value, "", &source);
+        let content = match source.format {
+            ConfigFormat::Json => serde_json::to_string_pretty(&ordered)
+                .map(|json| json + "\n")
+                .map_err(|e| serialize_error(&e))?,
+            ConfigFormat::Yaml => {
+                serde_norway::to_string(&ordered).map_err(|e| serialize_error(&e))?
+            }
+            ConfigFormat::Toml | ConfigFormat::Pyproject => {
+                toml::to_string_pretty(&ordered).map_err(|e| serialize_error(&e))?
+            }
+        };
+        Ok(Self {
+            source,
+            migrated,
+            content,
+        })
+    }
+
+    /// El archivo ya está en el formato actual. Se decide por la versión y
+    /// las migraciones, no por el texto: reescribirlo cambiaría el formato
+    /// (indentación, comentarios) sin cambiar la configuración
+    pub fn is_unchanged(&self) -> bool {
+        self.migrated.applied.is_empty() && self.migrated.from_version == CURRENT_VERSION
+    }
+
+    /// Reescribir el archivo elimina sus comentarios (YAML y TOML)
+    pub fn loses_comments(&self) -> bool {
+        self.source.format != ConfigFormat::Json
+    }
+
+    /// Diferencias entre el archivo actual y el migrado (formato unificado)
+    pub fn diff(&self) -> String {
+        let name = self.source.name();
+        TextDiff::from_lines(&self.source.content, &self.content)
+            .unified_diff()
+            .context_radius(3)
+            .header(&name, &format!("{} (v{})", name, CURRENT_VERSION))
+            .to_string()
+    }
+
+    /// Escribe el contenido migrado en el archivo
+    pub fn write(&self) -> Result<()> {
+        std::fs::write(&self.source.path, &self.content).map_err(|e| {
+            ConfigError::new(
+                format!("No se pudo escribir {}: {}", self.source.name(), e),
+                "Verifica los permisos del archivo.".to_string(),
+            )
+            .into()
+        })
     }
+}
 
-    if modified {
-        eprintln!("ℹ️  La configuración ha sido migrada automáticamente al formato más reciente.");
+/// Orden de las claves que no estaban en el archivo original
+const KEY_ORDER: [&str; 6] = ["from", "to", "severity", "reason", "deny", "allow"];
+
+/// Copia de `value` con las claves de cada objeto en el orden en que
+/// aparecen en el archivo original (`version` primero; una clave renombrada
+/// ocupa el lugar de la antigua y las nuevas van al final)
+fn ordered(value: &Value, pointer: &str, source: &ConfigSource) -> serde_norway::Value {
+    match value {
+        Value::Object(map) => {
+            let renamed = if pointer.is_empty() {
+                &RENAMED_KEYS[..]
+            } else {
+                &RENAMED_RULE_KEYS[..]
+            };
+            let mut keys: Vec<(&String, usize)> = map
+                .keys()
+                .map(|key| {
+                    let names = std::iter::once(key.as_str()).chain(
+                        renamed
+                            .iter()
+                            .filter(|(_, new)| new == key)
+                            .map(|(old, _)| *old),
+                    );
+                    let located = names
+                        .filter_map(|name| source.locate(&format!("{}/{}", pointer, escape(name))))
+                        .min();
+                    let position = match located {
+                        _ if pointer.is_empty() && key == "version" => 0,
+                        Some(offset) => offset + 1,
+                        None => KEY_ORDER
+                            .iter()
+                            .position(|k| k == key)
+                            .map_or(usize::MAX, |i| usize::MAX - KEY_ORDER.len() + i),
+                    };
+                    (key, position)
+                })
+                .collect();
+            keys.sort_by_key(|(_, position)| *position);
+            serde_norway::Value::Mapping(
+                keys.into_iter()
+                    .map(|(key, _)| {
+                        let child = format!("{}/{}", pointer, escape(key));
+                        (key.as_str().into(), ordered(&map[key], &child, source))
+                    })
+                    .collect(),
+            )
+        }
+        Value::Array(items) => serde_norway::Value::Sequence(
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| ordered(item, &format!("{}/{}", pointer, i), source))
+                .collect(),
+        ),
+        other => serde_norway::to_value(other).unwrap_or(serde_norway::Value::Null),
     }
+}
+
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn serialize_error(e: &dyn std::fmt::Display) -> miette::Report {
+    ConfigError::new(
+        format!("No se pudo generar la configuración migrada: {}", e),
+        "Este es un error en el linter, por favor repórtalo.".to_string(),
+    )
+    .into()
+}
 
-    config
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrates_legacy_config_in_chain() {
+        let migrated = migrate(json!({
+            "max_lines": 40,
+            "pattern": "MVC",
+            "rules": {
+                "src/domain/": ["src/infra/", "axios"]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(migrated.from_version, 1);
+        assert_eq!(migrated.applied.len(), 2);
+        assert_eq!(
+            migrated.value,
+            json!({
+                "version": CURRENT_VERSION,
+                "max_lines_per_function": 40,
+                "architecture_pattern": "MVC",
+                "forbidden_imports": [
+                    { "from": "src/domain/", "to": "src/infra/" },
+                    { "from": "src/domain/", "to": "axios" }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_expands_bare_rules_and_skips_current_versions() {
+        let migrated = migrate(json!({
+            "version": 2,
+            "forbidden_imports": [
+                "src/ui/ -> src/db/",
+                { "pattern": "src/api/", "forbidden": "src/db/", "message": "Usa servicios" },
+                { "from": "a", "to": "b" }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(migrated.applied.len(), 1);
+        assert_eq!(
+            migrated.value["forbidden_imports"],
+            json!([
+                { "from": "src/ui/", "to": "src/db/" },
+                { "from": "src/api/", "to": "src/db/", "reason": "Usa servicios" },
+                { "from": "a", "to": "b" }
+            ])
+        );
+
+        // Formato actual sin version: no está desactualizada
+        let current = migrate(json!({ "max_lines_per_function": 40 })).unwrap();
+        assert!(!current.is_outdated());
+        // Las claves antiguas no se tocan a partir de v2
+        let v2 = migrate(json!({ "version": 2, "pattern": "x" })).unwrap();
+        assert!(v2.value.get("pattern").is_some());
+
+        assert!(migrate(json!({ "version": CURRENT_VERSION + 1 })).is_err());
+    }
 }
@@ -2,27 +2,44 @@
 //!
 //! Este módulo contiene toda la lógica relacionada con:
 //! - Tipos de configuración (Framework, ArchPattern, etc.)
-//! - Carga y validación de architect.json
+//! - Carga y validación de architect.json (también en YAML y TOML), con
+//!   diagnósticos que señalan la clave o el valor exacto
 //! - Wizard de configuración con IA
 //! - Gestión de ignored_paths
+//! - Herencia (`extends`) y fusión de configuraciones (también en monorepos)
+//! - Presets de reglas implícitas por patrón
+//! - Migraciones entre versiones del formato (`version`)
 //! - Setup de hooks de git (husky)
 
+pub mod diagnostics;
+pub mod extends;
+pub mod formats;
 mod generator;
 mod husky;
 mod ignored_paths;
 mod loader;
-mod migration;
+pub mod merge;
+pub mod migration;
+pub mod presets;
+mod spans;
 mod types;
 mod wizard;
 
 // Re-export tipos públicos
 pub use types::{
-    AIConfig, AIProvider, ArchError, ArchPattern, ForbiddenRule, Framework, LinterContext, Severity,
+    AIConfig, AIProvider, ArchError, ArchPattern, DependencyAuditConfig, ExternalImportRule,
+    ForbiddenRule, Framework, GeneratedCodeConfig, GeneratedCodeMode, HistoryConfig, LinterContext,
+    ModuleBoundary, Severity, WorkspaceConfig,
 };
 
 // Re-export funciones de loader
-pub use loader::load_config;
+pub use formats::find_config;
 pub use loader::ConfigFile;
+pub use loader::{load_config, load_config_value};
+
+// Re-export tipos de presets (used in tests and library API)
+#[allow(unused_imports)]
+pub use presets::{ActivePresetRule, PresetConfig};
 
 // Re-export funciones de generator (used in tests and library API)
 #[allow(unused_imports)]
@@ -0,0 +1,269 @@
+//! Reglas implícitas por patrón de arquitectura (presets)
+//!
+//! Cada `ArchPattern` soportado (MVC, Hexagonal, Clean) declara un conjunto de
+//! reglas como datos. Los presets son opt-in: solo se aplican si architect.json
+//! incluye `"presets": { "enabled": true }`. Cada regla puede desactivarse o
+//! sobreescribirse individualmente por su `id`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::types::{ArchPattern, ForbiddenRule, Severity};
+
+/// Extensiones TypeScript/JavaScript
+const TS_JS: &[&str] = &["ts", "tsx", "js", "jsx"];
+/// Extensiones Python
+const PYTHON: &[&str] = &["py"];
+/// Extensiones PHP
+const PHP: &[&str] = &["php"];
+/// Aplica a cualquier lenguaje soportado
+const ANY: &[&str] = &[];
+
+/// Regla declarada por un preset
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PresetRule {
+    /// Identificador estable usado para desactivar o sobreescribir la regla
+    pub id: &'static str,
+    pub from: &'static str,
+    pub to: &'static str,
+    pub severity: Severity,
+    pub reason: &'static str,
+    /// Extensiones de archivo a las que aplica (vacío = todas)
+    pub extensions: &'static [&'static str],
+}
+
+const MVC_RULES: &[PresetRule] = &[
+    PresetRule {
+        id: "mvc/controller-repository",
+        from: "controller",
+        to: "repository",
+        severity: Severity::Error,
+        reason: "MVC: Prohibido importar Repositorios en Controladores.",
+        extensions: TS_JS,
+    },
+    PresetRule {
+        id: "mvc/views-models",
+        from: "views",
+        to: "models",
+        severity: Severity::Error,
+        reason: "MVC: Las vistas no deben importar modelos directamente.",
+        extensions: PYTHON,
+    },
+    PresetRule {
+        id: "mvc/controller-model",
+        from: "controller",
+        to: "model",
+        severity: Severity::Error,
+        reason: "MVC: Los controladores no deben importar modelos directamente.",
+        extensions: PHP,
+    },
+];
+
+const HEXAGONAL_RULES: &[PresetRule] = &[
+    PresetRule {
+        id: "hexagonal/domain-application",
+        from: "/domain/",
+        to: "/application/",
+        severity: Severity::Error,
+        reason: "Hexagonal: El dominio no debe depender de la capa de aplicación.",
+        extensions: ANY,
+    },
+    PresetRule {
+        id: "hexagonal/domain-infrastructure",
+        from: "/domain/",
+        to: "/infrastructure/",
+        severity: Severity::Error,
+        reason: "Hexagonal: El dominio no debe depender de la infraestructura.",
+        extensions: ANY,
+    },
+    PresetRule {
+        id: "hexagonal/application-infrastructure",
+        from: "/application/",
+        to: "/infrastructure/",
+        severity: Severity::Error,
+        reason: "Hexagonal: La aplicación debe usar puertos, no la infraestructura directamente.",
+        extensions: ANY,
+    },
+];
+
+const CLEAN_RULES: &[PresetRule] = &[
+    PresetRule {
+        id: "clean/entities-usecases",
+        from: "/entities/",
+        to: "/use-cases/",
+        severity: Severity::Error,
+        reason: "Clean: Las entidades no deben depender de los casos de uso.",
+        extensions: ANY,
+    },
+    PresetRule {
+        id: "clean/entities-adapters",
+        from: "/entities/",
+        to: "/adapters/",
+        severity: Severity::Error,
+        reason: "Clean: Las entidades no deben depender de los adaptadores.",
+        extensions: ANY,
+    },
+    PresetRule {
+        id: "clean/usecases-adapters",
+        from: "/use-cases/",
+        to: "/adapters/",
+        severity: Severity::Error,
+        reason: "Clean: Los casos de uso no deben depender de los adaptadores.",
+        extensions: ANY,
+    },
+    PresetRule {
+        id: "clean/usecases-frameworks",
+        from: "/use-cases/",
+        to: "/frameworks/",
+        severity: Severity::Error,
+        reason: "Clean: Los casos de uso no deben depender de frameworks.",
+        extensions: ANY,
+    },
+];
+
+/// Devuelve las reglas declaradas por el preset de un patrón
+pub fn preset_rules(pattern: &ArchPattern) -> &'static [PresetRule] {
+    match pattern {
+        ArchPattern::MVC => MVC_RULES,
+        ArchPattern::Hexagonal => HEXAGONAL_RULES,
+        ArchPattern::Clean => CLEAN_RULES,
+        ArchPattern::Ninguno | ArchPattern::Custom(_) => &[],
+    }
+}
+
+/// Sobreescritura parcial de una regla de preset
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct PresetOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Sección `presets` de architect.json
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct PresetConfig {
+    /// Activa las reglas implícitas del `architecture_pattern`
+    #[serde(default)]
+    pub enabled: bool,
+    /// Ids de reglas del preset que no deben aplicarse
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled: Vec<String>,
+    /// Sobreescrituras por id de regla
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub overrides: HashMap<String, PresetOverride>,
+}
+
+impl PresetConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Regla de preset ya resuelta contra la configuración del proyecto
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivePresetRule {
+    pub id: String,
+    pub rule: ForbiddenRule,
+    pub extensions: Vec<String>,
+}
+
+impl ActivePresetRule {
+    /// Indica si la regla aplica al lenguaje del archivo dado
+    pub fn applies_to(&self, file_path: &Path) -> bool {
+        if self.extensions.is_empty() {
+            return true;
+        }
+        file_path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|ext| self.extensions.iter().any(|e| e == ext))
+            .unwrap_or(false)
+    }
+}
+
+/// Resuelve las reglas activas del preset aplicando `disabled` y `overrides`
+pub fn resolve(pattern: &ArchPattern, config: &PresetConfig) -> Vec<ActivePresetRule> {
+    if !config.enabled {
+        return Vec::new();
+    }
+
+    preset_rules(pattern)
+        .iter()
+        .filter(|preset| !config.disabled.iter().any(|id| id == preset.id))
+        .map(|preset| {
+            let over = config.overrides.get(preset.id).cloned().unwrap_or_default();
+            ActivePresetRule {
+                id: preset.id.to_string(),
+                rule: ForbiddenRule {
+                    from: over.from.unwrap_or_else(|| preset.from.to_string()),
+                    to: over.to.unwrap_or_else(|| preset.to.to_string()),
+                    severity: Some(over.severity.unwrap_or(preset.severity)),
+                    reason: Some(over.reason.unwrap_or_else(|| preset.reason.to_string())),
+                },
+                extensions: preset.extensions.iter().map(|e| e.to_string()).collect(),
+            }
+        })
+        .collect()
+}
+
+/// Devuelve los ids de `disabled`/`overrides` que no existen en el preset del patrón
+pub fn unknown_rule_ids(pattern: &ArchPattern, config: &PresetConfig) -> Vec<String> {
+    let known: Vec<&str> = preset_rules(pattern).iter().map(|r| r.id).collect();
+    config
+        .disabled
+        .iter()
+        .chain(config.overrides.keys())
+        .filter(|id| !known.contains(&id.as_str()))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_are_opt_in() {
+        let rules = resolve(&ArchPattern::MVC, &PresetConfig::default());
+        assert!(rules.is_empty());
+    }
+
+    #[test]
+    fn test_disable_and_override() {
+        let mut overrides = HashMap::new();
+        overrides.insert(
+            "mvc/views-models".to_string(),
+            PresetOverride {
+                severity: Some(Severity::Warning),
+                ..Default::default()
+            },
+        );
+        let config = PresetConfig {
+            enabled: true,
+            disabled: vec!["mvc/controller-repository".to_string()],
+            overrides,
+        };
+
+        let rules = resolve(&ArchPattern::MVC, &config);
+        assert!(rules.iter().all(|r| r.id != "mvc/controller-repository"));
+        let views = rules.iter().find(|r| r.id == "mvc/views-models").unwrap();
+        assert_eq!(views.rule.get_severity(), Severity::Warning);
+        assert!(views.applies_to(Path::new("app/views/user.py")));
+        assert!(!views.applies_to(Path::new("app/views/user.ts")));
+    }
+
+    #[test]
+    fn test_custom_pattern_has_no_preset() {
+        let config = PresetConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        assert!(resolve(&ArchPattern::Custom("Atomic".into()), &config).is_empty());
+    }
+}
@@ -0,0 +1,512 @@
+//! Posiciones de las claves y valores del archivo de configuración
+//!
+//! Los problemas del esquema se identifican con una ruta JSON
+//! (`/forbidden_imports/0/severity`). Este índice asocia cada ruta con el
+//! rango de su clave y de su valor en el texto original, a partir de las
+//! posiciones que da el parser de cada formato: `toml_edit` conserva los
+//! spans de claves, valores y tablas, y `yaml-rust2` marca cada evento. El
+//! JSON se recorre con un analizador mínimo, ya validado por serde_json.
+
+use super::formats::ConfigFormat;
+use std::collections::HashMap;
+use std::ops::Range;
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+
+/// Rangos (en bytes) de una entrada: la clave, si la tiene (los elementos de
+/// una lista no tienen), y el valor. Las tablas TOML usan su cabecera como valor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Located {
+    pub key: Option<Range<usize>>,
+    pub value: Range<usize>,
+}
+
+/// Rangos de cada ruta JSON de un archivo de configuración
+#[derive(Debug, Clone, Default)]
+pub struct SpanIndex {
+    entries: HashMap<String, Located>,
+}
+
+impl SpanIndex {
+    /// Indexa `content`. Si el texto no se puede analizar el índice queda
+    /// vacío (o parcial) y los diagnósticos se muestran sin fragmento.
+    pub fn build(format: ConfigFormat, content: &str) -> Self {
+        let mut index = Self::default();
+        match format {
+            ConfigFormat::Json => {
+                JsonIndexer {
+                    content,
+                    pos: 0,
+                    index: &mut index,
+                }
+                .value("", None);
+            }
+            ConfigFormat::Yaml => {
+                let mut indexer = YamlIndexer::new(content);
+                if Parser::new_from_str(content)
+                    .load(&mut indexer, false)
+                    .is_ok()
+                {
+                    index = indexer.index;
+                }
+            }
+            ConfigFormat::Toml | ConfigFormat::Pyproject => {
+                if let Ok(document) = toml_edit::ImDocument::parse(content) {
+                    let root = if format == ConfigFormat::Pyproject {
+                        document
+                            .get("tool")
+                            .and_then(|tool| tool.get("architect"))
+                            .and_then(toml_edit::Item::as_table)
+                    } else {
+                        Some(document.as_table())
+                    };
+                    if let Some(root) = root {
+                        index_toml_table(root, "", content, &mut index);
+                    }
+                }
+            }
+        }
+        index
+    }
+
+    pub fn get(&self, pointer: &str) -> Option<&Located> {
+        self.entries.get(pointer)
+    }
+
+    /// Con claves repetidas gana la última, como al deserializar
+    fn insert(&mut self, pointer: String, key: Option<Range<usize>>, value: Range<usize>) {
+        self.entries.insert(pointer, Located { key, value });
+    }
+}
+
+fn child(pointer: &str, segment: &str) -> String {
+    format!(
+        "{}/{}",
+        pointer,
+        segment.replace('~', "~0").replace('/', "~1")
+    )
+}
+
+/// Recorrido de un JSON válido guardando el rango de cada clave y valor
+struct JsonIndexer<'a> {
+    content: &'a str,
+    pos: usize,
+    index: &'a mut SpanIndex,
+}
+
+impl JsonIndexer<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.content.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn value(&mut self, pointer: &str, key: Option<Range<usize>>) -> Option<()> {
+        self.skip_whitespace();
+        let start = self.pos;
+        match self.peek()? {
+            b'{' => {
+                self.pos += 1;
+                loop {
+                    self.skip_whitespace();
+                    if self.peek()? == b'}' {
+                        break;
+                    }
+                    let key_start = self.pos;
+                    self.string()?;
+                    let key = key_start..self.pos;
+                    let name: String = serde_json::from_str(&self.content[key.clone()]).ok()?;
+                    self.skip_whitespace();
+                    if self.peek()? != b':' {
+                        return None;
+                    }
+                    self.pos += 1;
+                    self.value(&child(pointer, &name), Some(key))?;
+                    self.separator();
+                }
+                self.pos += 1;
+            }
+            b'[' => {
+                self.pos += 1;
+                let mut i = 0;
+                loop {
+                    self.skip_whitespace();
+                    if self.peek()? == b']' {
+                        break;
+                    }
+                    self.value(&child(pointer, &i.to_string()), None)?;
+                    self.separator();
+                    i += 1;
+                }
+                self.pos += 1;
+            }
+            b'"' => self.string()?,
+            _ => {
+                while !matches!(
+                    self.peek(),
+                    None | Some(b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r')
+                ) {
+                    self.pos += 1;
+                }
+            }
+        }
+        self.index.insert(pointer.to_string(), key, start..self.pos);
+        Some(())
+    }
+
+    fn separator(&mut self) {
+        self.skip_whitespace();
+        if self.peek() == Some(b',') {
+            self.pos += 1;
+        }
+    }
+
+    fn string(&mut self) -> Option<()> {
+        if self.peek()? != b'"' {
+            return None;
+        }
+        self.pos += 1;
+        loop {
+            match self.peek()? {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return Some(());
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+}
+
+fn index_toml_table(table: &toml_edit::Table, pointer: &str, content: &str, index: &mut SpanIndex) {
+    for (name, _) in table.iter() {
+        if let Some((key, item)) = table.get_key_value(name) {
+            index_toml_item(key.span(), item, &child(pointer, name), content, index);
+        }
+    }
+}
+
+fn index_toml_item(
+    key: Option<Range<usize>>,
+    item: &toml_edit::Item,
+    pointer: &str,
+    content: &str,
+    index: &mut SpanIndex,
+) {
+    match item {
+        toml_edit::Item::None => {}
+        toml_edit::Item::Value(value) => index_toml_value(key, value, pointer, index),
+        toml_edit::Item::Table(table) => {
+            // Las tablas implícitas (`[tool.architect]` para `tool`) no tienen span
+            if let Some(span) = table.span() {
+                index.insert(pointer.to_string(), key, header(content, span));
+            }
+            index_toml_table(table, pointer, content, index);
+        }
+        toml_edit::Item::ArrayOfTables(tables) => {
+            if let Some(span) = tables.iter().next().and_then(toml_edit::Table::span) {
+                index.insert(pointer.to_string(), key, header(content, span));
+            }
+            for (i, table) in tables.iter().enumerate() {
+                let item = child(pointer, &i.to_string());
+                if let Some(span) = table.span() {
+                    index.insert(item.clone(), None, header(content, span));
+                }
+                index_toml_table(table, &item, content, index);
+            }
+        }
+    }
+}
+
+fn index_toml_value(
+    key: Option<Range<usize>>,
+    value: &toml_edit::Value,
+    pointer: &str,
+    index: &mut SpanIndex,
+) {
+    if let Some(span) = value.span() {
+        index.insert(pointer.to_string(), key, span);
+    }
+    match value {
+        toml_edit::Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                index_toml_value(None, item, &child(pointer, &i.to_string()), index);
+            }
+        }
+        toml_edit::Value::InlineTable(table) => {
+            for (name, _) in table.iter() {
+                if let Some((key, toml_edit::Item::Value(value))) = table.get_key_value(name) {
+                    index_toml_value(key.span(), value, &child(pointer, name), index);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Primera línea de una tabla TOML: su cabecera (`[[forbidden_imports]]`)
+fn header(content: &str, span: Range<usize>) -> Range<usize> {
+    let text = content.get(span.clone()).unwrap_or("");
+    let line = text.lines().next().unwrap_or("").trim_end();
+    span.start..span.start + line.len()
+}
+
+/// Mapa o lista YAML abierto durante el recorrido de eventos
+struct YamlFrame {
+    pointer: String,
+    key: Option<Range<usize>>,
+    start: usize,
+    /// Inicio de su primer elemento: en bloque, la marca del evento de
+    /// apertura puede caer después de la primera clave
+    first: Option<usize>,
+    /// `{...}` o `[...]`: termina en el cierre; en bloque, en su último valor
+    flow: bool,
+    kind: YamlKind,
+}
+
+enum YamlKind {
+    /// Clave leída cuyo valor aún no ha llegado
+    Mapping(Option<(String, Range<usize>)>),
+    /// Índice del siguiente elemento
+    Sequence(usize),
+}
+
+struct YamlIndexer<'a> {
+    content: &'a str,
+    line_starts: Vec<usize>,
+    stack: Vec<YamlFrame>,
+    /// Fin del último valor, para cerrar los bloques
+    last_end: usize,
+    index: SpanIndex,
+}
+
+impl<'a> YamlIndexer<'a> {
+    fn new(content: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            content,
+            line_starts,
+            stack: Vec::new(),
+            last_end: 0,
+            index: SpanIndex::default(),
+        }
+    }
+
+    /// Offset en bytes de una marca (línea desde 1, columna en caracteres desde 0)
+    fn offset(&self, mark: Marker) -> usize {
+        let Some(&start) = self.line_starts.get(mark.line().saturating_sub(1)) else {
+            return self.content.len();
+        };
+        self.content[start..]
+            .char_indices()
+            .nth(mark.col())
+            .map_or(self.content.len(), |(i, _)| start + i)
+    }
+
+    /// Ruta y clave del valor que empieza ahora
+    fn next_entry(&self) -> (String, Option<Range<usize>>) {
+        match self.stack.last() {
+            None => (String::new(), None),
+            Some(frame) => match &frame.kind {
+                YamlKind::Mapping(Some((name, key))) => {
+                    (child(&frame.pointer, name), Some(key.clone()))
+                }
+                // Clave compleja (un mapa como clave): no tiene ruta JSON
+                YamlKind::Mapping(None) => (child(&frame.pointer, "?"), None),
+                YamlKind::Sequence(i) => (child(&frame.pointer, &i.to_string()), None),
+            },
+        }
+    }
+
+    /// Anota en el contenedor actual que tiene contenido desde `start`
+    fn touch(&mut self, start: usize) {
+        if let Some(frame) = self.stack.last_mut() {
+            frame.first = Some(frame.first.map_or(start, |first| first.min(start)));
+        }
+    }
+
+    fn advance(&mut self) {
+        if let Some(frame) = self.stack.last_mut() {
+            match &mut frame.kind {
+                YamlKind::Mapping(key) => *key = None,
+                YamlKind::Sequence(i) => *i += 1,
+            }
+        }
+    }
+
+    /// Longitud en el texto de un escalar que empieza en `start`
+    fn scalar_len(&self, start: usize, value: &str, style: TScalarStyle) -> usize {
+        let rest = &self.content[start..];
+        match style {
+            TScalarStyle::SingleQuoted => quoted_len(rest, b'\''),
+            TScalarStyle::DoubleQuoted => quoted_len(rest, b'"'),
+            TScalarStyle::Plain if rest.starts_with(value) => value.len(),
+            // Escalares de varias líneas o de bloque (`|`, `>`): la primera línea
+            _ => {
+                let line = rest.lines().next().unwrap_or("");
+                line.split(" #").next().unwrap_or("").trim_end().len()
+            }
+        }
+    }
+
+    /// Escalar o alias en `start..start + len`: la clave de un mapa que
+    /// espera una o el valor de la entrada actual
+    fn scalar(&mut self, start: usize, len: usize, text: String) {
+        let end = start + len;
+        self.last_end = end;
+        self.touch(start);
+        if let Some(YamlFrame {
+            kind: YamlKind::Mapping(key @ None),
+            ..
+        }) = self.stack.last_mut()
+        {
+            *key = Some((text, start..end));
+            return;
+        }
+        let (pointer, key) = self.next_entry();
+        self.index.insert(pointer, key, start..end);
+        self.advance();
+    }
+}
+
+impl MarkedEventReceiver for YamlIndexer<'_> {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        let start = self.offset(mark);
+        match event {
+            Event::Scalar(value, style, ..) => {
+                let len = self.scalar_len(start, &value, style);
+                self.scalar(start, len, value);
+            }
+            Event::Alias(_) => {
+                let rest = &self.content[start..];
+                let len = rest
+                    .find(|c: char| c.is_whitespace() || matches!(c, ',' | ']' | '}'))
+                    .unwrap_or(rest.len());
+                self.scalar(start, len, rest[..len].to_string());
+            }
+            Event::MappingStart(..) | Event::SequenceStart(..) => {
+                let (pointer, key) = self.next_entry();
+                let kind = if matches!(event, Event::MappingStart(..)) {
+                    YamlKind::Mapping(None)
+                } else {
+                    YamlKind::Sequence(0)
+                };
+                self.stack.push(YamlFrame {
+                    pointer,
+                    key,
+                    start,
+                    first: None,
+                    flow: matches!(self.content.as_bytes().get(start), Some(b'{' | b'[')),
+                    kind,
+                });
+            }
+            Event::MappingEnd | Event::SequenceEnd => {
+                let Some(frame) = self.stack.pop() else {
+                    return;
+                };
+                let (begin, end) = if frame.flow {
+                    (frame.start, (start + 1).min(self.content.len()))
+                } else {
+                    let begin = frame.first.unwrap_or(frame.start);
+                    (begin, self.last_end.max(begin))
+                };
+                self.last_end = end;
+                self.touch(begin);
+                self.index.insert(frame.pointer, frame.key, begin..end);
+                self.advance();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Longitud de una cadena que empieza con la comilla `quote`, incluidas ambas
+fn quoted_len(text: &str, quote: u8) -> usize {
+    let bytes = text.as_bytes();
+    let mut i = 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if quote == b'"' => i += 1,
+            b'\n' => return i,
+            b if b == quote => return i + 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text<'a>(content: &'a str, range: &Range<usize>) -> &'a str {
+        &content[range.clone()]
+    }
+
+    #[test]
+    fn test_nested_objects_and_repeated_keys() {
+        // "from" aparece en varios niveles y elementos: cada ruta tiene su rango
+        let content = "{\n  \"forbidden_imports\": [\n    { \"from\": \"a\", \"to\": \"b\" },\n    { \"from\": \"c\", \"to\": { \"from\": 1 } }\n  ]\n}\n";
+        let index = SpanIndex::build(ConfigFormat::Json, content);
+        let located = index.get("/forbidden_imports/1/to/from").unwrap();
+        assert_eq!(text(content, located.key.as_ref().unwrap()), "\"from\"");
+        assert_eq!(text(content, &located.value), "1");
+        let located = index.get("/forbidden_imports/1/from").unwrap();
+        assert_eq!(text(content, &located.value), "\"c\"");
+    }
+
+    #[test]
+    fn test_yaml_blocks_and_flow_collections() {
+        let content = "forbidden_imports:\n  - from: a # origen\n    to: 'b'\n  - { from: c, to: d }\nignored_paths: [dist/, build/]\n";
+        let index = SpanIndex::build(ConfigFormat::Yaml, content);
+        assert_eq!(
+            text(content, &index.get("/forbidden_imports/0").unwrap().value),
+            "from: a # origen\n    to: 'b'"
+        );
+        assert_eq!(
+            text(
+                content,
+                &index.get("/forbidden_imports/0/from").unwrap().value
+            ),
+            "a"
+        );
+        assert_eq!(
+            text(
+                content,
+                &index.get("/forbidden_imports/0/to").unwrap().value
+            ),
+            "'b'"
+        );
+        assert_eq!(
+            text(content, &index.get("/forbidden_imports/1").unwrap().value),
+            "{ from: c, to: d }"
+        );
+        assert_eq!(
+            text(content, &index.get("/ignored_paths/1").unwrap().value),
+            "build/"
+        );
+    }
+
+    #[test]
+    fn test_toml_tables_and_inline_values() {
+        let content = "[history]\nenabled = true\n\n[[forbidden_imports]]\nfrom = \"a\"\n\n[[forbidden_imports]]\nfrom = \"c\"\nexcept = [\"x\", \"y\"]\n";
+        let index = SpanIndex::build(ConfigFormat::Toml, content);
+        // La segunda cabecera, no la primera con el mismo texto
+        let header = &index.get("/forbidden_imports/1").unwrap().value;
+        assert_eq!(text(content, header), "[[forbidden_imports]]");
+        assert_eq!(Some(header.start), content.rfind("[[forbidden_imports]]"));
+        let located = index.get("/forbidden_imports/1/except/1").unwrap();
+        assert_eq!(text(content, &located.value), "\"y\"");
+        let located = index.get("/history/enabled").unwrap();
+        assert_eq!(text(content, located.key.as_ref().unwrap()), "enabled");
+        assert_eq!(text(content, &located.value), "true");
+    }
+}
@@ -154,6 +154,65 @@ impl ForbiddenRule {
     }
 }
 
+/// Módulo con punto de entrada público (`modules` en architect.json)
+///
+/// Desde fuera del módulo solo se puede importar su entrada pública
+/// (`index.ts`/`index.js`/`__init__.py` en la raíz del módulo) y los archivos
+/// listados en `exports`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ModuleBoundary {
+    /// Directorio del módulo; `*` en un segmento declara un módulo por carpeta
+    /// (p. ej. `src/modules/*`)
+    pub path: String,
+    /// Archivos públicos adicionales, relativos al módulo (patrones estilo gitignore)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exports: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Regla sobre paquetes de terceros (`external_imports` en architect.json)
+///
+/// `deny` prohíbe paquetes concretos; `allow` activa el modo lista blanca
+/// (solo esos paquetes, además de los módulos nativos del runtime).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ExternalImportRule {
+    /// Archivos a los que aplica la regla; sin `from` aplica a todo el proyecto
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Rutas excluidas de la regla
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub except: Vec<String>,
+    /// Paquetes prohibidos (admite `*`, p. ej. `@nestjs/*`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+    /// Únicos paquetes permitidos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl ExternalImportRule {
+    pub fn get_severity(&self) -> Severity {
+        self.severity.unwrap_or(Severity::Error)
+    }
+
+    /// Si la regla aplica al archivo (según `from` y `except`)
+    pub fn applies_to(&self, file_path: &str) -> bool {
+        use crate::parsers::typescript::matches_pattern;
+        let included = self
+            .from
+            .as_deref()
+            .is_none_or(|from| matches_pattern(file_path, from));
+        included && !self.except.iter().any(|e| matches_pattern(file_path, e))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AIProvider {
     Claude,
@@ -201,6 +260,152 @@ impl Default for AIConfig {
     }
 }
 
+/// Configuración del historial de Health Score (`history` en architect.json)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HistoryConfig {
+    /// Registrar cada análisis completo en `.architect/history.jsonl`
+    /// (desactivado por defecto para no escribir en el proyecto sin pedirlo)
+    #[serde(default)]
+    pub enabled: bool,
+    /// Caída de puntos (total o por componente) que se considera regresión
+    #[serde(default = "default_regression_threshold")]
+    pub regression_threshold: u8,
+}
+
+fn default_regression_threshold() -> u8 {
+    5
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            regression_threshold: default_regression_threshold(),
+        }
+    }
+}
+
+impl HistoryConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Configuración de la auditoría de dependencias (`dependency_audit` en architect.json)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct DependencyAuditConfig {
+    /// Paquetes que nunca se reportan (admite `*`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+    /// Rutas adicionales que se consideran código de desarrollo (tests, scripts)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dev_paths: Vec<String>,
+}
+
+impl DependencyAuditConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Qué hacer con el código generado o vendorizado
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum GeneratedCodeMode {
+    /// No se analiza
+    #[default]
+    Skip,
+    /// Se analiza, pero sus violaciones pasan a info y no cuenta en la complejidad
+    Downweight,
+    /// Se analiza como el resto del código
+    Analyze,
+}
+
+/// Detección de código generado y vendorizado (`generated_code` en architect.json)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GeneratedCodeConfig {
+    #[serde(default)]
+    pub mode: GeneratedCodeMode,
+    /// Marcadores en la cabecera del archivo (sin distinguir mayúsculas)
+    #[serde(default = "default_generated_markers")]
+    pub markers: Vec<String>,
+    /// Líneas más largas que esto delatan un archivo minificado (0 lo desactiva)
+    #[serde(default = "default_minified_line_length")]
+    pub max_line_length: usize,
+    /// Directorios de código de terceros (patrones estilo .gitignore)
+    #[serde(default = "default_vendored_dirs")]
+    pub vendored_dirs: Vec<String>,
+    /// Rutas que nunca se consideran generadas (patrones estilo .gitignore)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+}
+
+fn default_generated_markers() -> Vec<String> {
+    [
+        "@generated",
+        "DO NOT EDIT",
+        "auto-generated",
+        "autogenerated",
+    ]
+    .iter()
+    .map(|m| m.to_string())
+    .collect()
+}
+
+fn default_minified_line_length() -> usize {
+    500
+}
+
+fn default_vendored_dirs() -> Vec<String> {
+    [
+        "vendor/",
+        "vendored/",
+        "third_party/",
+        "third-party/",
+        "bower_components/",
+    ]
+    .iter()
+    .map(|d| d.to_string())
+    .collect()
+}
+
+impl Default for GeneratedCodeConfig {
+    fn default() -> Self {
+        Self {
+            mode: GeneratedCodeMode::default(),
+            markers: default_generated_markers(),
+            max_line_length: default_minified_line_length(),
+            vendored_dirs: default_vendored_dirs(),
+            include: Vec::new(),
+        }
+    }
+}
+
+impl GeneratedCodeConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Configuración de monorepo (`workspace` en el architect.json raíz)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct WorkspaceConfig {
+    /// Paquetes del monorepo (globs relativos a la raíz). Si se indica,
+    /// sustituye a la detección automática (npm/pnpm/yarn, Nx, Cargo, Python)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<String>,
+    /// Reglas entre paquetes: `from`/`to` son globs sobre la ruta o el nombre
+    /// del paquete (p. ej. `{ "from": "libs/*", "to": "apps/*" }`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<ForbiddenRule>,
+}
+
+impl WorkspaceConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 #[derive(Default)]
 pub struct LinterContext {
     pub max_lines: usize,
@@ -214,6 +419,24 @@ pub struct LinterContext {
     pub ai_configs: Vec<AIConfig>,
     pub build_command: Option<String>,
     pub ai_fix_retries: usize,
+    /// Reglas implícitas del patrón activadas vía `presets`
+    pub preset_rules: Vec<super::presets::ActivePresetRule>,
+    /// Configuración del historial de Health Score
+    pub history: HistoryConfig,
+    /// Reglas de forbidden_imports que apuntan a dueños (`owner:@equipo`)
+    pub owner_rules: Vec<ForbiddenRule>,
+    /// CODEOWNERS del proyecto, si existe
+    pub codeowners: Option<crate::codeowners::CodeOwners>,
+    /// Módulos con entrada pública (prohíben imports profundos desde fuera)
+    pub boundaries: Option<crate::analyzer::boundaries::ModuleBoundaries>,
+    /// Reglas sobre paquetes de terceros (npm, pip, composer)
+    pub external_imports: Option<crate::packages::ExternalImportRules>,
+    /// Configuración de la auditoría de dependencias (`deps`)
+    pub dependency_audit: DependencyAuditConfig,
+    /// Monorepo al que pertenece el paquete analizado (reglas entre paquetes)
+    pub workspace: Option<std::sync::Arc<crate::workspace::Workspace>>,
+    /// Detección de código generado y vendorizado
+    pub generated_code: GeneratedCodeConfig,
 }
 
 #[derive(Error, Debug, Diagnostic)]
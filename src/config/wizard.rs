@@ -11,9 +11,7 @@ use super::types::{AIConfig, ArchPattern, ForbiddenRule, LinterContext};
 
 /// Orquestador de configuración: Carga silenciosa o Wizard con IA
 pub fn setup_or_load_config(root: &Path) -> Result<Arc<LinterContext>> {
-    let config_path = root.join("architect.json");
-
-    if config_path.exists() {
+    if super::formats::find_config(root).is_some() {
         // MODO AUTOMÁTICO: carga silenciosa
         let ctx = super::loader::load_config(root)?;
         return Ok(Arc::new(ctx));
@@ -141,12 +139,20 @@ pub fn save_config_from_wizard(
 
     // Valores por defecto para el primer architect.json
     let config = ConfigFile {
+        version: Some(super::migration::CURRENT_VERSION),
         max_lines_per_function: max_lines,
         architecture_pattern,
         forbidden_imports: forbidden_imports.clone(),
         ignored_paths: ignored_paths.clone(),
         build_command: suggested_build_command,
         ai_fix_retries: 3,
+        presets: Default::default(),
+        history: Default::default(),
+        modules: Vec::new(),
+        external_imports: Vec::new(),
+        dependency_audit: Default::default(),
+        workspace: Default::default(),
+        generated_code: Default::default(),
     };
 
     let json = serde_json::to_string_pretty(&config).into_diagnostic()?;
@@ -183,6 +189,15 @@ pub fn save_config_from_wizard(
         ai_configs,
         build_command: config.build_command,
         ai_fix_retries: config.ai_fix_retries,
+        preset_rules: Vec::new(),
+        history: config.history,
+        owner_rules: Vec::new(),
+        codeowners: crate::codeowners::CodeOwners::load(root),
+        boundaries: None,
+        external_imports: None,
+        dependency_audit: config.dependency_audit,
+        workspace: None,
+        generated_code: config.generated_code,
     })
 }
 
@@ -0,0 +1,308 @@
+//! Persistent analysis daemon
+//!
+//! `architect-linter-pro --daemon` keeps the project analyzed in memory and
+//! answers JSON-RPC requests on a Unix socket at `.architect/daemon.sock`,
+//! so editors, git hooks and scripts get answers without re-parsing the
+//! project:
+//!
+//! - `protocol`: request and response messages
+//! - `state`: configuration and the incremental analysis of the project
+//!
+//! A file-system watcher keeps the state current: changed files are
+//! re-parsed and patched into the result, the same way watch mode does. `architect-linter-pro rpc <METHOD>`
+//! is a small client for scripts.
+
+pub mod protocol;
+pub mod state;
+
+use crate::analysis_result::{AnalysisResult, CategorizedViolation};
+use crate::history::HISTORY_DIR;
+use protocol::{Method, Request, Response, RpcError};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+/// Socket file inside `HISTORY_DIR`
+pub const SOCKET_FILE: &str = "daemon.sock";
+/// PID file inside `HISTORY_DIR`
+pub const PID_FILE: &str = "daemon.pid";
+/// Log file (stdout and stderr of the daemon) inside `HISTORY_DIR`
+pub const LOG_FILE: &str = "daemon.log";
+
+/// Path of the daemon socket of a project
+pub fn socket_path(project_root: &Path) -> PathBuf {
+    project_root.join(HISTORY_DIR).join(SOCKET_FILE)
+}
+
+#[cfg(unix)]
+pub use unix::{request, serve};
+
+#[cfg(unix)]
+mod unix {
+    use super::state::DaemonState;
+    use super::*;
+    use miette::{IntoDiagnostic, Result};
+    use notify::{Config, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::sync::mpsc::{channel, Sender};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    /// Quiet period after the last file event before re-analyzing
+    const DEBOUNCE: Duration = Duration::from_millis(200);
+
+    enum DaemonEvent {
+        FileNotify(notify::Result<notify::Event>),
+        Shutdown,
+    }
+
+    /// Analyze the project and serve requests until `shutdown`
+    pub fn serve(project_root: &Path) -> Result<()> {
+        let socket = socket_path(project_root);
+        if UnixStream::connect(&socket).is_ok() {
+            return Err(miette::miette!(
+                "Ya hay un daemon escuchando en {}",
+                socket.display()
+            ));
+        }
+        // A socket left behind by a daemon that did not shut down cleanly
+        let _ = std::fs::remove_file(&socket);
+        if let Some(dir) = socket.parent() {
+            std::fs::create_dir_all(dir).into_diagnostic()?;
+        }
+
+        let root = project_root
+            .canonicalize()
+            .unwrap_or_else(|_| project_root.to_path_buf());
+        let state = Arc::new(Mutex::new(DaemonState::new(&root)?));
+        let listener = UnixListener::bind(&socket).into_diagnostic()?;
+        tracing::info!("Daemon listening on {}", socket.display());
+
+        let (tx, rx) = channel::<DaemonEvent>();
+        let watcher_tx = tx.clone();
+        let mut watcher = RecommendedWatcher::new(
+            move |res| {
+                let _ = watcher_tx.send(DaemonEvent::FileNotify(res));
+            },
+            Config::default(),
+        )
+        .into_diagnostic()?;
+        watcher
+            .watch(&root, RecursiveMode::Recursive)
+            .into_diagnostic()?;
+
+        let accept_state = Arc::clone(&state);
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let state = Arc::clone(&accept_state);
+                let tx = tx.clone();
+                std::thread::spawn(move || {
+                    if let Err(e) = handle_connection(stream, &state, &tx) {
+                        tracing::debug!("Connection closed: {}", e);
+                    }
+                });
+            }
+        });
+
+        let mut changed: Vec<PathBuf> = Vec::new();
+        let mut last_event = Instant::now();
+        loop {
+            match rx.recv_timeout(Duration::from_millis(50)) {
+                Ok(DaemonEvent::FileNotify(Ok(event))) => {
+                    if matches!(
+                        event.kind,
+                        EventKind::Modify(_) | EventKind::Create(_) | EventKind::Remove(_)
+                    ) {
+                        let mut state = state.lock().expect("Failed to lock mutex");
+                        for path in event.paths {
+                            if !changed.contains(&path) && state.is_relevant(&path) {
+                                changed.push(path);
+                            }
+                        }
+                        last_event = Instant::now();
+                    }
+                }
+                Ok(DaemonEvent::FileNotify(Err(e))) => {
+                    tracing::warn!("File watcher error: {:?}", e);
+                }
+                Ok(DaemonEvent::Shutdown) => break,
+                Err(_) => {}
+            }
+
+            if !changed.is_empty() && last_event.elapsed() >= DEBOUNCE {
+                let mut state = state.lock().expect("Failed to lock mutex");
+                if let Err(e) = state.apply_changes(&changed) {
+                    tracing::error!("Re-analysis failed: {}", e);
+                }
+                changed.clear();
+            }
+        }
+
+        drop(watcher);
+        state.lock().expect("Failed to lock mutex").save_cache();
+        let _ = std::fs::remove_file(&socket);
+        let _ = std::fs::remove_file(root.join(HISTORY_DIR).join(PID_FILE));
+        tracing::info!("Daemon stopped");
+        Ok(())
+    }
+
+    /// Send one request to the daemon of `project_root` and return its result
+    pub fn request(project_root: &Path, method: &str, params: Value) -> Result<Value> {
+        let socket = socket_path(project_root);
+        let mut stream = UnixStream::connect(&socket).map_err(|e| {
+            miette::miette!(
+                "No hay un daemon escuchando en {} ({}). Inícialo con --daemon.",
+                socket.display(),
+                e
+            )
+        })?;
+        let request = Request::new(1, method, params);
+        writeln!(
+            stream,
+            "{}",
+            serde_json::to_string(&request).into_diagnostic()?
+        )
+        .into_diagnostic()?;
+
+        let mut line = String::new();
+        BufReader::new(stream)
+            .read_line(&mut line)
+            .into_diagnostic()?;
+        let response: Response = serde_json::from_str(&line).into_diagnostic()?;
+        match response.error {
+            Some(error) => Err(miette::miette!("{} (código {})", error.message, error.code)),
+            None => Ok(response.result.unwrap_or(Value::Null)),
+        }
+    }
+
+    /// Answer newline-delimited requests until the client disconnects
+    fn handle_connection(
+        stream: UnixStream,
+        state: &Mutex<DaemonState>,
+        events: &Sender<DaemonEvent>,
+    ) -> std::io::Result<()> {
+        let reader = BufReader::new(stream.try_clone()?);
+        let mut writer = stream;
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (response, shutdown) = match serde_json::from_str::<Request>(&line) {
+                Err(e) => (
+                    Response::err(
+                        Value::Null,
+                        RpcError::new(protocol::PARSE_ERROR, e.to_string()),
+                    ),
+                    false,
+                ),
+                Ok(request) => match Method::parse(&request) {
+                    Err(error) => (Response::err(request.id, error), false),
+                    Ok(method) => {
+                        let shutdown = method == Method::Shutdown;
+                        let response = match handle(state, method) {
+                            Ok(result) => Response::ok(request.id, result),
+                            Err(error) => Response::err(request.id, error),
+                        };
+                        (response, shutdown)
+                    }
+                },
+            };
+            writeln!(writer, "{}", serde_json::to_string(&response)?)?;
+            writer.flush()?;
+            if shutdown {
+                let _ = events.send(DaemonEvent::Shutdown);
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle(state: &Mutex<DaemonState>, method: Method) -> Result<Value, RpcError> {
+        let mut state = state
+            .lock()
+            .map_err(|_| RpcError::new(protocol::INTERNAL_ERROR, "Estado del daemon corrupto"))?;
+        match method {
+            Method::Analyze { full } => {
+                state
+                    .analyze(full)
+                    .map_err(|e| RpcError::new(protocol::INTERNAL_ERROR, e.to_string()))?;
+                let mut summary = summary_json(state.result());
+                summary["duration_ms"] = json!(state.last_analysis().as_millis());
+                Ok(summary)
+            }
+            Method::Violations { file } => Ok(json!({
+                "file": file,
+                "violations": state
+                    .violations_for(&file)
+                    .into_iter()
+                    .map(violation_json)
+                    .collect::<Vec<_>>(),
+            })),
+            Method::Score => Ok(score_json(state.result())),
+            Method::Shutdown => Ok(json!({ "stopping": true })),
+        }
+    }
+}
+
+#[cfg(not(unix))]
+pub fn serve(_project_root: &Path) -> miette::Result<()> {
+    Err(miette::miette!(
+        "El daemon usa sockets Unix y no está soportado en Windows."
+    ))
+}
+
+#[cfg(not(unix))]
+pub fn request(_project_root: &Path, _method: &str, _params: Value) -> miette::Result<Value> {
+    Err(miette::miette!(
+        "El daemon usa sockets Unix y no está soportado en Windows."
+    ))
+}
+
+/// Files, violation counts, cycles and score of a result
+fn summary_json(result: &AnalysisResult) -> Value {
+    json!({
+        "files_analyzed": result.files_analyzed,
+        "total_violations": result.violations.len(),
+        "blocked_violations": result.blocked_count(),
+        "warning_violations": result.warning_count(),
+        "circular_dependencies": result.circular_dependencies.len(),
+        "long_functions": result.long_functions.len(),
+        "health_score": score_json(result),
+    })
+}
+
+/// Health score in the shape of the JSON report
+fn score_json(result: &AnalysisResult) -> Value {
+    result
+        .health_score
+        .as_ref()
+        .map(|s| {
+            json!({
+                "total": s.total,
+                "grade": s.grade.as_str(),
+                "components": {
+                    "layer_isolation": s.components.layer_isolation,
+                    "circular_deps": s.components.circular_deps,
+                    "complexity": s.components.complexity,
+                    "violations": s.components.violations,
+                }
+            })
+        })
+        .unwrap_or(Value::Null)
+}
+
+/// Violation in the shape of the JSON report, plus the rule's reason
+fn violation_json(cv: &CategorizedViolation) -> Value {
+    json!({
+        "file": cv.violation.file_path.to_string_lossy().to_string(),
+        "line": cv.violation.line_number,
+        "category": cv.category.as_str(),
+        "rule": {
+            "from": cv.violation.rule.from,
+            "to": cv.violation.rule.to,
+            "reason": cv.violation.rule.reason,
+        },
+        "import": cv.violation.offensive_import,
+    })
+}
@@ -0,0 +1,156 @@
+//! JSON-RPC 2.0 messages of the daemon socket
+//!
+//! Each message is one line of JSON. Methods:
+//!
+//! - `analyze` (`{ "full": bool }`): re-analyze and return the summary
+//! - `violations` (`{ "file": "src/app.ts" }`): violations of one file
+//! - `score`: health score and its components
+//! - `shutdown`: stop the daemon
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Request {
+    pub jsonrpc: String,
+    #[serde(default)]
+    pub id: Value,
+    pub method: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub params: Value,
+}
+
+impl Request {
+    pub fn new(id: u64, method: &str, params: Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id: Value::from(id),
+            method: method.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub jsonrpc: String,
+    pub id: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+impl Response {
+    pub fn ok(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    pub fn err(id: Value, error: RpcError) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: None,
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// A request the daemon understands
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Method {
+    Analyze { full: bool },
+    Violations { file: String },
+    Score,
+    Shutdown,
+}
+
+impl Method {
+    pub fn parse(request: &Request) -> Result<Self, RpcError> {
+        if request.jsonrpc != "2.0" {
+            return Err(RpcError::new(
+                INVALID_REQUEST,
+                "Se esperaba jsonrpc \"2.0\"",
+            ));
+        }
+        match request.method.as_str() {
+            "analyze" => Ok(Method::Analyze {
+                full: request
+                    .params
+                    .get("full")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+            }),
+            "violations" => request
+                .params
+                .get("file")
+                .and_then(Value::as_str)
+                .map(|file| Method::Violations {
+                    file: file.to_string(),
+                })
+                .ok_or_else(|| RpcError::new(INVALID_PARAMS, "Falta el parámetro \"file\"")),
+            "score" => Ok(Method::Score),
+            "shutdown" => Ok(Method::Shutdown),
+            other => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Método no soportado: {}", other),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_methods() {
+        let request: Request = serde_json::from_value(json!({
+            "jsonrpc": "2.0", "id": 1, "method": "violations", "params": { "file": "src/app.ts" }
+        }))
+        .unwrap();
+        assert_eq!(
+            Method::parse(&request).unwrap(),
+            Method::Violations {
+                file: "src/app.ts".to_string()
+            }
+        );
+
+        let request = Request::new(2, "violations", Value::Null);
+        assert_eq!(Method::parse(&request).unwrap_err().code, INVALID_PARAMS);
+        let request = Request::new(3, "lint", Value::Null);
+        assert_eq!(Method::parse(&request).unwrap_err().code, METHOD_NOT_FOUND);
+        let request = Request::new(4, "analyze", Value::Null);
+        assert_eq!(
+            Method::parse(&request).unwrap(),
+            Method::Analyze { full: false }
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! In-memory project state kept by the daemon
+//!
+//! The configuration and an `IncrementalAnalyzer` (analysis cache, import
+//! graph and last `AnalysisResult`) live for the whole session. File
+//! changes re-parse only the changed files and patch the result, so
+//! queries are answered from memory. The ignore rules are loaded once too,
+//! since every file-system event is checked against them.
+
+use crate::analysis_result::{AnalysisResult, CategorizedViolation};
+use crate::cache::{self, AnalysisCache};
+use crate::config::{self, LinterContext, Severity};
+use crate::discovery::{self, IgnoreExplanation};
+use crate::ignore_rules::{IgnoreRules, ARCHITECT_IGNORE};
+use crate::watch::incremental::IncrementalAnalyzer;
+use miette::Result;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Project state shared by the daemon's connections
+pub struct DaemonState {
+    root: PathBuf,
+    ctx: Arc<LinterContext>,
+    analyzer: IncrementalAnalyzer,
+    /// Rules of the ignore files, reloaded when one of them changes
+    ignore_rules: IgnoreRules,
+    /// Duration of the last (re-)analysis
+    last_analysis: Duration,
+}
+
+impl DaemonState {
+    /// Load the configuration of `root` and run the initial analysis,
+    /// reusing the cache on disk
+    pub fn new(root: &Path) -> Result<Self> {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let ctx = Arc::new(config::load_config(&root)?);
+        let config_hash = cache::hash_config(&ctx);
+        let cache = AnalysisCache::load(&root, &config_hash)
+            .unwrap_or_else(|| AnalysisCache::new(config_hash));
+        let started = Instant::now();
+        let analyzer =
+            IncrementalAnalyzer::new(&root, Arc::clone(&ctx), Severity::Info, Some(cache))?;
+        let ignore_rules = IgnoreRules::load(&root);
+        let mut state = Self {
+            root,
+            ctx,
+            analyzer,
+            ignore_rules,
+            last_analysis: Duration::ZERO,
+        };
+        state.finished(started);
+        Ok(state)
+    }
+
+    /// Last analysis result, with cycles and health score
+    pub fn result(&self) -> &AnalysisResult {
+        self.analyzer.result()
+    }
+
+    pub fn last_analysis(&self) -> Duration {
+        self.last_analysis
+    }
+
+    /// Re-analyze the project. Unchanged files are served from the cache;
+    /// `full` reloads the configuration and discards the cache first.
+    pub fn analyze(&mut self, full: bool) -> Result<()> {
+        let started = Instant::now();
+        let cache = if full {
+            self.ctx = Arc::new(config::load_config(&self.root)?);
+            Some(AnalysisCache::new(cache::hash_config(&self.ctx)))
+        } else {
+            self.analyzer.cache()
+        };
+        self.analyzer =
+            IncrementalAnalyzer::new(&self.root, Arc::clone(&self.ctx), Severity::Info, cache)?;
+        self.finished(started);
+        Ok(())
+    }
+
+    /// Whether a change to `path` affects the analysis: a source file that
+    /// discovery would pick up, the configuration file or an ignore file
+    pub fn is_relevant(&mut self, path: &Path) -> bool {
+        if self.is_config_file(path) || is_ignore_file(path) {
+            return true;
+        }
+        let Ok(rel) = path.strip_prefix(&self.root) else {
+            return false;
+        };
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        matches!(
+            discovery::explain_ignore_with(
+                &mut self.ignore_rules,
+                &self.ctx.ignored_paths,
+                &rel,
+                false
+            ),
+            IgnoreExplanation::NotIgnored { .. }
+        )
+    }
+
+    /// Apply file-system changes reported by the watcher
+    pub fn apply_changes(&mut self, changed: &[PathBuf]) -> Result<()> {
+        if changed.iter().any(|path| self.is_config_file(path)) {
+            tracing::info!("Configuration changed, reloading");
+            self.ignore_rules = IgnoreRules::load(&self.root);
+            return self.analyze(true);
+        }
+        if changed.iter().any(|path| is_ignore_file(path)) {
+            // Files may enter or leave the analysis: discover them again
+            tracing::info!("Ignore files changed, re-analyzing");
+            self.ignore_rules = IgnoreRules::load(&self.root);
+            return self.analyze(false);
+        }
+        let started = Instant::now();
+        self.analyzer.apply_changes(changed)?;
+        self.finished(started);
+        Ok(())
+    }
+
+    /// Violations of one file, given relative to the root or absolute
+    pub fn violations_for(&self, file: &str) -> Vec<&CategorizedViolation> {
+        let path = Path::new(file);
+        let path = if path.is_absolute() {
+            path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+        } else {
+            self.root.join(path)
+        };
+        let key = AnalysisCache::normalize_path(&path, &self.root);
+        self.result()
+            .violations
+            .iter()
+            .filter(|cv| AnalysisCache::normalize_path(&cv.violation.file_path, &self.root) == key)
+            .collect()
+    }
+
+    /// Persist the analysis cache, so the next session starts warm
+    pub fn save_cache(&self) {
+        if let Some(cache) = self.analyzer.cache() {
+            if let Err(e) = cache.save(&self.root) {
+                tracing::warn!("Could not save analysis cache: {}", e);
+            }
+        }
+    }
+
+    fn finished(&mut self, started: Instant) {
+        self.last_analysis = started.elapsed();
+        tracing::info!(
+            "Analyzed {} files in {:?}",
+            self.result().files_analyzed,
+            self.last_analysis
+        );
+    }
+
+    fn is_config_file(&self, path: &Path) -> bool {
+        config::find_config(&self.root).is_some_and(|config| config == path)
+    }
+}
+
+/// `.gitignore`, `.architectignore` or `.git/info/exclude`
+fn is_ignore_file(path: &Path) -> bool {
+    path.ends_with(".git/info/exclude")
+        || path
+            .file_name()
+            .is_some_and(|name| name == ".gitignore" || name == ARCHITECT_IGNORE)
+}
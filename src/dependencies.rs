@@ -0,0 +1,684 @@
+//! Declared-vs-used dependency audit
+//!
+//! Cross-checks the packages imported by the source files (as extracted by
+//! each `ArchitectParser`) against the manifests of the project:
+//!
+//! - `package.json`: `dependencies`, `peerDependencies` and
+//!   `optionalDependencies` are production, `devDependencies` are dev
+//! - `requirements.txt` (production) and `requirements-dev.txt` & co. (dev)
+//! - `pyproject.toml`: `[project]` dependencies and extras, PEP 735
+//!   `[dependency-groups]` (dev) and Poetry's dependency tables
+//! - `composer.json`: `require` and `require-dev`
+//!
+//! It reports packages that are imported but not declared, production
+//! dependencies that are never imported and dev dependencies imported from
+//! production code. Ecosystems without a manifest are skipped.
+
+use crate::config::DependencyAuditConfig;
+use crate::packages::{normalize_python_name, Ecosystem, PackageResolver};
+use crate::parsers;
+use miette::Result;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Requirement files holding dev-only Python dependencies
+const PYTHON_DEV_REQUIREMENTS: [&str; 6] = [
+    "requirements-dev.txt",
+    "requirements_dev.txt",
+    "dev-requirements.txt",
+    "requirements-test.txt",
+    "requirements/dev.txt",
+    "requirements/test.txt",
+];
+
+/// `[project.optional-dependencies]` extras that hold tooling, not runtime code
+const PYTHON_DEV_EXTRAS: [&str; 7] = ["dev", "test", "tests", "testing", "lint", "docs", "typing"];
+
+/// Distributions whose top-level module is not their (normalized) name
+const PYTHON_IMPORT_NAMES: [(&str, &str); 24] = [
+    ("attrs", "attr"),
+    ("beautifulsoup4", "bs4"),
+    ("django_cors_headers", "corsheaders"),
+    ("django_filter", "django_filters"),
+    ("djangorestframework", "rest_framework"),
+    ("faiss_cpu", "faiss"),
+    ("google_api_python_client", "googleapiclient"),
+    ("msgpack_python", "msgpack"),
+    ("opencv_python", "cv2"),
+    ("opencv_python_headless", "cv2"),
+    ("pillow", "pil"),
+    ("protobuf", "google"),
+    ("psycopg2_binary", "psycopg2"),
+    ("psycopg_binary", "psycopg"),
+    ("pycryptodome", "crypto"),
+    ("pyjwt", "jwt"),
+    ("pymongo", "bson"),
+    ("pyopenssl", "openssl"),
+    ("pyserial", "serial"),
+    ("python_multipart", "multipart"),
+    ("pyyaml", "yaml"),
+    ("pyzmq", "zmq"),
+    ("scikit_image", "skimage"),
+    ("scikit_learn", "sklearn"),
+];
+
+/// Directories whose files are tests, tooling or fixtures rather than production code
+const DEV_DIRS: [&str; 10] = [
+    "test",
+    "tests",
+    "__tests__",
+    "__mocks__",
+    "spec",
+    "e2e",
+    "cypress",
+    "fixtures",
+    "scripts",
+    "stories",
+];
+
+/// A dependency listed in a manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeclaredDependency {
+    pub ecosystem: Ecosystem,
+    pub name: String,
+    /// Only needed for development (tests, tooling)
+    pub dev: bool,
+    /// Manifest it was declared in, relative to the project root
+    pub manifest: String,
+}
+
+/// Read the dependencies declared in the npm, pip and composer manifests of `root`
+pub fn read_manifests(root: &Path) -> Vec<DeclaredDependency> {
+    let mut declared = Vec::new();
+    let mut add = |ecosystem, name: &str, dev, manifest: &str| {
+        let name = name.trim();
+        if !name.is_empty() {
+            declared.push(DeclaredDependency {
+                ecosystem,
+                name: name.to_string(),
+                dev,
+                manifest: manifest.to_string(),
+            });
+        }
+    };
+
+    // 1. npm: package.json
+    if let Some(json) = read_json(&root.join("package.json")) {
+        for (section, dev) in [
+            ("dependencies", false),
+            ("peerDependencies", false),
+            ("optionalDependencies", false),
+            ("devDependencies", true),
+        ] {
+            if let Some(deps) = json.get(section).and_then(|d| d.as_object()) {
+                for name in deps.keys() {
+                    add(Ecosystem::Npm, name, dev, "package.json");
+                }
+            }
+        }
+    }
+
+    // 2. pip: requirements files
+    for (file, dev) in std::iter::once(("requirements.txt", false))
+        .chain(PYTHON_DEV_REQUIREMENTS.iter().map(|f| (*f, true)))
+    {
+        if let Ok(content) = fs::read_to_string(root.join(file)) {
+            for line in content.lines() {
+                let line = line.trim();
+                // Skip comments, options (-r, -e, --index-url) and empty lines
+                if line.is_empty() || line.starts_with('#') || line.starts_with('-') {
+                    continue;
+                }
+                add(Ecosystem::Pip, &requirement_name(line), dev, file);
+            }
+        }
+    }
+
+    // 3. pip: pyproject.toml
+    let pyproject = fs::read_to_string(root.join("pyproject.toml"))
+        .ok()
+        .and_then(|content| content.parse::<toml::Table>().ok());
+    if let Some(pyproject) = pyproject {
+        for (name, dev) in pyproject_dependencies(&pyproject) {
+            add(Ecosystem::Pip, &name, dev, "pyproject.toml");
+        }
+    }
+
+    // 4. composer: composer.json (platform packages are not dependencies)
+    if let Some(json) = read_json(&root.join("composer.json")) {
+        for (section, dev) in [("require", false), ("require-dev", true)] {
+            if let Some(deps) = json.get(section).and_then(|d| d.as_object()) {
+                for name in deps.keys().filter(|n| n.contains('/')) {
+                    add(Ecosystem::Composer, name, dev, "composer.json");
+                }
+            }
+        }
+    }
+
+    declared
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+/// Distribution name of a PEP 508 requirement (`Django[argon2]>=4.2; python_version>"3.8"`)
+fn requirement_name(requirement: &str) -> String {
+    requirement
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect()
+}
+
+/// `(name, dev)` pairs from the dependency tables of a pyproject.toml
+fn pyproject_dependencies(pyproject: &toml::Table) -> Vec<(String, bool)> {
+    let mut deps = Vec::new();
+    let requirements = |value: Option<&toml::Value>| -> Vec<String> {
+        value
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str())
+                    .map(requirement_name)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    // PEP 621
+    if let Some(project) = pyproject.get("project").and_then(|p| p.as_table()) {
+        deps.extend(
+            requirements(project.get("dependencies"))
+                .into_iter()
+                .map(|n| (n, false)),
+        );
+        if let Some(extras) = project
+            .get("optional-dependencies")
+            .and_then(|o| o.as_table())
+        {
+            for (extra, value) in extras {
+                let dev = PYTHON_DEV_EXTRAS.contains(&extra.as_str());
+                deps.extend(requirements(Some(value)).into_iter().map(|n| (n, dev)));
+            }
+        }
+    }
+
+    // PEP 735 dependency groups are never installed with the package
+    if let Some(groups) = pyproject
+        .get("dependency-groups")
+        .and_then(|g| g.as_table())
+    {
+        for value in groups.values() {
+            deps.extend(requirements(Some(value)).into_iter().map(|n| (n, true)));
+        }
+    }
+
+    // Poetry
+    let poetry = pyproject
+        .get("tool")
+        .and_then(|t| t.get("poetry"))
+        .and_then(|p| p.as_table());
+    if let Some(poetry) = poetry {
+        let keys = |table: Option<&toml::Value>| -> Vec<String> {
+            table
+                .and_then(|t| t.as_table())
+                .map(|t| {
+                    t.keys()
+                        .filter(|k| k.as_str() != "python")
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        deps.extend(
+            keys(poetry.get("dependencies"))
+                .into_iter()
+                .map(|n| (n, false)),
+        );
+        deps.extend(
+            keys(poetry.get("dev-dependencies"))
+                .into_iter()
+                .map(|n| (n, true)),
+        );
+        if let Some(groups) = poetry.get("group").and_then(|g| g.as_table()) {
+            for group in groups.values() {
+                deps.extend(
+                    keys(group.get("dependencies"))
+                        .into_iter()
+                        .map(|n| (n, true)),
+                );
+            }
+        }
+    }
+
+    deps
+}
+
+/// Normalized top-level module names a Python distribution can be imported as
+fn python_import_names(distribution: &str) -> Vec<String> {
+    let normalized = normalize_python_name(distribution);
+    let mut names = vec![normalized.clone()];
+    if let Some(stripped) = normalized.strip_prefix("python_") {
+        names.push(stripped.to_string());
+    }
+    if normalized.starts_with("google_cloud_") {
+        names.push("google".to_string());
+    }
+    names.extend(
+        PYTHON_IMPORT_NAMES
+            .iter()
+            .filter(|(dist, _)| *dist == normalized)
+            .map(|(_, module)| module.to_string()),
+    );
+    names
+}
+
+/// Whether a file (relative to the root) is test, tooling or fixture code
+pub fn is_dev_file(rel_path: &str, extra_patterns: &[String]) -> bool {
+    let rel_path = rel_path.replace('\\', "/");
+    let mut parts: Vec<&str> = rel_path.split('/').collect();
+    let file_name = parts.pop().unwrap_or_default();
+
+    parts.iter().any(|dir| DEV_DIRS.contains(dir))
+        || [".test.", ".spec.", ".stories.", ".config."]
+            .iter()
+            .any(|marker| file_name.contains(marker))
+        || file_name.starts_with("test_")
+        || file_name.ends_with("_test.py")
+        || matches!(file_name, "conftest.py" | "setup.py" | "noxfile.py")
+        || extra_patterns
+            .iter()
+            .any(|pattern| crate::parsers::typescript::matches_pattern(&rel_path, pattern))
+}
+
+/// Where a package is imported
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct ImportLocation {
+    /// File relative to the project root
+    pub file: String,
+    pub line: usize,
+}
+
+/// What is wrong with a dependency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// Imported but not declared in any manifest
+    Undeclared,
+    /// Declared as a dev dependency but imported from production code
+    DevInProduction,
+    /// Declared as a production dependency but never imported
+    Unused,
+}
+
+/// One finding of the audit
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyIssue {
+    pub kind: IssueKind,
+    pub ecosystem: Ecosystem,
+    pub package: String,
+    /// Manifest declaring the package (None for undeclared packages)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<String>,
+    /// Imports of the package (production imports only for dev dependencies)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<ImportLocation>,
+}
+
+/// Result of the declared-vs-used audit
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyAudit {
+    /// Manifests that were read
+    pub manifests: Vec<String>,
+    pub declared: usize,
+    /// Distinct third-party packages imported by the code
+    pub imported: usize,
+    pub issues: Vec<DependencyIssue>,
+}
+
+impl DependencyAudit {
+    pub fn issues_of(&self, kind: IssueKind) -> impl Iterator<Item = &DependencyIssue> {
+        self.issues.iter().filter(move |issue| issue.kind == kind)
+    }
+
+    /// Undeclared packages and dev dependencies in production break installs; unused ones do not
+    pub fn has_errors(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| issue.kind != IssueKind::Unused)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# Dependency Audit\n\n");
+        md.push_str(&format!(
+            "**Manifests:** {}  \n**Declared:** {} | **Imported:** {} | **Issues:** {}\n",
+            if self.manifests.is_empty() {
+                "none".to_string()
+            } else {
+                self.manifests.join(", ")
+            },
+            self.declared,
+            self.imported,
+            self.issues.len()
+        ));
+
+        for (kind, title) in [
+            (IssueKind::Undeclared, "Undeclared"),
+            (
+                IssueKind::DevInProduction,
+                "Dev Dependencies in Production Code",
+            ),
+            (IssueKind::Unused, "Unused"),
+        ] {
+            let issues: Vec<&DependencyIssue> = self.issues_of(kind).collect();
+            if issues.is_empty() {
+                continue;
+            }
+            md.push_str(&format!("\n## {} ({})\n\n", title, issues.len()));
+            md.push_str("| Package | Ecosystem | Manifest | Imported from |\n");
+            md.push_str("|---------|-----------|----------|---------------|\n");
+            for issue in issues {
+                let locations: Vec<String> = issue
+                    .locations
+                    .iter()
+                    .take(3)
+                    .map(|l| format!("`{}:{}`", l.file, l.line))
+                    .collect();
+                let more = issue.locations.len().saturating_sub(3);
+                md.push_str(&format!(
+                    "| `{}` | {} | {} | {}{} |\n",
+                    issue.package,
+                    issue.ecosystem.as_str(),
+                    issue.manifest.as_deref().unwrap_or("-"),
+                    if locations.is_empty() {
+                        "-".to_string()
+                    } else {
+                        locations.join(", ")
+                    },
+                    if more > 0 {
+                        format!(" (+{} more)", more)
+                    } else {
+                        String::new()
+                    }
+                ));
+            }
+        }
+        md
+    }
+}
+
+/// A third-party import found in a source file
+struct PackageImport {
+    ecosystem: Ecosystem,
+    name: String,
+    location: ImportLocation,
+    production: bool,
+}
+
+/// Audit the dependencies of the project at `root`
+pub fn audit(
+    root: &Path,
+    ignored_paths: &[String],
+    config: &DependencyAuditConfig,
+) -> Result<DependencyAudit> {
+    let resolver = PackageResolver::new(root);
+    let root = resolver.root.clone();
+    let files: Vec<PathBuf> = crate::discovery::collect_files(&root, ignored_paths);
+
+    let imports: Vec<PackageImport> = files
+        .par_iter()
+        .flat_map_iter(|file| package_imports(file, &root, &resolver, config))
+        .collect();
+
+    let declared = read_manifests(&root);
+    let mut manifests: Vec<String> = declared.iter().map(|d| d.manifest.clone()).collect();
+    manifests.sort();
+    manifests.dedup();
+
+    let ignored = |ecosystem: Ecosystem, name: &str| {
+        config.ignore.iter().any(|pattern| {
+            crate::packages::package_matches(
+                pattern,
+                &crate::packages::PackageRef {
+                    ecosystem,
+                    name: name.to_string(),
+                    builtin: false,
+                },
+            )
+        })
+    };
+    let has_manifest = |ecosystem: Ecosystem| match ecosystem {
+        Ecosystem::Npm => root.join("package.json").is_file(),
+        Ecosystem::Pip => ["requirements.txt", "pyproject.toml", "setup.py", "Pipfile"]
+            .iter()
+            .any(|f| root.join(f).is_file()),
+        // Namespaces can only be mapped to packages with a lock file
+        Ecosystem::Composer => root.join("composer.json").is_file() && resolver.has_composer_lock(),
+    };
+
+    // Group imports by (ecosystem, import key)
+    let mut used: BTreeMap<(Ecosystem, String), Vec<&PackageImport>> = BTreeMap::new();
+    for import in &imports {
+        used.entry((import.ecosystem, import_key(import.ecosystem, &import.name)))
+            .or_default()
+            .push(import);
+    }
+    let declarations_of = |ecosystem: Ecosystem, key: &str| -> Vec<&DeclaredDependency> {
+        declared
+            .iter()
+            .filter(|d| d.ecosystem == ecosystem && declared_keys(d).iter().any(|k| k == key))
+            .collect()
+    };
+
+    let mut issues = Vec::new();
+    for ((ecosystem, key), uses) in &used {
+        let name = &uses[0].name;
+        if !has_manifest(*ecosystem) || ignored(*ecosystem, name) {
+            continue;
+        }
+        let declarations = declarations_of(*ecosystem, key);
+        if declarations.is_empty() {
+            issues.push(DependencyIssue {
+                kind: IssueKind::Undeclared,
+                ecosystem: *ecosystem,
+                package: name.clone(),
+                manifest: None,
+                locations: sorted_locations(uses.iter().copied()),
+            });
+        } else if declarations.iter().all(|d| d.dev) {
+            let production: Vec<ImportLocation> =
+                sorted_locations(uses.iter().copied().filter(|u| u.production));
+            if !production.is_empty() {
+                issues.push(DependencyIssue {
+                    kind: IssueKind::DevInProduction,
+                    ecosystem: *ecosystem,
+                    package: declarations[0].name.clone(),
+                    manifest: Some(declarations[0].manifest.clone()),
+                    locations: production,
+                });
+            }
+        }
+    }
+
+    let mut reported_unused: Vec<(Ecosystem, String)> = Vec::new();
+    for dependency in declared.iter().filter(|d| !d.dev) {
+        // Type packages are used by the compiler, never imported
+        if dependency.name.starts_with("@types/") || ignored(dependency.ecosystem, &dependency.name)
+        {
+            continue;
+        }
+        let is_used = declared_keys(dependency)
+            .iter()
+            .any(|key| used.contains_key(&(dependency.ecosystem, key.clone())));
+        let id = (dependency.ecosystem, dependency.name.clone());
+        if !is_used && !reported_unused.contains(&id) {
+            reported_unused.push(id);
+            issues.push(DependencyIssue {
+                kind: IssueKind::Unused,
+                ecosystem: dependency.ecosystem,
+                package: dependency.name.clone(),
+                manifest: Some(dependency.manifest.clone()),
+                locations: Vec::new(),
+            });
+        }
+    }
+
+    issues
+        .sort_by(|a, b| (a.kind, a.ecosystem, &a.package).cmp(&(b.kind, b.ecosystem, &b.package)));
+
+    Ok(DependencyAudit {
+        manifests,
+        declared: declared.len(),
+        imported: used.len(),
+        issues,
+    })
+}
+
+/// Third-party imports of one file
+fn package_imports(
+    file: &Path,
+    root: &Path,
+    resolver: &PackageResolver,
+    config: &DependencyAuditConfig,
+) -> Vec<PackageImport> {
+    let Some(parser) = parsers::get_parser_for_file(file) else {
+        return Vec::new();
+    };
+    let Ok(source) = fs::read_to_string(file) else {
+        return Vec::new();
+    };
+    let Ok(imports) = parser.extract_imports(&source, file) else {
+        return Vec::new();
+    };
+
+    let rel = file
+        .strip_prefix(root)
+        .unwrap_or(file)
+        .to_string_lossy()
+        .replace('\\', "/");
+    let production = !is_dev_file(&rel, &config.dev_paths);
+    imports
+        .iter()
+        .filter_map(|import| {
+            let package = resolver.package_of(file, &import.source)?;
+            (!package.builtin).then(|| PackageImport {
+                ecosystem: package.ecosystem,
+                name: package.name,
+                location: ImportLocation {
+                    file: rel.clone(),
+                    line: import.line_number,
+                },
+                production,
+            })
+        })
+        .collect()
+}
+
+/// Key an imported package name is matched by
+fn import_key(ecosystem: Ecosystem, name: &str) -> String {
+    match ecosystem {
+        Ecosystem::Npm => name.to_string(),
+        Ecosystem::Pip => normalize_python_name(name),
+        Ecosystem::Composer => name.to_lowercase(),
+    }
+}
+
+/// Keys under which a declared dependency can be imported
+fn declared_keys(dependency: &DeclaredDependency) -> Vec<String> {
+    match dependency.ecosystem {
+        Ecosystem::Pip => python_import_names(&dependency.name),
+        ecosystem => vec![import_key(ecosystem, &dependency.name)],
+    }
+}
+
+fn sorted_locations<'a>(uses: impl Iterator<Item = &'a PackageImport>) -> Vec<ImportLocation> {
+    let mut locations: Vec<ImportLocation> = uses.map(|u| u.location.clone()).collect();
+    locations.sort();
+    locations.dedup();
+    locations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requirement_names() {
+        assert_eq!(requirement_name("Django[argon2]>=4.2"), "Django");
+        assert_eq!(
+            requirement_name("ruamel.yaml==0.18; python_version>'3.8'"),
+            "ruamel.yaml"
+        );
+        assert_eq!(
+            requirement_name("requests @ https://example.com/r.whl"),
+            "requests"
+        );
+    }
+
+    #[test]
+    fn test_python_import_names() {
+        assert!(python_import_names("PyYAML").contains(&"yaml".to_string()));
+        assert!(python_import_names("python-dateutil").contains(&"dateutil".to_string()));
+        assert!(python_import_names("Pillow").contains(&"pil".to_string()));
+        assert_eq!(python_import_names("requests"), ["requests"]);
+    }
+
+    #[test]
+    fn test_pyproject_dependencies() {
+        let pyproject: toml::Table = r#"
+[project]
+dependencies = ["fastapi>=0.110", "pydantic"]
+
+[project.optional-dependencies]
+dev = ["pytest"]
+postgres = ["psycopg[binary]"]
+
+[dependency-groups]
+lint = ["ruff"]
+
+[tool.poetry.dependencies]
+python = "^3.11"
+httpx = "*"
+
+[tool.poetry.group.test.dependencies]
+factory-boy = "*"
+"#
+        .parse()
+        .unwrap();
+
+        let mut deps = pyproject_dependencies(&pyproject);
+        deps.sort();
+        assert_eq!(
+            deps,
+            [
+                ("factory-boy".to_string(), true),
+                ("fastapi".to_string(), false),
+                ("httpx".to_string(), false),
+                ("psycopg".to_string(), false),
+                ("pydantic".to_string(), false),
+                ("pytest".to_string(), true),
+                ("ruff".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dev_files() {
+        assert!(is_dev_file("src/users/user.service.spec.ts", &[]));
+        assert!(is_dev_file("tests/test_api.py", &[]));
+        assert!(is_dev_file("app/conftest.py", &[]));
+        assert!(is_dev_file("vite.config.ts", &[]));
+        assert!(!is_dev_file("src/latest_news.py", &[]));
+        assert!(!is_dev_file("src/main.ts", &[]));
+        assert!(is_dev_file("tools/seed.ts", &["tools/".to_string()]));
+    }
+}
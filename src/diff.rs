@@ -0,0 +1,398 @@
+//! Diff mode: compare the analysis of a base git ref against HEAD
+//!
+//! The base is the merge-base of the given ref and HEAD. Its tree is exported
+//! into a scratch directory and analyzed with the current configuration, so
+//! the comparison only reflects code changes, not rule changes. Findings are
+//! matched by file, rule and import text (never by line number), which keeps
+//! them stable when unrelated lines move.
+
+use crate::analysis_result::{AnalysisResult, CategorizedViolation, LongFunction};
+use crate::circular::CircularDependency;
+use crate::config::LinterContext;
+use crate::metrics::HealthScore;
+use crate::{analyzer, git};
+use miette::{IntoDiagnostic, Result};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+
+/// Findings introduced and fixed by HEAD relative to the base ref
+#[derive(Debug, Clone)]
+pub struct DiffReport {
+    /// Ref given on the command line (e.g. `origin/main`)
+    pub base_ref: String,
+    /// Merge-base commit that was actually analyzed
+    pub base_commit: String,
+    pub introduced: Vec<CategorizedViolation>,
+    pub fixed: Vec<CategorizedViolation>,
+    pub introduced_cycles: Vec<CircularDependency>,
+    pub fixed_cycles: Vec<CircularDependency>,
+    pub introduced_long_functions: Vec<LongFunction>,
+    pub fixed_long_functions: Vec<LongFunction>,
+    pub base_score: Option<HealthScore>,
+    pub head_score: Option<HealthScore>,
+}
+
+/// Score change of a single component between base and HEAD
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreDelta {
+    pub metric: &'static str,
+    pub base: u8,
+    pub head: u8,
+}
+
+impl ScoreDelta {
+    pub fn delta(&self) -> i16 {
+        self.head as i16 - self.base as i16
+    }
+}
+
+/// Analyze the merge-base of `base_ref` and HEAD with `ctx`.
+///
+/// Paths in the returned result point into `project_root`, as if the base
+/// had been checked out there.
+pub fn analyze_base(
+    project_root: &Path,
+    base_ref: &str,
+    ctx: &LinterContext,
+) -> Result<(String, AnalysisResult)> {
+    let sha = git::merge_base(project_root, base_ref)?;
+    let subdir = git::repo_relative_path(project_root)?;
+
+    let scratch = tempfile::tempdir().into_diagnostic()?;
+    let root = scratch.path().canonicalize().into_diagnostic()?;
+    git::export_commit_tree(project_root, &sha, &subdir, &root)?;
+
+    let mut result = analyzer::analyze_project(&root, ctx)?;
+    result.project_name = project_root
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("project")
+        .to_string();
+    for cv in &mut result.violations {
+        cv.violation.file_path = rebase(&cv.violation.file_path, &root, project_root);
+    }
+    for func in &mut result.long_functions {
+        func.file_path = rebase(&func.file_path, &root, project_root);
+    }
+    Ok((sha, result))
+}
+
+/// Compare two analyses of the same project
+pub fn compare(
+    base: &AnalysisResult,
+    head: &AnalysisResult,
+    project_root: &Path,
+    base_ref: &str,
+    base_commit: &str,
+) -> DiffReport {
+    let violation_key = |cv: &CategorizedViolation| {
+        let v = &cv.violation;
+        (
+            relative(&v.file_path, project_root),
+            v.rule.from.clone(),
+            v.rule.to.clone(),
+            v.offensive_import.trim().to_string(),
+            cv.is_security(),
+        )
+    };
+    let function_key = |f: &LongFunction| (relative(&f.file_path, project_root), f.name.clone());
+    let cycle_key = |c: &CircularDependency| canonical_cycle(&c.cycle);
+
+    let (introduced, fixed) = split(&base.violations, &head.violations, violation_key);
+    let (introduced_cycles, fixed_cycles) = split(
+        &base.circular_dependencies,
+        &head.circular_dependencies,
+        cycle_key,
+    );
+    let (introduced_long_functions, fixed_long_functions) =
+        split(&base.long_functions, &head.long_functions, function_key);
+
+    DiffReport {
+        base_ref: base_ref.to_string(),
+        base_commit: base_commit.to_string(),
+        introduced,
+        fixed,
+        introduced_cycles,
+        fixed_cycles,
+        introduced_long_functions,
+        fixed_long_functions,
+        base_score: base.health_score.clone(),
+        head_score: head.health_score.clone(),
+    }
+}
+
+impl DiffReport {
+    /// Per-component score change, total first
+    pub fn score_deltas(&self) -> Vec<ScoreDelta> {
+        let (Some(base), Some(head)) = (&self.base_score, &self.head_score) else {
+            return Vec::new();
+        };
+        let (b, h) = (&base.components, &head.components);
+        vec![
+            ScoreDelta {
+                metric: "total",
+                base: base.total,
+                head: head.total,
+            },
+            ScoreDelta {
+                metric: "layer_isolation",
+                base: b.layer_isolation,
+                head: h.layer_isolation,
+            },
+            ScoreDelta {
+                metric: "circular_deps",
+                base: b.circular_deps,
+                head: h.circular_deps,
+            },
+            ScoreDelta {
+                metric: "complexity",
+                base: b.complexity,
+                head: h.complexity,
+            },
+            ScoreDelta {
+                metric: "violations",
+                base: b.violations,
+                head: h.violations,
+            },
+        ]
+    }
+
+    /// Whether HEAD introduced blocking violations or new cycles
+    pub fn has_critical_issues(&self) -> bool {
+        self.introduced
+            .iter()
+            .any(|v| v.category == crate::analysis_result::ViolationCategory::Blocked)
+            || !self.introduced_cycles.is_empty()
+    }
+
+    /// A copy of `head` that only keeps what this diff introduced.
+    ///
+    /// Lets every report format (SARIF, JUnit, ...) be used as a PR gate.
+    pub fn introduced_result(&self, head: &AnalysisResult) -> AnalysisResult {
+        let mut result = AnalysisResult::new(head.project_name.clone(), head.pattern.clone());
+        result.files_analyzed = head.files_analyzed;
+        result.timestamp = head.timestamp;
+        result.complexity_stats.total_functions = head.complexity_stats.total_functions;
+        result.complexity_stats.max_lines_threshold = head.complexity_stats.max_lines_threshold;
+        result.layer_stats.total_imports = head.layer_stats.total_imports;
+        for violation in &self.introduced {
+            result.add_violation(violation.clone());
+        }
+        for cycle in &self.introduced_cycles {
+            result.add_circular_dependency(cycle.clone());
+        }
+        for func in &self.introduced_long_functions {
+            result.add_long_function(func.clone());
+        }
+        result.health_score = head.health_score.clone();
+        result.generated_files = head.generated_files.clone();
+        result
+    }
+
+    pub fn to_json(&self) -> String {
+        let violation = |cv: &CategorizedViolation| {
+            json!({
+                "file": cv.violation.file_path,
+                "line": cv.violation.line_number,
+                "import": cv.violation.offensive_import,
+                "from": cv.violation.rule.from,
+                "to": cv.violation.rule.to,
+                "category": cv.category.as_str(),
+                "blame": cv.blame,
+                "owners": cv.owners,
+            })
+        };
+        let deltas: Vec<Value> = self
+            .score_deltas()
+            .iter()
+            .map(|d| json!({ "metric": d.metric, "base": d.base, "head": d.head, "delta": d.delta() }))
+            .collect();
+
+        let value = json!({
+            "base_ref": self.base_ref,
+            "base_commit": self.base_commit,
+            "score": deltas,
+            "introduced": {
+                "violations": self.introduced.iter().map(violation).collect::<Vec<_>>(),
+                "circular_dependencies": self.introduced_cycles,
+                "long_functions": self.introduced_long_functions,
+            },
+            "fixed": {
+                "violations": self.fixed.iter().map(violation).collect::<Vec<_>>(),
+                "circular_dependencies": self.fixed_cycles,
+                "long_functions": self.fixed_long_functions,
+            },
+        });
+        serde_json::to_string_pretty(&value).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::new();
+        md.push_str(&format!(
+            "# Architecture Diff vs `{}` ({})\n\n",
+            self.base_ref,
+            &self.base_commit[..7.min(self.base_commit.len())]
+        ));
+
+        let deltas = self.score_deltas();
+        if !deltas.is_empty() {
+            md.push_str("| Metric | Base | HEAD | Δ |\n|---|---:|---:|---:|\n");
+            for d in &deltas {
+                md.push_str(&format!(
+                    "| {} | {} | {} | {:+} |\n",
+                    d.metric,
+                    d.base,
+                    d.head,
+                    d.delta()
+                ));
+            }
+            md.push('\n');
+        }
+
+        for (title, violations) in [("Introduced", &self.introduced), ("Fixed", &self.fixed)] {
+            md.push_str(&format!(
+                "## {} violations ({})\n\n",
+                title,
+                violations.len()
+            ));
+            for cv in violations {
+                md.push_str(&format!(
+                    "- `{}:{}` {} → {} (`{}`)\n",
+                    cv.violation.file_path.display(),
+                    cv.violation.line_number,
+                    cv.violation.rule.from,
+                    cv.violation.rule.to,
+                    cv.violation.offensive_import.trim()
+                ));
+            }
+            md.push('\n');
+        }
+        for (title, cycles) in [
+            ("Introduced", &self.introduced_cycles),
+            ("Fixed", &self.fixed_cycles),
+        ] {
+            md.push_str(&format!("## {} cycles ({})\n\n", title, cycles.len()));
+            for cycle in cycles {
+                md.push_str(&format!("- {}\n", cycle.cycle.join(" → ")));
+            }
+            md.push('\n');
+        }
+        for (title, functions) in [
+            ("Introduced", &self.introduced_long_functions),
+            ("Fixed", &self.fixed_long_functions),
+        ] {
+            md.push_str(&format!(
+                "## {} long functions ({})\n\n",
+                title,
+                functions.len()
+            ));
+            for f in functions {
+                md.push_str(&format!(
+                    "- `{}:{}` {} ({} lines)\n",
+                    f.file_path.display(),
+                    f.line_start,
+                    f.name,
+                    f.lines
+                ));
+            }
+            md.push('\n');
+        }
+        md
+    }
+}
+
+/// Multiset difference: items only in `head` (introduced) and only in `base` (fixed)
+fn split<T: Clone, K: Hash + Eq>(
+    base: &[T],
+    head: &[T],
+    key: impl Fn(&T) -> K,
+) -> (Vec<T>, Vec<T>) {
+    let mut remaining: HashMap<K, usize> = HashMap::new();
+    for item in base {
+        *remaining.entry(key(item)).or_default() += 1;
+    }
+
+    let mut introduced = Vec::new();
+    let mut matched: HashMap<K, usize> = HashMap::new();
+    for item in head {
+        let k = key(item);
+        match remaining.get_mut(&k) {
+            Some(n) if *n > 0 => {
+                *n -= 1;
+                *matched.entry(k).or_default() += 1;
+            }
+            _ => introduced.push(item.clone()),
+        }
+    }
+
+    let fixed = base
+        .iter()
+        .filter(|item| {
+            let k = key(item);
+            match matched.get_mut(&k) {
+                Some(n) if *n > 0 => {
+                    *n -= 1;
+                    false
+                }
+                _ => true,
+            }
+        })
+        .cloned()
+        .collect();
+
+    (introduced, fixed)
+}
+
+/// Rotate a cycle so it starts at its smallest node (and drop the closing repeat)
+fn canonical_cycle(cycle: &[String]) -> Vec<String> {
+    let mut nodes = cycle.to_vec();
+    if nodes.len() > 1 && nodes.first() == nodes.last() {
+        nodes.pop();
+    }
+    if let Some(start) = nodes
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.cmp(b.1))
+        .map(|(i, _)| i)
+    {
+        nodes.rotate_left(start);
+    }
+    nodes
+}
+
+fn relative(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn rebase(path: &Path, from: &Path, to: &Path) -> PathBuf {
+    match path.strip_prefix(from) {
+        Ok(rel) => to.join(rel),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_is_multiset_difference() {
+        let base = vec!["a", "b", "b", "c"];
+        let head = vec!["b", "c", "c", "d"];
+        let (introduced, fixed) = split(&base, &head, |s| *s);
+        assert_eq!(introduced, vec!["c", "d"]);
+        assert_eq!(fixed, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_canonical_cycle_ignores_rotation() {
+        let a = canonical_cycle(&["b.ts".into(), "c.ts".into(), "a.ts".into(), "b.ts".into()]);
+        let b = canonical_cycle(&["a.ts".into(), "b.ts".into(), "c.ts".into()]);
+        assert_eq!(a, b);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::config::default_ignored_paths;
 use crate::detector;
+use crate::ignore_rules::{IgnoreMatch, IgnoreRules};
 use crate::parsers;
 use serde::Serialize;
 use std::fs;
@@ -16,31 +17,99 @@ pub struct ProjectContext {
 
 /// Recolecta todos los archivos soportados que el linter debe analizar.
 /// Incluye: TypeScript (.ts, .tsx), JavaScript (.js, .jsx), Python (.py), Go (.go), PHP (.php), Java (.java)
-/// Respeta los patrones de exclusión definidos en ignored_paths.
+/// Respeta los patrones de exclusión definidos en ignored_paths, además de
+/// `.gitignore`, `.git/info/exclude`, las exclusiones globales de git y
+/// `.architectignore` (ver `ignore_rules`).
 pub fn collect_files(root: &Path, ignored_paths: &[String]) -> Vec<PathBuf> {
-    let supported_exts = parsers::supported_extensions();
+    let mut ignore_rules = IgnoreRules::load(root);
 
     WalkDir::new(root)
         .into_iter()
-        .filter_entry(|e| is_not_ignored_with_patterns(e, root, ignored_paths))
-        .filter_map(|e| e.ok())
-        .filter(|e| {
-            let path_str = e.path().to_string_lossy();
-
-            // Ignorar archivos de definición TypeScript
-            if path_str.ends_with(".d.ts") {
-                return false;
-            }
-
-            // Verificar si la extensión está en la lista de soportadas
-            e.path().extension().map_or(false, |ext| {
-                supported_exts.iter().any(|&supported| ext == supported)
-            })
+        .filter_entry(|e| {
+            is_not_ignored_with_patterns(e, root, ignored_paths)
+                && (e.depth() == 0
+                    || !ignore_rules
+                        .is_ignored(&relative_path(e.path(), root), e.file_type().is_dir()))
         })
+        .filter_map(|e| e.ok())
+        .filter(|e| is_supported_file(e.path()))
         .map(|e| e.path().to_path_buf())
         .collect()
 }
 
+/// Motivo por el que un archivo se excluye (o no) del análisis
+#[derive(Debug, Clone)]
+pub enum IgnoreExplanation {
+    /// Excluido por una entrada de `ignored_paths` de la configuración
+    IgnoredPaths { path: String, pattern: String },
+    /// Excluido por un patrón de un archivo de ignore
+    IgnoreFile(IgnoreMatch),
+    /// No excluido; `reincluded` es la negación (`!patrón`) que lo re-incluyó
+    NotIgnored { reincluded: Option<IgnoreMatch> },
+    /// No excluido, pero su extensión no es de un lenguaje soportado
+    Unsupported,
+}
+
+/// Explica por qué `rel` (relativa al root del proyecto) se excluye o no de
+/// la recolección de archivos, con la misma precedencia que `collect_files`
+pub fn explain_ignore(
+    root: &Path,
+    ignored_paths: &[String],
+    rel: &str,
+    is_dir: bool,
+) -> IgnoreExplanation {
+    explain_ignore_with(&mut IgnoreRules::load(root), ignored_paths, rel, is_dir)
+}
+
+/// Como `explain_ignore`, con reglas de ignore ya cargadas (para quien
+/// consulta muchas rutas, como el daemon)
+pub fn explain_ignore_with(
+    ignore_rules: &mut IgnoreRules,
+    ignored_paths: &[String],
+    rel: &str,
+    is_dir: bool,
+) -> IgnoreExplanation {
+    let rel = rel.replace('\\', "/");
+    let parts: Vec<&str> = rel.split('/').filter(|s| !s.is_empty()).collect();
+    for n in 1..=parts.len() {
+        let path = parts[..n].join("/");
+        if let Some(pattern) = matching_ignored_path(&path, parts[n - 1], ignored_paths) {
+            return IgnoreExplanation::IgnoredPaths {
+                path,
+                pattern: pattern.clone(),
+            };
+        }
+    }
+
+    match ignore_rules.explain(&rel, is_dir) {
+        Some(found) if found.is_ignored() => IgnoreExplanation::IgnoreFile(found),
+        reincluded if is_dir || is_supported_file(Path::new(&rel)) => {
+            IgnoreExplanation::NotIgnored { reincluded }
+        }
+        _ => IgnoreExplanation::Unsupported,
+    }
+}
+
+/// Si la extensión es de un lenguaje soportado (sin archivos de definición .d.ts)
+pub fn is_supported_file(path: &Path) -> bool {
+    // Ignorar archivos de definición TypeScript
+    if path.to_string_lossy().ends_with(".d.ts") {
+        return false;
+    }
+    // Verificar si la extensión está en la lista de soportadas
+    let supported_exts = parsers::supported_extensions();
+    path.extension()
+        .is_some_and(|ext| supported_exts.iter().any(|&supported| ext == supported))
+}
+
+/// Ruta relativa al root, con separadores `/`
+fn relative_path(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
 /// Genera un resumen completo del proyecto para que la IA tome decisiones arquitectónicas.
 pub fn get_architecture_snapshot(root: &Path) -> ProjectContext {
     let mut folders = Vec::new();
@@ -89,85 +158,14 @@ pub fn get_architecture_snapshot(root: &Path) -> ProjectContext {
 fn get_dependency_list(root: &Path) -> Vec<String> {
     let mut deps_list = Vec::new();
 
-    // 1. JavaScript/TypeScript: package.json
-    let pkg_path = root.join("package.json");
-    if let Ok(content) = fs::read_to_string(&pkg_path) {
-        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-            if let Some(deps) = json.get("dependencies").and_then(|d| d.as_object()) {
-                deps_list.extend(deps.keys().cloned());
-            }
-            if let Some(dev_deps) = json.get("devDependencies").and_then(|d| d.as_object()) {
-                deps_list.extend(dev_deps.keys().cloned());
-            }
-        }
-    }
+    // 1. npm, pip y composer: package.json, requirements*.txt, pyproject.toml, composer.json
+    deps_list.extend(
+        crate::dependencies::read_manifests(root)
+            .into_iter()
+            .map(|dep| dep.name),
+    );
 
-    // 2. Python: requirements.txt
-    let requirements_path = root.join("requirements.txt");
-    if let Ok(content) = fs::read_to_string(&requirements_path) {
-        for line in content.lines() {
-            let line = line.trim();
-            // Skip comments and empty lines
-            if line.is_empty() || line.starts_with('#') {
-                continue;
-            }
-            // Extract package name (strip version specifiers like ==, >=, <=, ~=, !=, etc.)
-            let pkg_name = line
-                .split(&['=', '<', '>', '~', '!', ';'][..])
-                .next()
-                .unwrap_or(line)
-                .trim()
-                .to_string();
-            if !pkg_name.is_empty() {
-                deps_list.push(pkg_name);
-            }
-        }
-    }
-
-    // 3. Python: pyproject.toml (simple line-by-line parsing)
-    let pyproject_path = root.join("pyproject.toml");
-    if let Ok(content) = fs::read_to_string(&pyproject_path) {
-        let mut in_dependencies = false;
-        let mut bracket_count = 0;
-        for line in content.lines() {
-            let trimmed = line.trim();
-
-            // Detect dependencies section start
-            if trimmed.starts_with("dependencies") && trimmed.contains('[') {
-                in_dependencies = true;
-                bracket_count =
-                    trimmed.matches('[').count() as i32 - trimmed.matches(']').count() as i32;
-                continue;
-            }
-
-            if in_dependencies {
-                // Track brackets for multi-line arrays
-                bracket_count += trimmed.matches('[').count() as i32;
-                bracket_count -= trimmed.matches(']').count() as i32;
-
-                if bracket_count <= 0 && trimmed.ends_with(']') {
-                    in_dependencies = false;
-                    continue;
-                }
-
-                // Extract quoted package names
-                if trimmed.starts_with('"') || trimmed.starts_with("'") {
-                    let pkg = trimmed
-                        .trim_start_matches('"')
-                        .trim_start_matches('\'')
-                        .split(&['"', '\'', ' ', '>', '<', '=', '~', '^'][..])
-                        .next()
-                        .unwrap_or("")
-                        .to_string();
-                    if !pkg.is_empty() {
-                        deps_list.push(pkg);
-                    }
-                }
-            }
-        }
-    }
-
-    // 4. Go: go.mod
+    // 2. Go: go.mod
     let go_mod_path = root.join("go.mod");
     if let Ok(content) = fs::read_to_string(&go_mod_path) {
         let mut in_require = false;
@@ -203,20 +201,7 @@ fn get_dependency_list(root: &Path) -> Vec<String> {
         }
     }
 
-    // 5. PHP: composer.json
-    let composer_path = root.join("composer.json");
-    if let Ok(content) = fs::read_to_string(&composer_path) {
-        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-            if let Some(deps) = json.get("require").and_then(|d| d.as_object()) {
-                deps_list.extend(deps.keys().cloned());
-            }
-            if let Some(dev_deps) = json.get("require-dev").and_then(|d| d.as_object()) {
-                deps_list.extend(dev_deps.keys().cloned());
-            }
-        }
-    }
-
-    // 6. Java: pom.xml (Maven) - simple line scan for artifactId
+    // 3. Java: pom.xml (Maven) - simple line scan for artifactId
     let pom_path = root.join("pom.xml");
     if let Ok(content) = fs::read_to_string(&pom_path) {
         let mut in_dependencies = false;
@@ -246,7 +231,7 @@ fn get_dependency_list(root: &Path) -> Vec<String> {
         }
     }
 
-    // 7. Java: build.gradle (Gradle) - simple line scan
+    // 4. Java: build.gradle (Gradle) - simple line scan
     let gradle_path = root.join("build.gradle");
     if let Ok(content) = fs::read_to_string(&gradle_path) {
         for line in content.lines() {
@@ -301,37 +286,28 @@ pub fn is_not_ignored_with_patterns(
     root: &Path,
     ignored_paths: &[String],
 ) -> bool {
-    // Obtener la ruta relativa al root del proyecto
-    let entry_path = entry.path();
-    let relative_path = entry_path
-        .strip_prefix(root)
-        .unwrap_or(entry_path)
-        .to_string_lossy()
-        .replace('\\', "/"); // Normalizar separadores para Windows
+    let relative_path = relative_path(entry.path(), root);
+    let file_name = entry.file_name().to_string_lossy();
+    matching_ignored_path(&relative_path, &file_name, ignored_paths).is_none()
+}
 
-    // Verificar si la ruta coincide con algún patrón de exclusión
-    for pattern in ignored_paths {
+/// Primer patrón de `ignored_paths` que excluye la ruta relativa
+fn matching_ignored_path<'a>(
+    relative_path: &str,
+    file_name: &str,
+    ignored_paths: &'a [String],
+) -> Option<&'a String> {
+    ignored_paths.iter().find(|pattern| {
         let normalized_pattern = pattern.replace('\\', "/");
 
         // Coincidencia exacta o si la ruta comienza con el patrón
-        if relative_path == normalized_pattern.trim_end_matches('/')
+        // También verificar el nombre del directorio/archivo directamente
+        relative_path == normalized_pattern.trim_end_matches('/')
             || relative_path.starts_with(&normalized_pattern)
             || relative_path.starts_with(&format!("{}/", normalized_pattern.trim_end_matches('/')))
-        {
-            return false;
-        }
-
-        // También verificar el nombre del directorio/archivo directamente
-        if let Some(file_name) = entry.file_name().to_str() {
-            if file_name == normalized_pattern.trim_end_matches('/')
-                || format!("{}/", file_name) == normalized_pattern
-            {
-                return false;
-            }
-        }
-    }
-
-    true
+            || file_name == normalized_pattern.trim_end_matches('/')
+            || format!("{}/", file_name) == normalized_pattern
+    })
 }
 
 fn is_architectural_file(path: &Path) -> bool {
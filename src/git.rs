@@ -1,10 +1,17 @@
 //! Git operations for --staged flag support
 //!
 //! This module provides functions to interact with git repositories
-//! and get information about staged files.
+//! and get information about staged files, plus read-only access to past
+//! commits (used by the score history backfill).
 
-use git2::{Repository, Status, StatusOptions, StatusShow};
-use miette::Result;
+use chrono::{DateTime, TimeZone, Utc};
+use git2::{
+    ObjectType, Oid, Repository, Sort, Status, StatusOptions, StatusShow, TreeWalkMode,
+    TreeWalkResult,
+};
+use miette::{IntoDiagnostic, Result};
+use std::collections::HashSet;
+use std::fs;
 use std::path::{Path, PathBuf};
 
 /// Check if the given path is inside a git repository
@@ -12,6 +19,13 @@ pub fn is_git_repo(path: &Path) -> bool {
     Repository::discover(path).is_ok()
 }
 
+/// SHA of the commit HEAD points to, or `None` outside a repo / before the first commit
+pub fn head_commit_sha(path: &Path) -> Option<String> {
+    let repo = Repository::discover(path).ok()?;
+    let commit = repo.head().ok()?.peel_to_commit().ok()?;
+    Some(commit.id().to_string())
+}
+
 /// Get all staged files in the repository
 /// Returns a list of absolute paths to staged files
 pub fn get_staged_files(repo_path: &Path) -> Result<Vec<PathBuf>> {
@@ -69,6 +83,170 @@ pub fn filter_staged_files(all_files: &[PathBuf], repo_path: &Path) -> Result<Ve
         .collect())
 }
 
+/// A commit selected for history backfill
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitInfo {
+    pub sha: String,
+    pub time: DateTime<Utc>,
+    pub summary: String,
+}
+
+/// List commits in `range`, oldest first, keeping every `every`-th one.
+///
+/// `range` accepts a single revision (`HEAD`, `main`, a tag: everything reachable
+/// from it) or `from..to`. Only first-parent history is followed. With
+/// `tags_only`, only tagged commits are considered. The newest selected commit
+/// is always the tip of the range.
+pub fn list_commits(
+    repo_path: &Path,
+    range: &str,
+    every: usize,
+    tags_only: bool,
+) -> Result<Vec<CommitInfo>> {
+    let repo = Repository::discover(repo_path)
+        .map_err(|e| miette::miette!("No se encontró repositorio git: {}", e))?;
+
+    let mut walk = repo.revwalk().into_diagnostic()?;
+    walk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME)
+        .into_diagnostic()?;
+    if range.contains("..") {
+        walk.push_range(range)
+            .map_err(|e| miette::miette!("Rango git inválido '{}': {}", range, e))?;
+    } else {
+        let tip = repo
+            .revparse_single(range)
+            .and_then(|obj| obj.peel_to_commit())
+            .map_err(|e| miette::miette!("Revisión git inválida '{}': {}", range, e))?;
+        walk.push(tip.id()).into_diagnostic()?;
+    }
+    walk.simplify_first_parent().into_diagnostic()?;
+
+    let tagged: HashSet<Oid> = if tags_only {
+        let mut tagged = HashSet::new();
+        repo.tag_foreach(|oid, _| {
+            if let Ok(commit) = repo.find_object(oid, None).and_then(|o| o.peel_to_commit()) {
+                tagged.insert(commit.id());
+            }
+            true
+        })
+        .into_diagnostic()?;
+        tagged
+    } else {
+        HashSet::new()
+    };
+
+    let every = every.max(1);
+    let mut commits = Vec::new();
+    let candidates = walk
+        .filter_map(|oid| oid.ok())
+        .filter(|oid| !tags_only || tagged.contains(oid));
+    for (i, oid) in candidates.enumerate() {
+        if i % every != 0 {
+            continue;
+        }
+        let commit = repo.find_commit(oid).into_diagnostic()?;
+        let time = Utc
+            .timestamp_opt(commit.time().seconds(), 0)
+            .single()
+            .unwrap_or_else(Utc::now);
+        commits.push(CommitInfo {
+            sha: oid.to_string(),
+            time,
+            summary: commit.summary().unwrap_or("").to_string(),
+        });
+    }
+
+    commits.reverse();
+    Ok(commits)
+}
+
+/// Write the files of `sha` under `subdir` (relative to the repo root) into
+/// `dest`, reading blobs straight from the object database. The working tree
+/// and index are never touched.
+pub fn export_commit_tree(repo_path: &Path, sha: &str, subdir: &Path, dest: &Path) -> Result<()> {
+    let repo = Repository::discover(repo_path)
+        .map_err(|e| miette::miette!("No se encontró repositorio git: {}", e))?;
+    let oid = Oid::from_str(sha).into_diagnostic()?;
+    let tree = repo
+        .find_commit(oid)
+        .and_then(|c| c.tree())
+        .map_err(|e| miette::miette!("No se pudo leer el commit {}: {}", sha, e))?;
+
+    let mut error = None;
+    tree.walk(TreeWalkMode::PreOrder, |root, entry| {
+        if entry.kind() != Some(ObjectType::Blob) {
+            return TreeWalkResult::Ok;
+        }
+        let Some(name) = entry.name() else {
+            return TreeWalkResult::Ok;
+        };
+        let rel = Path::new(root).join(name);
+        let Ok(rel) = rel.strip_prefix(subdir) else {
+            return TreeWalkResult::Ok;
+        };
+        let target = dest.join(rel);
+        let result = repo
+            .find_blob(entry.id())
+            .map_err(|e| e.to_string())
+            .and_then(|blob| {
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+                }
+                fs::write(&target, blob.content()).map_err(|e| e.to_string())
+            });
+        match result {
+            Ok(()) => TreeWalkResult::Ok,
+            Err(e) => {
+                error = Some(e);
+                TreeWalkResult::Abort
+            }
+        }
+    })
+    .ok();
+
+    match error {
+        Some(e) => Err(miette::miette!("No se pudo exportar {}: {}", sha, e)),
+        None => Ok(()),
+    }
+}
+
+/// Path of `path` relative to the root of the repository containing it
+pub fn repo_relative_path(path: &Path) -> Result<PathBuf> {
+    let repo = Repository::discover(path)
+        .map_err(|e| miette::miette!("No se encontró repositorio git: {}", e))?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| miette::miette!("El repositorio no tiene directorio de trabajo"))?;
+    let workdir = workdir.canonicalize().into_diagnostic()?;
+    let path = path.canonicalize().into_diagnostic()?;
+    Ok(path
+        .strip_prefix(&workdir)
+        .map(Path::to_path_buf)
+        .unwrap_or_default())
+}
+
+/// Commit where the current branch forked from `base` (`git merge-base base HEAD`).
+///
+/// Falls back to `base` itself when the histories are unrelated, so changes
+/// that landed on the base branch afterwards are not attributed to HEAD.
+pub fn merge_base(repo_path: &Path, base: &str) -> Result<String> {
+    let repo = Repository::discover(repo_path)
+        .map_err(|e| miette::miette!("No se encontró repositorio git: {}", e))?;
+    let base_commit = repo
+        .revparse_single(base)
+        .and_then(|obj| obj.peel_to_commit())
+        .map_err(|e| miette::miette!("Revisión git inválida '{}': {}", base, e))?;
+    let head = repo
+        .head()
+        .and_then(|h| h.peel_to_commit())
+        .map_err(|e| miette::miette!("No se pudo leer HEAD: {}", e))?;
+
+    let oid = repo
+        .merge_base(base_commit.id(), head.id())
+        .unwrap_or_else(|_| base_commit.id());
+    Ok(oid.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,206 @@
+//! Gitignore-style path patterns
+//!
+//! Shared by CODEOWNERS parsing, ignore files and boundary module paths: `*`,
+//! `?`, `[...]` inside a segment, `**` across segments (a trailing `/**` only
+//! matches what is inside), leading `/` (or any inner `/`) anchors the pattern
+//! to the file's directory, trailing `/` only matches directories and `!`
+//! negates.
+
+/// A single compiled pattern line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitPattern {
+    /// Original text, for diagnostics
+    pub source: String,
+    /// `!pattern`
+    pub negated: bool,
+    /// `pattern/`
+    pub dir_only: bool,
+    segments: Vec<String>,
+}
+
+impl GitPattern {
+    /// Parse one pattern. Returns None for blank lines and comments.
+    pub fn parse(line: &str) -> Option<Self> {
+        let trimmed = trim_trailing_spaces(line);
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            return None;
+        }
+
+        let (negated, body) = match trimmed.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('\\').unwrap_or(trimmed)),
+        };
+        let (dir_only, body) = match body.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, body),
+        };
+        if body.is_empty() {
+            return None;
+        }
+
+        // A separator at the beginning or in the middle anchors the pattern
+        let anchored = body.contains('/');
+        let body = body.trim_start_matches('/');
+        let mut segments: Vec<String> = Vec::new();
+        if !anchored {
+            segments.push("**".to_string());
+        }
+        segments.extend(body.split('/').filter(|s| !s.is_empty()).map(String::from));
+
+        Some(Self {
+            source: trimmed.to_string(),
+            negated,
+            dir_only,
+            segments,
+        })
+    }
+
+    /// Whether `path` (relative, `/`-separated) itself matches the pattern
+    pub fn matches(&self, path: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        let parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        match_segments(&self.segments, &parts)
+    }
+
+    /// Whether `path` or any of its parent directories matches the pattern
+    pub fn matches_path_or_parent(&self, path: &str, is_dir: bool) -> bool {
+        if self.matches(path, is_dir) {
+            return true;
+        }
+        let parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        (1..parts.len()).any(|n| self.matches(&parts[..n].join("/"), true))
+    }
+}
+
+/// Trailing spaces are ignored unless escaped with a backslash
+fn trim_trailing_spaces(line: &str) -> &str {
+    let line = line.trim_end_matches(['\r', '\n']);
+    let mut end = line.len();
+    while end > 0 && line.as_bytes()[end - 1] == b' ' {
+        if end >= 2 && line.as_bytes()[end - 2] == b'\\' {
+            break;
+        }
+        end -= 1;
+    }
+    line[..end].trim_start()
+}
+
+fn match_segments(pattern: &[String], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        // `dir/**` matches everything inside `dir`, but not `dir` itself
+        Some((first, rest)) if first == "**" && rest.is_empty() => !path.is_empty(),
+        Some((first, rest)) if first == "**" => {
+            (0..=path.len()).any(|skip| match_segments(rest, &path[skip..]))
+        }
+        Some((first, rest)) => match path.split_first() {
+            Some((segment, path_rest)) => {
+                wildmatch(first.as_bytes(), segment.as_bytes()) && match_segments(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Whether `text` matches a wildcard pattern (`*` also spans `/`)
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    wildmatch(pattern.as_bytes(), text.as_bytes())
+}
+
+/// Match a single path segment against `*`, `?`, `[...]` and `\` escapes
+fn wildmatch(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some(b'*') => (0..=text.len()).any(|skip| wildmatch(&pattern[1..], &text[skip..])),
+        Some(b'?') => !text.is_empty() && wildmatch(&pattern[1..], &text[1..]),
+        Some(b'[') => {
+            let Some((&c, text_rest)) = text.split_first() else {
+                return false;
+            };
+            match match_class(&pattern[1..], c) {
+                Some((matched, consumed)) => {
+                    matched && wildmatch(&pattern[1 + consumed..], text_rest)
+                }
+                // Unterminated class: treat `[` literally
+                None => c == b'[' && wildmatch(&pattern[1..], text_rest),
+            }
+        }
+        Some(b'\\') if pattern.len() > 1 => {
+            text.first() == Some(&pattern[1]) && wildmatch(&pattern[2..], &text[1..])
+        }
+        Some(&p) => text.first() == Some(&p) && wildmatch(&pattern[1..], &text[1..]),
+    }
+}
+
+/// Match `c` against a class body (after `[`). Returns (matched, bytes consumed incl. `]`).
+fn match_class(class: &[u8], c: u8) -> Option<(bool, usize)> {
+    let mut i = 0;
+    let negated = matches!(class.first(), Some(b'!') | Some(b'^'));
+    if negated {
+        i += 1;
+    }
+    let mut matched = false;
+    let mut first = true;
+    while i < class.len() {
+        let current = class[i];
+        if current == b']' && !first {
+            return Some((matched != negated, i + 1));
+        }
+        first = false;
+        if i + 2 < class.len() && class[i + 1] == b'-' && class[i + 2] != b']' {
+            if (current..=class[i + 2]).contains(&c) {
+                matched = true;
+            }
+            i += 3;
+        } else {
+            if current == c {
+                matched = true;
+            }
+            i += 1;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(s: &str) -> GitPattern {
+        GitPattern::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_unanchored_matches_any_level() {
+        assert!(p("*.js").matches("src/app/main.js", false));
+        assert!(p("build").matches("packages/a/build", true));
+        assert!(!p("*.js").matches("src/main.ts", false));
+    }
+
+    #[test]
+    fn test_anchored_and_double_star() {
+        assert!(p("/src/*.ts").matches("src/a.ts", false));
+        assert!(!p("/src/*.ts").matches("lib/src/a.ts", false));
+        assert!(p("apps/**/api").matches("apps/web/v1/api", true));
+        assert!(p("docs/**").matches("docs/a/b.md", false));
+        assert!(!p("docs/**").matches("docs", true));
+    }
+
+    #[test]
+    fn test_dir_only_and_parents() {
+        assert!(!p("logs/").matches("logs", false));
+        assert!(p("logs/").matches_path_or_parent("logs/today.txt", false));
+        assert!(p("/apps/payments").matches_path_or_parent("apps/payments/src/a.ts", false));
+    }
+
+    #[test]
+    fn test_negation_comments_and_classes() {
+        assert!(p("!keep.txt").negated);
+        assert!(GitPattern::parse("# comment").is_none());
+        assert!(GitPattern::parse("   ").is_none());
+        assert!(p("file[0-9].txt").matches("file7.txt", false));
+        assert!(!p("file[!0-9].txt").matches("file7.txt", false));
+    }
+}
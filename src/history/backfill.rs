@@ -0,0 +1,121 @@
+//! Backfill the score history from past commits
+//!
+//! Each selected commit is exported from the git object database into a
+//! scratch directory (the working tree and index are never touched), analyzed
+//! with the `architect.json` it had at the time, and merged into the history
+//! ordered by commit date.
+
+use super::{history_path, load, write_all, HistoryEntry};
+use crate::analyzer;
+use crate::config::{self, LinterContext};
+use crate::git::{self, CommitInfo};
+use miette::{IntoDiagnostic, Result};
+use std::fs;
+use std::path::Path;
+
+/// Which commits to analyze
+#[derive(Debug, Clone)]
+pub struct BackfillOptions {
+    /// Single revision (`HEAD`, `v2.0`) or `from..to`
+    pub range: String,
+    /// Analyze every Nth commit (1 = all)
+    pub every: usize,
+    /// Only consider tagged commits
+    pub tags_only: bool,
+}
+
+impl Default for BackfillOptions {
+    fn default() -> Self {
+        Self {
+            range: "HEAD".to_string(),
+            every: 1,
+            tags_only: false,
+        }
+    }
+}
+
+/// Outcome of a backfill run
+#[derive(Debug, Default)]
+pub struct BackfillSummary {
+    /// Entries added to the history
+    pub added: usize,
+    /// Commits skipped because the history already had them
+    pub skipped: usize,
+    /// Commits that could not be analyzed, with the reason
+    pub failed: Vec<(String, String)>,
+}
+
+/// Analyze the selected commits and merge them into `.architect/history.jsonl`.
+///
+/// `fallback` is used for commits that have no valid `architect.json`.
+/// `on_commit` is called before each analysis with `(index, total, commit)`.
+pub fn backfill(
+    project_root: &Path,
+    options: &BackfillOptions,
+    fallback: &LinterContext,
+    mut on_commit: impl FnMut(usize, usize, &CommitInfo),
+) -> Result<BackfillSummary> {
+    let subdir = git::repo_relative_path(project_root)?;
+    let commits = git::list_commits(
+        project_root,
+        &options.range,
+        options.every,
+        options.tags_only,
+    )?;
+
+    let mut entries = load(project_root)?;
+    let mut summary = BackfillSummary::default();
+
+    for (i, commit) in commits.iter().enumerate() {
+        if entries
+            .iter()
+            .any(|e| e.commit.as_deref() == Some(commit.sha.as_str()))
+        {
+            summary.skipped += 1;
+            continue;
+        }
+
+        on_commit(i, commits.len(), commit);
+        match analyze_commit(project_root, &subdir, commit, fallback) {
+            Ok(entry) => {
+                entries.push(entry);
+                summary.added += 1;
+            }
+            Err(e) => summary.failed.push((commit.sha.clone(), e.to_string())),
+        }
+    }
+
+    if summary.added > 0 {
+        entries.sort_by_key(|e| e.timestamp);
+        let path = history_path(project_root);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).into_diagnostic()?;
+        }
+        write_all(&path, &entries)?;
+    }
+
+    Ok(summary)
+}
+
+/// Run the full analysis on a single commit without touching the working tree
+pub fn analyze_commit(
+    project_root: &Path,
+    subdir: &Path,
+    commit: &CommitInfo,
+    fallback: &LinterContext,
+) -> Result<HistoryEntry> {
+    let scratch = tempfile::tempdir().into_diagnostic()?;
+    let root = scratch.path();
+    git::export_commit_tree(project_root, &commit.sha, subdir, root)?;
+
+    // Use the rules the project had at that point in time when possible
+    let loaded = config::load_config(root);
+    let ctx = loaded.as_ref().unwrap_or(fallback);
+
+    let result = analyzer::analyze_project(root, ctx)?;
+
+    let mut entry = HistoryEntry::from_result(&result, Some(commit.sha.clone()))
+        .ok_or_else(|| miette::miette!("No se pudo calcular el Health Score de {}", commit.sha))?;
+    entry.timestamp = commit.time;
+    Ok(entry)
+}
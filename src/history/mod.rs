@@ -0,0 +1,253 @@
+//! Health score history for trend tracking
+//!
+//! With `history.enabled`, every full analysis appends one JSON line to
+//! `.architect/history.jsonl` keyed by git commit SHA and timestamp. The `trend` command and the HTML
+//! report read it back to chart how the score evolves and flag regressions.
+//! `backfill` fills the history from past commits.
+
+pub mod backfill;
+
+use crate::analysis_result::AnalysisResult;
+use crate::metrics::ScoreComponents;
+use chrono::{DateTime, Utc};
+use miette::{IntoDiagnostic, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Directory (relative to the project root) holding the history file
+pub const HISTORY_DIR: &str = ".architect";
+/// History file name inside `HISTORY_DIR`
+pub const HISTORY_FILE: &str = "history.jsonl";
+
+/// One data point of the score time series
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Commit SHA analyzed (None outside a git repository)
+    pub commit: Option<String>,
+    /// When the analysis ran (or the commit date when backfilled)
+    pub timestamp: DateTime<Utc>,
+    pub total: u8,
+    pub grade: String,
+    pub components: ScoreComponents,
+    /// Violation count per rule (`from -> to`)
+    pub violations_by_rule: BTreeMap<String, usize>,
+    pub total_violations: usize,
+    pub cycles: usize,
+    pub long_functions: usize,
+    pub files_analyzed: usize,
+}
+
+impl HistoryEntry {
+    /// Build an entry from a scored analysis result; `None` if no score was computed
+    pub fn from_result(result: &AnalysisResult, commit: Option<String>) -> Option<Self> {
+        let score = result.health_score.as_ref()?;
+
+        let mut violations_by_rule = BTreeMap::new();
+        for cv in &result.violations {
+            let rule = format!("{} -> {}", cv.violation.rule.from, cv.violation.rule.to);
+            *violations_by_rule.entry(rule).or_insert(0) += 1;
+        }
+
+        Some(Self {
+            commit,
+            timestamp: result.timestamp,
+            total: score.total,
+            grade: score.grade.as_str().to_string(),
+            components: score.components,
+            violations_by_rule,
+            total_violations: result.violations.len(),
+            cycles: result.circular_dependencies.len(),
+            long_functions: result.long_functions.len(),
+            files_analyzed: result.files_analyzed,
+        })
+    }
+
+    /// First 7 characters of the commit SHA, or `-`
+    pub fn short_commit(&self) -> &str {
+        self.commit
+            .as_deref()
+            .map(|c| &c[..c.len().min(7)])
+            .unwrap_or("-")
+    }
+}
+
+/// Path of the history file for a project
+pub fn history_path(project_root: &Path) -> PathBuf {
+    project_root.join(HISTORY_DIR).join(HISTORY_FILE)
+}
+
+/// Load the history, oldest first. Malformed lines are skipped.
+pub fn load(project_root: &Path) -> Result<Vec<HistoryEntry>> {
+    let path = history_path(project_root);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).into_diagnostic()?;
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                tracing::warn!("Skipping malformed history line: {}", e);
+                None
+            }
+        })
+        .collect())
+}
+
+/// Append an entry. If the last entry is for the same commit it is replaced,
+/// so re-running on an unchanged HEAD does not pile up duplicate points.
+pub fn record(project_root: &Path, entry: HistoryEntry) -> Result<()> {
+    let path = history_path(project_root);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).into_diagnostic()?;
+    }
+
+    let mut entries = load(project_root)?;
+    let same_commit = entry.commit.is_some()
+        && entries.last().map(|last| last.commit == entry.commit) == Some(true);
+
+    if same_commit {
+        entries.pop();
+        entries.push(entry);
+        write_all(&path, &entries)
+    } else {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .into_diagnostic()?;
+        let line = serde_json::to_string(&entry).into_diagnostic()?;
+        writeln!(file, "{}", line).into_diagnostic()
+    }
+}
+
+/// Rewrite the whole history file
+pub fn write_all(path: &Path, entries: &[HistoryEntry]) -> Result<()> {
+    let mut content = String::new();
+    for entry in entries {
+        content.push_str(&serde_json::to_string(entry).into_diagnostic()?);
+        content.push('\n');
+    }
+    fs::write(path, content).into_diagnostic()
+}
+
+/// A drop between two consecutive entries larger than the threshold
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    /// Index of the regressed entry in the history
+    pub index: usize,
+    pub commit: Option<String>,
+    /// `(metric, before, after)` for every metric that dropped beyond the threshold
+    pub drops: Vec<(&'static str, u8, u8)>,
+}
+
+/// Compare two consecutive entries
+pub fn detect_regression(
+    previous: &HistoryEntry,
+    current: &HistoryEntry,
+    threshold: u8,
+) -> Vec<(&'static str, u8, u8)> {
+    let metrics = [
+        ("total", previous.total, current.total),
+        (
+            "layer_isolation",
+            previous.components.layer_isolation,
+            current.components.layer_isolation,
+        ),
+        (
+            "circular_deps",
+            previous.components.circular_deps,
+            current.components.circular_deps,
+        ),
+        (
+            "complexity",
+            previous.components.complexity,
+            current.components.complexity,
+        ),
+        (
+            "violations",
+            previous.components.violations,
+            current.components.violations,
+        ),
+    ];
+    metrics
+        .into_iter()
+        .filter(|(_, before, after)| before.saturating_sub(*after) > threshold)
+        .collect()
+}
+
+/// All regressions in the history, oldest first
+pub fn find_regressions(entries: &[HistoryEntry], threshold: u8) -> Vec<Regression> {
+    entries
+        .windows(2)
+        .enumerate()
+        .filter_map(|(i, pair)| {
+            let drops = detect_regression(&pair[0], &pair[1], threshold);
+            (!drops.is_empty()).then(|| Regression {
+                index: i + 1,
+                commit: pair[1].commit.clone(),
+                drops,
+            })
+        })
+        .collect()
+}
+
+/// Render the total score as an ASCII chart (one column per entry).
+/// Regressed entries are drawn with `▼`.
+pub fn render_ascii_chart(entries: &[HistoryEntry], height: usize, threshold: u8) -> String {
+    if entries.is_empty() {
+        return String::new();
+    }
+    let height = height.max(2);
+    let regressions: Vec<usize> = find_regressions(entries, threshold)
+        .iter()
+        .map(|r| r.index)
+        .collect();
+
+    let min = entries
+        .iter()
+        .map(|e| e.total)
+        .min()
+        .unwrap_or(0)
+        .saturating_sub(5) as f64;
+    let max = entries
+        .iter()
+        .map(|e| e.total)
+        .max()
+        .unwrap_or(100)
+        .saturating_add(5)
+        .min(100) as f64;
+    let span = (max - min).max(1.0);
+    let row_of = |value: u8| -> usize {
+        (((max - value as f64) / span) * (height - 1) as f64).round() as usize
+    };
+
+    let mut chart = String::new();
+    for row in 0..height {
+        let label = max - span * row as f64 / (height - 1) as f64;
+        chart.push_str(&format!("{:>4.0} ┤", label));
+        for (i, entry) in entries.iter().enumerate() {
+            if row_of(entry.total) == row {
+                chart.push(if regressions.contains(&i) {
+                    '▼'
+                } else {
+                    '●'
+                });
+            } else {
+                chart.push(' ');
+            }
+        }
+        chart.push('\n');
+    }
+    chart.push_str(&format!("     └{}\n", "─".repeat(entries.len())));
+
+    let first = entries.first().map(|e| e.short_commit()).unwrap_or("-");
+    let last = entries.last().map(|e| e.short_commit()).unwrap_or("-");
+    chart.push_str(&format!("      {} … {}\n", first, last));
+    chart
+}
@@ -0,0 +1,297 @@
+//! Ignore files
+//!
+//! Discovery skips what git skips. Rules are read, from lowest to highest
+//! priority, from the global excludes file (`core.excludesFile`, by default
+//! `$XDG_CONFIG_HOME/git/ignore`), the repository's `.git/info/exclude` and
+//! the `.gitignore` and `.architectignore` of every directory from the
+//! repository root down to the file. Deeper files win over shallower ones and
+//! `.architectignore` wins over `.gitignore` in the same directory.
+//!
+//! As in git, the last matching pattern decides, `!pattern` re-includes a
+//! path and nothing can be re-included once a parent directory is excluded.
+//! Outside a git repository the ignore files of the project are still read.
+
+use crate::gitpattern::GitPattern;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Ignore file specific to the linter, with the same syntax as `.gitignore`
+pub const ARCHITECT_IGNORE: &str = ".architectignore";
+
+/// Where an ignore rule was read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IgnoreFileKind {
+    /// `core.excludesFile` or `$XDG_CONFIG_HOME/git/ignore`
+    GlobalExcludes,
+    /// `.git/info/exclude`
+    InfoExclude,
+    /// `.gitignore`
+    GitIgnore,
+    /// `.architectignore`
+    ArchitectIgnore,
+}
+
+impl IgnoreFileKind {
+    /// Name shown in diagnostics
+    pub fn label(&self) -> &'static str {
+        match self {
+            IgnoreFileKind::GlobalExcludes => "exclusiones globales de git",
+            IgnoreFileKind::InfoExclude => ".git/info/exclude",
+            IgnoreFileKind::GitIgnore => ".gitignore",
+            IgnoreFileKind::ArchitectIgnore => ARCHITECT_IGNORE,
+        }
+    }
+}
+
+/// One pattern line of an ignore file
+#[derive(Debug, Clone)]
+pub struct IgnoreRule {
+    pub pattern: GitPattern,
+    pub kind: IgnoreFileKind,
+    /// File the pattern was read from
+    pub file: PathBuf,
+    /// 1-based line number in `file`
+    pub line: usize,
+    /// Directory the pattern is relative to, `/`-separated from the top
+    base: String,
+}
+
+impl IgnoreRule {
+    /// `file:line`, for diagnostics
+    pub fn location(&self) -> String {
+        format!("{}:{}", self.file.display(), self.line)
+    }
+}
+
+/// The rule that decides a path and the path it matched, which is the path
+/// itself or, when a parent directory is excluded, that directory
+#[derive(Debug, Clone)]
+pub struct IgnoreMatch {
+    pub rule: IgnoreRule,
+    /// Matched path, relative to the project root
+    pub path: String,
+}
+
+impl IgnoreMatch {
+    /// Whether the path ends up excluded (the deciding rule is not a negation)
+    pub fn is_ignored(&self) -> bool {
+        !self.rule.pattern.negated
+    }
+}
+
+/// Ignore rules of a project, with per-directory files loaded on demand
+#[derive(Debug, Clone)]
+pub struct IgnoreRules {
+    /// Repository working directory, or the project root outside a repository
+    top: PathBuf,
+    /// Project root relative to `top` (empty when they are the same)
+    prefix: String,
+    /// Global excludes and `.git/info/exclude`
+    repo_rules: Vec<IgnoreRule>,
+    /// `.gitignore` and `.architectignore` rules by directory relative to `top`
+    dir_rules: HashMap<String, Vec<IgnoreRule>>,
+}
+
+impl IgnoreRules {
+    /// Rules that apply to the project at `root`
+    pub fn load(root: &Path) -> Self {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let mut top = root.clone();
+        let mut prefix = String::new();
+        let mut repo_rules = Vec::new();
+
+        match git2::Repository::discover(&root) {
+            Ok(repo) => {
+                if let Some(path) = global_excludes_file(repo.config().ok()) {
+                    repo_rules.extend(read_rules(&path, IgnoreFileKind::GlobalExcludes, ""));
+                }
+                let info_exclude = repo.commondir().join("info").join("exclude");
+                repo_rules.extend(read_rules(&info_exclude, IgnoreFileKind::InfoExclude, ""));
+
+                if let Some(workdir) = repo.workdir() {
+                    let workdir = workdir
+                        .canonicalize()
+                        .unwrap_or_else(|_| workdir.to_path_buf());
+                    if let Ok(rel) = root.strip_prefix(&workdir) {
+                        prefix = rel.to_string_lossy().replace('\\', "/");
+                        top = workdir;
+                    }
+                }
+            }
+            Err(_) => {
+                if let Some(path) = global_excludes_file(git2::Config::open_default().ok()) {
+                    repo_rules.extend(read_rules(&path, IgnoreFileKind::GlobalExcludes, ""));
+                }
+            }
+        }
+
+        Self {
+            top,
+            prefix,
+            repo_rules,
+            dir_rules: HashMap::new(),
+        }
+    }
+
+    /// Whether `rel` (relative to the project root, `/`-separated) is
+    /// excluded by its own rules. Parent directories are not checked: the
+    /// discovery walk never descends into an excluded directory.
+    pub fn is_ignored(&mut self, rel: &str, is_dir: bool) -> bool {
+        self.decide(rel, is_dir)
+            .is_some_and(|rule| !rule.pattern.negated)
+    }
+
+    /// The rule that decides `rel`, looking at its parent directories first.
+    /// Returns the excluding rule of the first excluded parent if there is
+    /// one; otherwise the last rule matching `rel` itself, which may be a
+    /// negation that re-included it. None when no rule matches.
+    pub fn explain(&mut self, rel: &str, is_dir: bool) -> Option<IgnoreMatch> {
+        let parts: Vec<&str> = rel.split('/').filter(|s| !s.is_empty()).collect();
+        for n in 1..parts.len() {
+            let parent = parts[..n].join("/");
+            if let Some(rule) = self.decide(&parent, true) {
+                if !rule.pattern.negated {
+                    return Some(IgnoreMatch {
+                        rule: rule.clone(),
+                        path: parent,
+                    });
+                }
+            }
+        }
+        self.decide(rel, is_dir).cloned().map(|rule| IgnoreMatch {
+            rule,
+            path: parts.join("/"),
+        })
+    }
+
+    /// Last rule matching `rel` itself
+    fn decide(&mut self, rel: &str, is_dir: bool) -> Option<&IgnoreRule> {
+        if rel.split('/').all(|s| s.is_empty()) {
+            return None;
+        }
+        let parts: Vec<&str> = self
+            .prefix
+            .split('/')
+            .chain(rel.split('/'))
+            .filter(|s| !s.is_empty())
+            .collect();
+        let path = parts.join("/");
+
+        // Directories whose ignore files apply, from the top down
+        let dirs: Vec<String> = (0..parts.len()).map(|n| parts[..n].join("/")).collect();
+        for dir in &dirs {
+            if !self.dir_rules.contains_key(dir) {
+                let rules = self.read_dir_rules(dir);
+                self.dir_rules.insert(dir.clone(), rules);
+            }
+        }
+
+        let rules = self
+            .repo_rules
+            .iter()
+            .chain(dirs.iter().flat_map(|dir| &self.dir_rules[dir]));
+        let mut decided = None;
+        for rule in rules {
+            let rel_to_base = if rule.base.is_empty() {
+                path.as_str()
+            } else {
+                &path[rule.base.len() + 1..]
+            };
+            if rule.pattern.matches(rel_to_base, is_dir) {
+                decided = Some(rule);
+            }
+        }
+        decided
+    }
+
+    /// `.gitignore` and `.architectignore` of `dir` (relative to the top)
+    fn read_dir_rules(&self, dir: &str) -> Vec<IgnoreRule> {
+        let path = self.top.join(dir);
+        let mut rules = read_rules(&path.join(".gitignore"), IgnoreFileKind::GitIgnore, dir);
+        rules.extend(read_rules(
+            &path.join(ARCHITECT_IGNORE),
+            IgnoreFileKind::ArchitectIgnore,
+            dir,
+        ));
+        rules
+    }
+}
+
+/// `core.excludesFile` if set, else `$XDG_CONFIG_HOME/git/ignore`
+/// (`~/.config/git/ignore` when XDG_CONFIG_HOME is unset)
+fn global_excludes_file(config: Option<git2::Config>) -> Option<PathBuf> {
+    if let Some(path) = config.and_then(|c| c.get_path("core.excludesFile").ok()) {
+        return Some(path);
+    }
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_home.join("git").join("ignore"))
+}
+
+/// Patterns of an ignore file; empty when it does not exist
+fn read_rules(file: &Path, kind: IgnoreFileKind, base: &str) -> Vec<IgnoreRule> {
+    let Ok(content) = fs::read_to_string(file) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            Some(IgnoreRule {
+                pattern: GitPattern::parse(line)?,
+                kind,
+                file: file.to_path_buf(),
+                line: i + 1,
+                base: base.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_nested_files_and_negation() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("src/gen")).unwrap();
+        fs::write(dir.path().join(".gitignore"), "*.log\ngenerated/\n").unwrap();
+        fs::write(dir.path().join("src/.gitignore"), "*.gen.ts\n").unwrap();
+        fs::write(dir.path().join("src/.architectignore"), "!keep.gen.ts\n").unwrap();
+
+        let mut rules = IgnoreRules::load(dir.path());
+        assert!(rules.is_ignored("debug.log", false));
+        assert!(rules.is_ignored("src/api.gen.ts", false));
+        assert!(!rules.is_ignored("src/keep.gen.ts", false));
+        assert!(rules.is_ignored("src/generated", true));
+        assert!(!rules.is_ignored("src/generated", false));
+        assert!(!rules.is_ignored("api.gen.ts", false));
+
+        let found = rules.explain("src/generated/a.ts", false).unwrap();
+        assert_eq!(found.path, "src/generated");
+        assert_eq!(found.rule.line, 2);
+        let kept = rules.explain("src/keep.gen.ts", false).unwrap();
+        assert!(!kept.is_ignored());
+        assert_eq!(kept.rule.kind, IgnoreFileKind::ArchitectIgnore);
+    }
+
+    #[test]
+    fn test_trailing_double_star_excludes_contents_only() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join(".gitignore"), "foo/**\n!foo/keep.ts\n").unwrap();
+
+        let mut rules = IgnoreRules::load(dir.path());
+        assert!(!rules.is_ignored("foo", true));
+        assert!(rules.is_ignored("foo/drop.ts", false));
+        assert!(rules.is_ignored("foo/bar", true));
+
+        let kept = rules.explain("foo/keep.ts", false).unwrap();
+        assert!(!kept.is_ignored());
+        assert_eq!(kept.rule.line, 2);
+    }
+}
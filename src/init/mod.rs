@@ -12,12 +12,17 @@ use std::path::Path;
 /// Check that architect.json does not exist at `root`, unless `force` is true.
 /// Returns Ok(()) if it's safe to proceed, Err if the user should abort.
 pub fn check_no_existing_config(root: &Path, force: bool) -> Result<()> {
-    let config_path = root.join("architect.json");
-    if config_path.exists() && !force {
-        return Err(miette::miette!(
-            "Ya existe architect.json en {}.\n   Usa --force para sobreescribir.",
-            root.display()
-        ));
+    if let Some(config_path) = crate::config::find_config(root) {
+        if !force {
+            return Err(miette::miette!(
+                "Ya existe {} en {}.\n   Usa --force para sobreescribir.",
+                config_path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy(),
+                root.display()
+            ));
+        }
     }
     Ok(())
 }
@@ -19,11 +19,19 @@ pub fn rule(from: &str, to: &str, severity: &str, reason: &str) -> ForbiddenRule
 pub fn base_config(pattern: &str, rules: Vec<ForbiddenRule>) -> ConfigFile {
     use crate::config::default_ignored_paths;
     ConfigFile {
+        version: Some(crate::config::migration::CURRENT_VERSION),
         architecture_pattern: ArchPattern::Custom(pattern.to_string()),
         forbidden_imports: rules,
         max_lines_per_function: 40,
         ignored_paths: default_ignored_paths(),
         build_command: None,
         ai_fix_retries: 3,
+        presets: Default::default(),
+        history: Default::default(),
+        modules: Vec::new(),
+        external_imports: Vec::new(),
+        dependency_audit: Default::default(),
+        workspace: Default::default(),
+        generated_code: Default::default(),
     }
 }
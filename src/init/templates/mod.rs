@@ -102,3 +102,34 @@ pub fn get_template(framework: &Framework, pattern: &str) -> Option<ConfigFile>
         _ => None,
     }
 }
+
+/// Frameworks whose templates are also built-in presets, by the name used in
+/// `"extends": ["preset:<name>-<pattern>"]`
+fn preset_frameworks() -> [(&'static str, Framework); 4] {
+    [
+        ("nestjs", Framework::NestJS),
+        ("express", Framework::Express),
+        ("nextjs", Framework::React),
+        ("django", Framework::Django),
+    ]
+}
+
+/// Names of every built-in preset (`nestjs-hexagonal`, `django-mvt`, ...)
+pub fn preset_names() -> Vec<String> {
+    preset_frameworks()
+        .iter()
+        .flat_map(|(name, framework)| {
+            patterns_for_framework(framework)
+                .into_iter()
+                .map(move |option| format!("{}-{}", name, option.pattern))
+        })
+        .collect()
+}
+
+/// The template behind a built-in preset, e.g. `nestjs-hexagonal`
+pub fn get_preset(name: &str) -> Option<ConfigFile> {
+    preset_frameworks().iter().find_map(|(prefix, framework)| {
+        let pattern = name.strip_prefix(prefix)?.strip_prefix('-')?;
+        get_template(framework, pattern)
+    })
+}
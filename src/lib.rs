@@ -9,28 +9,40 @@ pub mod ai;
 pub mod analysis_result;
 pub mod analyzer;
 pub mod autofix;
+pub mod blame;
 pub mod cache;
 pub mod circular;
 pub mod cli;
+pub mod codeowners;
 pub mod config;
+pub mod daemon;
+pub mod dependencies;
 pub mod detection;
 pub mod detector;
+pub mod diff;
 pub mod discovery;
 pub mod git;
 pub mod git_changes;
+pub mod gitpattern;
+pub mod history;
+pub mod ignore_rules;
 pub mod logging;
+pub mod lsp;
 pub mod security;
 pub mod source_span;
+pub mod suppression;
 
 pub mod metrics;
 pub mod notification;
 pub mod output;
+pub mod packages;
 pub mod parsers;
 pub mod report;
 pub mod scoring;
 pub mod ui;
 pub mod init;
 pub mod watch;
+pub mod workspace;
 pub mod features;
 pub mod license;
 
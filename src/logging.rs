@@ -4,9 +4,35 @@
 //! It supports different log levels based on debug mode and provides structured
 //! logging for better observability.
 
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
 use tracing::Level;
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
+/// Where [`init`] writes while a full-screen UI owns the terminal
+static REDIRECT: Mutex<Option<File>> = Mutex::new(None);
+
+/// stdout, unless [`redirect_to_file`] is active
+struct Output;
+
+impl<'a> MakeWriter<'a> for Output {
+    type Writer = Box<dyn Write + 'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        let file = REDIRECT
+            .lock()
+            .ok()
+            .and_then(|file| file.as_ref().and_then(|file| file.try_clone().ok()));
+        match file {
+            Some(file) => Box::new(file),
+            None => Box::new(std::io::stdout()),
+        }
+    }
+}
+
 /// Initialize the tracing subscriber with appropriate log level
 ///
 /// # Arguments
@@ -38,6 +64,7 @@ pub fn init(debug_mode: bool) {
                 .with_thread_ids(debug_mode) // Show thread IDs in debug mode
                 .with_line_number(debug_mode) // Show line numbers in debug mode
                 .with_file(debug_mode) // Show file names in debug mode
+                .with_writer(Output)
                 .compact(), // Use compact format for better readability
         );
 
@@ -47,7 +74,51 @@ pub fn init(debug_mode: bool) {
     }
 }
 
+/// Like [`init`], but writes to stderr
+///
+/// Used when stdout carries a protocol, as in `architect-linter-pro lsp`.
+pub fn init_stderr(debug_mode: bool) {
+    let log_level = if debug_mode {
+        Level::DEBUG
+    } else {
+        Level::WARN
+    };
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(log_level.to_string()));
+
+    let subscriber = tracing_subscriber::registry().with(env_filter).with(
+        fmt::layer()
+            .with_writer(std::io::stderr)
+            .with_ansi(false)
+            .with_target(debug_mode)
+            .compact(),
+    );
 
+    if let Err(e) = subscriber.try_init() {
+        eprintln!("⚠️  Failed to initialize logging: {}", e);
+    }
+}
+
+/// Append the log of [`init`] to `path` instead of stdout, until
+/// [`restore_stdout`]. Used by the watch dashboard, which draws over the
+/// whole terminal.
+pub fn redirect_to_file(path: &Path) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    if let Ok(mut redirect) = REDIRECT.lock() {
+        *redirect = Some(file);
+    }
+    Ok(())
+}
+
+/// Send the log back to stdout
+pub fn restore_stdout() {
+    if let Ok(mut redirect) = REDIRECT.lock() {
+        *redirect = None;
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,225 @@
+//! Language Server Protocol
+//!
+//! `architect-linter-pro lsp` speaks LSP over stdio so any editor with an LSP
+//! client (VS Code, Neovim, Helix, JetBrains) gets the linter's findings as
+//! the user types:
+//!
+//! - `session`: documents, configuration and the incremental import graph
+//!
+//! Diagnostics are published for open documents from their unsaved text.
+//! Code actions offer an `architect-ignore-next-line` suppression and, for
+//! import rules, removing the offending import; hovering a violation shows
+//! the rule and its `reason`. Saving the configuration file reloads it.
+
+pub mod session;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+    Notification as _, PublishDiagnostics, ShowMessage,
+};
+use lsp_types::request::{CodeActionRequest, HoverRequest, Request as _};
+use lsp_types::{
+    CodeActionKind, CodeActionOptions, CodeActionProviderCapability, HoverProviderCapability,
+    InitializeParams, MessageType, PublishDiagnosticsParams, SaveOptions, ServerCapabilities,
+    ServerInfo, ShowMessageParams, TextDocumentSyncCapability, TextDocumentSyncKind,
+    TextDocumentSyncOptions, TextDocumentSyncSaveOptions,
+};
+use miette::{IntoDiagnostic, Result};
+use session::Session;
+use std::path::{Path, PathBuf};
+
+/// Run the server on stdin/stdout until the client sends `exit`.
+/// `fallback_root` is used when the client sends no workspace folder.
+pub fn run(fallback_root: &Path) -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let (id, params) = connection.initialize_start().into_diagnostic()?;
+    let params: InitializeParams = serde_json::from_value(params).into_diagnostic()?;
+    let root = workspace_root(&params).unwrap_or_else(|| fallback_root.to_path_buf());
+    let result = serde_json::json!({
+        "capabilities": capabilities(),
+        "serverInfo": ServerInfo {
+            name: "architect-linter-pro".to_string(),
+            version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        },
+    });
+    connection.initialize_finish(id, result).into_diagnostic()?;
+    tracing::info!("LSP session started for {}", root.display());
+
+    let mut session = Session::new(&root);
+    report_config_error(&connection, &session)?;
+    main_loop(&connection, &mut session)?;
+
+    drop(connection);
+    io_threads.join().into_diagnostic()?;
+    Ok(())
+}
+
+fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Options(
+            TextDocumentSyncOptions {
+                open_close: Some(true),
+                change: Some(TextDocumentSyncKind::FULL),
+                save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions {
+                    include_text: Some(false),
+                })),
+                ..Default::default()
+            },
+        )),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+            code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+}
+
+#[allow(deprecated)] // root_uri and root_path are still what most clients send
+fn workspace_root(params: &InitializeParams) -> Option<PathBuf> {
+    params
+        .workspace_folders
+        .as_ref()
+        .and_then(|folders| folders.first())
+        .and_then(|folder| folder.uri.to_file_path().ok())
+        .or_else(|| params.root_uri.as_ref()?.to_file_path().ok())
+        .or_else(|| params.root_path.as_ref().map(PathBuf::from))
+}
+
+fn main_loop(connection: &Connection, session: &mut Session) -> Result<()> {
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request).into_diagnostic()? {
+                    return Ok(());
+                }
+                let response = handle_request(session, request);
+                connection
+                    .sender
+                    .send(Message::Response(response))
+                    .into_diagnostic()?;
+            }
+            Message::Notification(notification) => {
+                let (published, reloaded) =
+                    handle_notification(session, notification).unwrap_or_default();
+                for params in published {
+                    send_notification::<PublishDiagnostics>(connection, params)?;
+                }
+                if reloaded {
+                    report_config_error(connection, session)?;
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+    Ok(())
+}
+
+fn handle_request(session: &Session, request: Request) -> Response {
+    let id = request.id.clone();
+    match request.method.as_str() {
+        HoverRequest::METHOD => {
+            match request.extract::<lsp_types::HoverParams>(HoverRequest::METHOD) {
+                Ok((id, params)) => {
+                    let position = params.text_document_position_params;
+                    Response::new_ok(
+                        id,
+                        session.hover(&position.text_document.uri, position.position),
+                    )
+                }
+                Err(err) => invalid_params(id, err),
+            }
+        }
+        CodeActionRequest::METHOD => {
+            match request.extract::<lsp_types::CodeActionParams>(CodeActionRequest::METHOD) {
+                Ok((id, params)) => Response::new_ok(
+                    id,
+                    session.code_actions(&params.text_document.uri, params.range),
+                ),
+                Err(err) => invalid_params(id, err),
+            }
+        }
+        method => Response::new_err(
+            id,
+            ErrorCode::MethodNotFound as i32,
+            format!("Método no soportado: {}", method),
+        ),
+    }
+}
+
+/// Diagnostics to publish, and whether the configuration was reloaded
+fn handle_notification(
+    session: &mut Session,
+    notification: Notification,
+) -> Option<(Vec<PublishDiagnosticsParams>, bool)> {
+    match notification.method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: lsp_types::DidOpenTextDocumentParams =
+                serde_json::from_value(notification.params).ok()?;
+            let document = params.text_document;
+            Some((session.update(document.uri, document.text), false))
+        }
+        DidChangeTextDocument::METHOD => {
+            let params: lsp_types::DidChangeTextDocumentParams =
+                serde_json::from_value(notification.params).ok()?;
+            // Full sync: the last change carries the whole text
+            let text = params.content_changes.into_iter().last()?.text;
+            Some((session.update(params.text_document.uri, text), false))
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: lsp_types::DidCloseTextDocumentParams =
+                serde_json::from_value(notification.params).ok()?;
+            Some((session.close(&params.text_document.uri), false))
+        }
+        DidSaveTextDocument::METHOD => {
+            let params: lsp_types::DidSaveTextDocumentParams =
+                serde_json::from_value(notification.params).ok()?;
+            let path = params.text_document.uri.to_file_path().ok()?;
+            if session.is_config_file(&path) {
+                Some((session.reload(), true))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn report_config_error(connection: &Connection, session: &Session) -> Result<()> {
+    let Some(error) = session.config_error() else {
+        return Ok(());
+    };
+    send_notification::<ShowMessage>(
+        connection,
+        ShowMessageParams {
+            typ: MessageType::ERROR,
+            message: format!(
+                "architect-linter: no se pudo cargar la configuración: {}",
+                error
+            ),
+        },
+    )
+}
+
+fn send_notification<N: lsp_types::notification::Notification>(
+    connection: &Connection,
+    params: N::Params,
+) -> Result<()> {
+    connection
+        .sender
+        .send(Message::Notification(Notification::new(
+            N::METHOD.to_string(),
+            params,
+        )))
+        .into_diagnostic()
+}
+
+fn invalid_params(id: lsp_server::RequestId, err: impl std::fmt::Debug) -> Response {
+    Response::new_err(
+        id,
+        ErrorCode::InvalidParams as i32,
+        format!("Parámetros inválidos: {:?}", err),
+    )
+}
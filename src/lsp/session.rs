@@ -0,0 +1,743 @@
+//! Language server state
+//!
+//! Open documents are analyzed from their in-memory text, never from disk.
+//! The import graph is built once from the files on disk and then kept up to
+//! date with `CircularDependencyAnalyzer::update_file_content`, so an edit
+//! only re-checks cycles in the part of the graph it is connected to.
+
+use crate::analyzer::generated::GeneratedCodeDetector;
+use crate::analyzer::swc_parser::{collect_findings_from_source, violation_message};
+use crate::autofix::Violation;
+use crate::cache::AnalysisCache;
+use crate::circular::{CircularDependency, CircularDependencyAnalyzer};
+use crate::config::{self, GeneratedCodeMode, LinterContext, Severity};
+use crate::discovery::{self, IgnoreExplanation};
+use crate::parsers::Language;
+use crate::suppression;
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, DiagnosticSeverity, Hover,
+    HoverContents, MarkupContent, MarkupKind, NumberOrString, Position, PublishDiagnosticsParams,
+    Range, TextEdit, Url, WorkspaceEdit,
+};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// `source` of every diagnostic published by the server
+pub const DIAGNOSTIC_SOURCE: &str = "architect-linter";
+
+/// A violation in an open document
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub violation: Violation,
+    /// Reported by the security audit
+    pub security: bool,
+}
+
+/// An open document
+struct Document {
+    path: PathBuf,
+    text: String,
+    findings: Vec<Finding>,
+    /// Excluded by `ignored_paths` or an ignore file: never reported
+    ignored: bool,
+    /// Generated code analyzed with `generated_code.mode = downweight`
+    downweighted: bool,
+}
+
+/// Server state: configuration, open documents and the import graph
+pub struct Session {
+    root: PathBuf,
+    ctx: Option<LinterContext>,
+    config_error: Option<String>,
+    documents: HashMap<Url, Document>,
+    graph: CircularDependencyAnalyzer,
+    cycles: Vec<CircularDependency>,
+}
+
+impl Session {
+    /// Load the configuration of `root` and build the import graph from disk
+    pub fn new(root: &Path) -> Self {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let mut session = Self {
+            graph: CircularDependencyAnalyzer::new(&root),
+            root,
+            ctx: None,
+            config_error: None,
+            documents: HashMap::new(),
+            cycles: Vec::new(),
+        };
+        session.load_config();
+        session.build_graph();
+        session
+    }
+
+    /// Why the configuration could not be loaded, if it could not
+    pub fn config_error(&self) -> Option<&str> {
+        self.config_error.as_deref()
+    }
+
+    /// Whether `path` is the project configuration file
+    pub fn is_config_file(&self, path: &Path) -> bool {
+        config::find_config(&self.root).is_some_and(|config| {
+            config.canonicalize().unwrap_or(config)
+                == path.canonicalize().unwrap_or(path.to_path_buf())
+        })
+    }
+
+    /// Reload the configuration and re-analyze every open document.
+    /// Returns the diagnostics to publish.
+    pub fn reload(&mut self) -> Vec<PublishDiagnosticsParams> {
+        self.load_config();
+        self.build_graph();
+        let uris: Vec<Url> = self.documents.keys().cloned().collect();
+        for uri in &uris {
+            self.analyze(uri);
+        }
+        uris.iter().filter_map(|uri| self.publish(uri)).collect()
+    }
+
+    /// A document was opened or its text changed. Returns the diagnostics to
+    /// publish: the document's and those of open documents whose cycles changed.
+    pub fn update(&mut self, uri: Url, text: String) -> Vec<PublishDiagnosticsParams> {
+        let Ok(path) = uri.to_file_path() else {
+            return Vec::new();
+        };
+        let document = match self.documents.remove(&uri) {
+            Some(mut document) => {
+                document.text = text;
+                document
+            }
+            None => self.new_document(path, text),
+        };
+        let path = document.path.clone();
+        if document.ignored {
+            self.graph.clear_imports(&path);
+        } else {
+            self.graph.update_file_content(&path, &document.text);
+        }
+        self.documents.insert(uri.clone(), document);
+        self.analyze(&uri);
+
+        let mut touched = self.recheck_cycles(&path);
+        touched.insert(uri);
+        touched.iter().filter_map(|uri| self.publish(uri)).collect()
+    }
+
+    /// A document was closed: the graph goes back to the file on disk and
+    /// the document's diagnostics are cleared
+    pub fn close(&mut self, uri: &Url) -> Vec<PublishDiagnosticsParams> {
+        let Some(document) = self.documents.remove(uri) else {
+            return Vec::new();
+        };
+        if document.ignored {
+            self.graph.clear_imports(&document.path);
+        } else if self.graph.update_file(&document.path).is_err() {
+            // Deleted before closing: keep the graph free of the file
+            self.graph
+                .invalidate_node(&self.graph.node_key(&document.path));
+        }
+        let mut touched = self.recheck_cycles(&document.path);
+        touched.remove(uri);
+        let mut published: Vec<PublishDiagnosticsParams> =
+            touched.iter().filter_map(|uri| self.publish(uri)).collect();
+        published.push(PublishDiagnosticsParams::new(uri.clone(), Vec::new(), None));
+        published
+    }
+
+    /// Diagnostics of an open document
+    pub fn diagnostics(&self, uri: &Url) -> Vec<Diagnostic> {
+        let Some(document) = self.documents.get(uri) else {
+            return Vec::new();
+        };
+        if document.ignored {
+            return Vec::new();
+        }
+        let mut diagnostics: Vec<Diagnostic> = document
+            .findings
+            .iter()
+            .map(|finding| finding_diagnostic(document, finding))
+            .collect();
+        for (cycle, line) in self.cycles_in(document) {
+            diagnostics.push(Diagnostic {
+                range: line_range(&document.text, line),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String("circular-dependency".to_string())),
+                source: Some(DIAGNOSTIC_SOURCE.to_string()),
+                message: format!("Dependencia cíclica: {}", cycle.cycle.join(" → ")),
+                ..Default::default()
+            });
+        }
+        diagnostics
+    }
+
+    /// Hover text for the violations on the line under `position`
+    pub fn hover(&self, uri: &Url, position: Position) -> Option<Hover> {
+        let document = self.documents.get(uri).filter(|d| !d.ignored)?;
+        let line = position.line as usize;
+        let mut sections: Vec<String> = document
+            .findings
+            .iter()
+            .filter(|f| f.violation.line_number == line + 1)
+            .map(|f| hover_text(&f.violation))
+            .collect();
+        sections.extend(
+            self.cycles_in(document)
+                .into_iter()
+                .filter(|(_, cycle_line)| *cycle_line == line)
+                .map(|(cycle, _)| {
+                    format!(
+                        "**Dependencia cíclica**\n\n{}",
+                        cycle
+                            .cycle
+                            .iter()
+                            .map(|node| format!("`{}`", node))
+                            .collect::<Vec<_>>()
+                            .join(" → ")
+                    )
+                }),
+        );
+        if sections.is_empty() {
+            return None;
+        }
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: sections.join("\n\n---\n\n"),
+            }),
+            range: Some(line_range(&document.text, line)),
+        })
+    }
+
+    /// Quick fixes for the violations inside `range`: an inline suppression
+    /// and, for import rules, removing the offending import
+    pub fn code_actions(&self, uri: &Url, range: Range) -> Vec<CodeActionOrCommand> {
+        let Some(document) = self.documents.get(uri).filter(|d| !d.ignored) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = document.text.lines().collect();
+        let mut actions = Vec::new();
+        let mut suppressed = HashSet::new();
+        for finding in &document.findings {
+            let line = finding.violation.line_number.saturating_sub(1);
+            if line < range.start.line as usize || line > range.end.line as usize {
+                continue;
+            }
+            let diagnostic = finding_diagnostic(document, finding);
+            let line_text = lines.get(line).copied().unwrap_or("");
+
+            if suppressed.insert(line) {
+                let indent: String = line_text
+                    .chars()
+                    .take_while(|c| c.is_whitespace())
+                    .collect();
+                let start = Position::new(line as u32, 0);
+                actions.push(quick_fix(
+                    "Ignorar esta línea (architect-ignore-next-line)".to_string(),
+                    uri,
+                    TextEdit::new(
+                        Range::new(start, start),
+                        suppression::next_line_comment(&document.path, &indent),
+                    ),
+                    diagnostic.clone(),
+                ));
+            }
+            let removal = (!finding.security)
+                .then(|| {
+                    remove_statement(
+                        &document.path,
+                        &document.text,
+                        line,
+                        &finding.violation.offensive_import,
+                    )
+                })
+                .flatten();
+            if let Some(edit) = removal {
+                actions.push(quick_fix(
+                    format!("Eliminar el import '{}'", import_label(&finding.violation)),
+                    uri,
+                    edit,
+                    diagnostic,
+                ));
+            }
+        }
+        actions
+    }
+
+    fn load_config(&mut self) {
+        self.config_error = None;
+        self.ctx = match config::load_config(&self.root) {
+            Ok(ctx) => Some(ctx),
+            Err(err) => {
+                self.config_error = Some(err.to_string());
+                None
+            }
+        };
+    }
+
+    fn build_graph(&mut self) {
+        let ignored = self
+            .ctx
+            .as_ref()
+            .map(|ctx| ctx.ignored_paths.clone())
+            .unwrap_or_else(config::default_ignored_paths);
+        let mut files = discovery::collect_files(&self.root, &ignored);
+        // Generated code the analysis skips stays out of the cycles too
+        if let Some(ctx) = self
+            .ctx
+            .as_ref()
+            .filter(|ctx| ctx.generated_code.mode == GeneratedCodeMode::Skip)
+        {
+            let detector = GeneratedCodeDetector::new(&ctx.generated_code);
+            files.retain(|file| {
+                let rel = AnalysisCache::normalize_path(file, &self.root);
+                let content = std::fs::read(file).unwrap_or_default();
+                detector.detect(&rel, &content).is_none()
+            });
+        }
+        self.graph = CircularDependencyAnalyzer::new(&self.root);
+        if let Err(err) = self.graph.build_graph(&files) {
+            tracing::warn!("Could not build the import graph: {}", err);
+        }
+        // Open buffers win over the files on disk
+        for document in self.documents.values() {
+            if document.ignored {
+                self.graph.clear_imports(&document.path);
+            } else {
+                self.graph
+                    .update_file_content(&document.path, &document.text);
+            }
+        }
+        self.cycles = self.graph.detect_cycles();
+    }
+
+    fn new_document(&self, path: PathBuf, text: String) -> Document {
+        let rel = path
+            .strip_prefix(&self.root)
+            .map(|rel| rel.to_string_lossy().replace('\\', "/"));
+        let (ignored, downweighted) = match (&self.ctx, rel) {
+            (Some(ctx), Ok(rel)) => {
+                let ignored = matches!(
+                    discovery::explain_ignore(&self.root, &ctx.ignored_paths, &rel, false),
+                    IgnoreExplanation::IgnoredPaths { .. } | IgnoreExplanation::IgnoreFile(_)
+                );
+                let generated = ctx.generated_code.mode != GeneratedCodeMode::Analyze
+                    && GeneratedCodeDetector::new(&ctx.generated_code)
+                        .detect(&rel, text.as_bytes())
+                        .is_some();
+                match ctx.generated_code.mode {
+                    GeneratedCodeMode::Skip => (ignored || generated, false),
+                    _ => (ignored, generated),
+                }
+            }
+            // Files outside the project are not analyzed
+            (_, Err(_)) => (true, false),
+            (None, Ok(_)) => (false, false),
+        };
+        Document {
+            path,
+            text,
+            findings: Vec::new(),
+            ignored,
+            downweighted,
+        }
+    }
+
+    /// Re-run the rules on the document's text
+    fn analyze(&mut self, uri: &Url) {
+        let (Some(ctx), Some(document)) = (&self.ctx, self.documents.get_mut(uri)) else {
+            return;
+        };
+        if document.ignored {
+            document.findings.clear();
+            return;
+        }
+        document.findings = match collect_findings_from_source(&document.path, &document.text, ctx)
+        {
+            Ok((violations, security)) => violations
+                .into_iter()
+                .map(|violation| Finding {
+                    violation,
+                    security: false,
+                })
+                .chain(security.into_iter().map(|finding| Finding {
+                    violation: finding.violation,
+                    security: true,
+                }))
+                .collect(),
+            Err(err) => {
+                tracing::debug!("Could not analyze {}: {}", document.path.display(), err);
+                Vec::new()
+            }
+        };
+    }
+
+    /// Re-detect cycles in the part of the graph connected to `path`.
+    /// Returns the open documents whose cycles may have changed.
+    fn recheck_cycles(&mut self, path: &Path) -> HashSet<Url> {
+        let mut affected = self.graph.get_affected_nodes(&self.graph.node_key(path));
+        let (stale, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut self.cycles)
+            .into_iter()
+            .partition(|cycle| cycle.cycle.iter().any(|node| affected.contains(node)));
+        self.cycles = kept;
+        self.cycles
+            .extend(self.graph.detect_cycles_in_subgraph(&affected));
+        affected.extend(stale.into_iter().flat_map(|cycle| cycle.cycle));
+
+        self.documents
+            .iter()
+            .filter(|(_, document)| affected.contains(&self.graph.node_key(&document.path)))
+            .map(|(uri, _)| uri.clone())
+            .collect()
+    }
+
+    /// Cycles through the document, with the line of its import that continues each one
+    fn cycles_in(&self, document: &Document) -> Vec<(&CircularDependency, usize)> {
+        let node = self.graph.node_key(&document.path);
+        self.cycles
+            .iter()
+            .filter_map(|cycle| {
+                let position = cycle.cycle.iter().position(|n| *n == node)?;
+                let line = cycle
+                    .cycle
+                    .get(position + 1)
+                    .and_then(|next| {
+                        self.graph
+                            .import_line_to(&document.path, &document.text, next)
+                    })
+                    .unwrap_or(0);
+                Some((cycle, line))
+            })
+            .collect()
+    }
+
+    fn publish(&self, uri: &Url) -> Option<PublishDiagnosticsParams> {
+        self.documents.get(uri)?;
+        Some(PublishDiagnosticsParams::new(
+            uri.clone(),
+            self.diagnostics(uri),
+            None,
+        ))
+    }
+}
+
+fn finding_diagnostic(document: &Document, finding: &Finding) -> Diagnostic {
+    let violation = &finding.violation;
+    let line = violation.line_number.saturating_sub(1);
+    let severity = if document.downweighted {
+        DiagnosticSeverity::INFORMATION
+    } else {
+        match violation.rule.get_severity() {
+            Severity::Error => DiagnosticSeverity::ERROR,
+            Severity::Warning => DiagnosticSeverity::WARNING,
+            Severity::Info => DiagnosticSeverity::INFORMATION,
+        }
+    };
+    let mut message = violation_message(violation);
+    if let Some(reason) = &violation.rule.reason {
+        message.push_str(&format!(" {}", reason));
+    }
+    Diagnostic {
+        range: import_range(&document.text, line, &violation.offensive_import),
+        severity: Some(severity),
+        code: Some(NumberOrString::String(
+            if finding.security {
+                "security"
+            } else {
+                "architecture"
+            }
+            .to_string(),
+        )),
+        source: Some(DIAGNOSTIC_SOURCE.to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+fn hover_text(violation: &Violation) -> String {
+    let rule = &violation.rule;
+    let mut text = format!(
+        "**Regla de arquitectura** · severidad `{}`\n\n`{}` no puede importar de `{}`",
+        match rule.get_severity() {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        },
+        rule.from,
+        rule.to
+    );
+    match &rule.reason {
+        Some(reason) => text.push_str(&format!("\n\n**Motivo:** {}", reason)),
+        None => text.push_str("\n\n_La regla no documenta un motivo (`reason`)._"),
+    }
+    text
+}
+
+/// The imported module, for action titles
+fn import_label(violation: &Violation) -> String {
+    let import = violation.offensive_import.trim();
+    import
+        .split(['\'', '"'])
+        .nth(1)
+        .unwrap_or(import)
+        .to_string()
+}
+
+fn quick_fix(
+    title: String,
+    uri: &Url,
+    edit: TextEdit,
+    diagnostic: Diagnostic,
+) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), vec![edit])])),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+/// Range of `needle` starting on the 0-based `line`, or of the whole line
+/// without indentation. Columns are UTF-16 code units, as LSP expects.
+fn import_range(text: &str, line: usize, needle: &str) -> Range {
+    match needle_span(text, line, needle) {
+        Some((start, end)) => Range::new(position_at(text, start), position_at(text, end)),
+        None => line_range(text, line),
+    }
+}
+
+/// Edit that deletes the import statement around `needle` on the 0-based
+/// `line`: its whole lines when no other code shares them, otherwise just
+/// the statement. None when the statement is not found, since deleting part
+/// of a multi-line import would leave code that does not parse.
+fn remove_statement(path: &Path, text: &str, line: usize, needle: &str) -> Option<TextEdit> {
+    let (start, end) = statement_span(path, text, line, needle)?;
+    let first_line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let last_line_end = text[end..].find('\n').map_or(text.len(), |i| end + i);
+    let own_lines = text[first_line_start..start].trim().is_empty()
+        && matches!(text[end..last_line_end].trim(), "" | ";");
+    let range = if own_lines {
+        Range::new(
+            Position::new(position_at(text, start).line, 0),
+            Position::new(position_at(text, end).line + 1, 0),
+        )
+    } else {
+        Range::new(position_at(text, start), position_at(text, end))
+    };
+    Some(TextEdit::new(range, String::new()))
+}
+
+/// Byte span of the import statement that contains `needle` on the 0-based
+/// `line`. Parsers may report just the specifier (`'../infra/db'`) of a
+/// statement that spans several lines, so it is looked up in the syntax tree.
+fn statement_span(path: &Path, text: &str, line: usize, needle: &str) -> Option<(usize, usize)> {
+    let (start, end) = needle_span(text, line, needle)?;
+    let extension = path.extension()?.to_str()?;
+    let (grammar, kinds): (tree_sitter::Language, &[&str]) =
+        match Language::from_extension(extension)? {
+            Language::TypeScript | Language::JavaScript => (
+                tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+                &["import_statement"],
+            ),
+            Language::Python => (
+                tree_sitter_python::LANGUAGE.into(),
+                &["import_statement", "import_from_statement"],
+            ),
+            Language::Php => (
+                tree_sitter_php::LANGUAGE_PHP.into(),
+                &["namespace_use_declaration", "expression_statement"],
+            ),
+        };
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&grammar).ok()?;
+    let tree = parser.parse(text, None)?;
+    let mut node = tree.root_node().descendant_for_byte_range(start, end)?;
+    while !kinds.contains(&node.kind()) {
+        node = node.parent()?;
+    }
+    Some((node.start_byte(), node.end_byte()))
+}
+
+/// Byte span of `needle` when it starts on the 0-based `line`
+fn needle_span(text: &str, line: usize, needle: &str) -> Option<(usize, usize)> {
+    let needle = needle.trim();
+    if needle.is_empty() {
+        return None;
+    }
+    let line_start = line_offset(text, line)?;
+    let line_end = text[line_start..]
+        .find('\n')
+        .map_or(text.len(), |i| line_start + i);
+    let start = line_start + text[line_start..].find(needle)?;
+    (start < line_end).then_some((start, start + needle.len()))
+}
+
+/// Byte offset where the 0-based `line` starts
+fn line_offset(text: &str, line: usize) -> Option<usize> {
+    match line {
+        0 => Some(0),
+        _ => text.match_indices('\n').nth(line - 1).map(|(i, _)| i + 1),
+    }
+}
+
+fn position_at(text: &str, offset: usize) -> Position {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position::new(
+        before.matches('\n').count() as u32,
+        utf16_len(&before[line_start..]),
+    )
+}
+
+fn line_range(text: &str, line: usize) -> Range {
+    let line_text = text.lines().nth(line).unwrap_or("");
+    let indent = line_text.len() - line_text.trim_start().len();
+    Range::new(
+        Position::new(line as u32, utf16_len(&line_text[..indent])),
+        Position::new(line as u32, utf16_len(line_text.trim_end())),
+    )
+}
+
+fn utf16_len(text: &str) -> u32 {
+    text.encode_utf16().count() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn project() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("architect.json"),
+            r#"{ "max_lines_per_function": 40, "architecture_pattern": "Hexagonal", "forbidden_imports": [{ "from": "src/domain/", "to": "src/infra/", "reason": "El dominio no depende de la infraestructura" }] }"#,
+        )
+        .unwrap();
+        fs::create_dir_all(dir.path().join("src/domain")).unwrap();
+        fs::create_dir_all(dir.path().join("src/infra")).unwrap();
+        fs::write(dir.path().join("src/infra/db.ts"), "export const db = 1;\n").unwrap();
+        fs::write(
+            dir.path().join("src/domain/user.ts"),
+            "export const user = 1;\n",
+        )
+        .unwrap();
+        dir
+    }
+
+    fn uri(dir: &TempDir, rel: &str) -> Url {
+        Url::from_file_path(dir.path().canonicalize().unwrap().join(rel)).unwrap()
+    }
+
+    #[test]
+    fn test_unsaved_buffer_is_analyzed() {
+        let dir = project();
+        let mut session = Session::new(dir.path());
+        let user = uri(&dir, "src/domain/user.ts");
+
+        let text = "import { db } from '../infra/db';\nexport const user = db;\n";
+        let published = session.update(user.clone(), text.to_string());
+        let diagnostics = &published
+            .iter()
+            .find(|p| p.uri == user)
+            .unwrap()
+            .diagnostics;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start.line, 0);
+        assert!(diagnostics[0].message.contains("infraestructura"));
+
+        let hover = session.hover(&user, Position::new(0, 3)).unwrap();
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("expected markdown");
+        };
+        assert!(markup.value.contains("**Motivo:** El dominio"));
+
+        let actions =
+            session.code_actions(&user, Range::new(Position::new(0, 0), Position::new(0, 0)));
+        assert_eq!(actions.len(), 2);
+
+        // Suppressing the line silences it
+        let text = format!("// {}\n{}", suppression::IGNORE_NEXT_LINE, text);
+        let published = session.update(user.clone(), text);
+        assert!(published[0].diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_remove_import_covers_multiline_statement() {
+        let dir = project();
+        let mut session = Session::new(dir.path());
+        let user = uri(&dir, "src/domain/user.ts");
+
+        let text = "import {\n  db,\n} from '../infra/db';\nexport const user = db;\n";
+        let published = session.update(user.clone(), text.to_string());
+        let diagnostic = &published[0].diagnostics[0];
+        assert_eq!(
+            diagnostic.range,
+            Range::new(Position::new(2, 7), Position::new(2, 20))
+        );
+
+        let actions =
+            session.code_actions(&user, Range::new(Position::new(2, 0), Position::new(2, 0)));
+        let removal = actions
+            .iter()
+            .find_map(|action| match action {
+                CodeActionOrCommand::CodeAction(action)
+                    if action.title.starts_with("Eliminar el import") =>
+                {
+                    action
+                        .edit
+                        .as_ref()?
+                        .changes
+                        .as_ref()?
+                        .get(&user)?
+                        .first()
+                        .cloned()
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(
+            removal.range,
+            Range::new(Position::new(0, 0), Position::new(3, 0))
+        );
+    }
+
+    #[test]
+    fn test_cycles_follow_buffer_edits() {
+        let dir = project();
+        let mut session = Session::new(dir.path());
+        let db = uri(&dir, "src/infra/db.ts");
+        let user = uri(&dir, "src/domain/user.ts");
+
+        session.update(
+            db.clone(),
+            "import { user } from '../domain/user';\nexport const db = user;\n".to_string(),
+        );
+        let published = session.update(
+            user.clone(),
+            "import { db } from '../infra/db';\nexport const user = db;\n".to_string(),
+        );
+        let cycle_diagnostics = |published: &[PublishDiagnosticsParams], uri: &Url| {
+            published
+                .iter()
+                .find(|p| &p.uri == uri)
+                .map(|p| {
+                    p.diagnostics
+                        .iter()
+                        .filter(|d| d.message.starts_with("Dependencia cíclica"))
+                        .count()
+                })
+                .unwrap_or(0)
+        };
+        assert_eq!(cycle_diagnostics(&published, &user), 1);
+        assert_eq!(cycle_diagnostics(&published, &db), 1);
+
+        let published = session.update(user.clone(), "export const user = 1;\n".to_string());
+        assert_eq!(cycle_diagnostics(&published, &user), 0);
+        assert_eq!(cycle_diagnostics(&published, &db), 0);
+    }
+}
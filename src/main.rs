@@ -1,5 +1,6 @@
 use indicatif::{ProgressBar, ProgressStyle};
 use miette::{GraphicalReportHandler, IntoDiagnostic, Result};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
@@ -7,38 +8,52 @@ mod ai;
 mod analysis_result;
 mod analyzer;
 mod autofix;
+mod blame;
 mod cache;
 mod circular;
 mod cli;
+mod codeowners;
 mod config;
+mod daemon;
+mod dependencies;
 mod detector;
+mod diff;
 mod discovery;
 mod git;
 mod git_changes;
+mod gitpattern;
+mod history;
+mod ignore_rules;
 mod logging;
+mod lsp;
 mod memory_cache;
 mod metrics;
 mod notification;
 mod output;
+mod packages;
 mod parsers;
 mod report;
 mod scoring;
 mod security;
 mod source_span;
+mod suppression;
 mod init;
 mod ui;
 mod watch;
+mod workspace;
 
 #[cfg(unix)]
 fn run_as_daemon(project_root: &std::path::Path) -> Result<()> {
     use daemonize::Daemonize;
     use std::fs::File;
 
-    let stdout = File::create("/tmp/architect-linter.out").into_diagnostic()?;
-    let stderr = File::create("/tmp/architect-linter.err").into_diagnostic()?;
+    let dir = project_root.join(history::HISTORY_DIR);
+    std::fs::create_dir_all(&dir).into_diagnostic()?;
+    let stdout = File::create(dir.join(daemon::LOG_FILE)).into_diagnostic()?;
+    let stderr = stdout.try_clone().into_diagnostic()?;
 
     let daemonize = Daemonize::new()
-        .pid_file("/tmp/architect-linter.pid")
+        .pid_file(dir.join(daemon::PID_FILE))
         .umask(0o027)
         .working_directory(project_root)
         .stdout(stdout)
@@ -121,6 +136,26 @@ fn main() -> Result<()> {
         None => return Ok(()), // Se procesó --help o --version
     };
 
+    // stdout carries the protocol: no banner, logs go to stderr
+    if cli_args.lsp_mode {
+        logging::init_stderr(cli_args.debug_mode);
+        let root = match cli_args.project_path {
+            Some(ref p) => PathBuf::from(p).canonicalize().into_diagnostic()?,
+            None => std::env::current_dir().into_diagnostic()?,
+        };
+        return lsp::run(&root);
+    }
+
+    // Client of the daemon: stdout carries the JSON result
+    if let Some(ref method) = cli_args.rpc_method {
+        logging::init_stderr(cli_args.debug_mode);
+        let root = match cli_args.project_path {
+            Some(ref p) => PathBuf::from(p).canonicalize().into_diagnostic()?,
+            None => std::env::current_dir().into_diagnostic()?,
+        };
+        return run_rpc(&root, method, &cli_args);
+    }
+
     // 2. Initialize structured logging
     logging::init(cli_args.debug_mode);
 
@@ -167,7 +202,47 @@ fn main() -> Result<()> {
         } else {
             std::env::current_dir().into_diagnostic()?
         };
-        return init::run_init(&root, cli_args.init_force);
+        return init::run_init(&root, cli_args.force);
+    }
+
+    if cli_args.trend_mode {
+        let root = match cli_args.project_path {
+            Some(ref p) => PathBuf::from(p),
+            None => std::env::current_dir().into_diagnostic()?,
+        };
+        return run_trend_mode(&root, &cli_args);
+    }
+
+    if cli_args.backfill_mode {
+        let root = match cli_args.project_path {
+            Some(ref p) => PathBuf::from(p).canonicalize().into_diagnostic()?,
+            None => std::env::current_dir().into_diagnostic()?,
+        };
+        return run_backfill_mode(&root, &cli_args);
+    }
+
+    if cli_args.deps_mode {
+        let root = match cli_args.project_path {
+            Some(ref p) => PathBuf::from(p).canonicalize().into_diagnostic()?,
+            None => std::env::current_dir().into_diagnostic()?,
+        };
+        return run_deps_mode(&root, &cli_args);
+    }
+
+    if cli_args.migrate_mode {
+        let root = match cli_args.project_path {
+            Some(ref p) => PathBuf::from(p).canonicalize().into_diagnostic()?,
+            None => std::env::current_dir().into_diagnostic()?,
+        };
+        return run_migrate_mode(&root, cli_args.migrate_dry_run, cli_args.force);
+    }
+
+    if let Some(ref target) = cli_args.explain_ignore {
+        let root = match cli_args.project_path {
+            Some(ref p) => PathBuf::from(p).canonicalize().into_diagnostic()?,
+            None => std::env::current_dir().into_diagnostic()?,
+        };
+        return run_explain_ignore(&root, target);
     }
 
     // 4. Obtener la ruta del proyecto
@@ -179,25 +254,33 @@ fn main() -> Result<()> {
     };
     tracing::info!("📂 Project root: {}", project_root.display());
 
+    // --check valida la configuración sin lanzar el wizard ni analizar
+    if cli_args.check_mode {
+        return run_check_mode(&project_root);
+    }
+
     // 5. Cargar o crear configuración asistida por IA
     tracing::debug!("Loading configuration...");
     let ctx = Arc::new(config::setup_or_load_config(&project_root)?);
     tracing::info!("✅ Configuration loaded: {:?} pattern", ctx.pattern);
 
-    if cli_args.check_mode {
-        println!("✅ Configuración válida.");
-        return Ok(());
-    }
-
     let no_cache = cli_args.no_cache;
 
     // 6. Check for daemon mode
     if cli_args.daemon_mode {
         #[cfg(unix)]
         {
-            println!("🚀 Entrando en modo daemon. Revisa /tmp/architect-linter.err para errores.");
+            let dir = project_root.join(history::HISTORY_DIR);
+            println!("🚀 Entrando en modo daemon.");
+            println!(
+                "   Socket: {}",
+                daemon::socket_path(&project_root).display()
+            );
+            println!("   Log:    {}", dir.join(daemon::LOG_FILE).display());
+            println!("   Consulta: architect-linter-pro rpc score .");
             tracing::info!("Starting daemon mode");
             run_as_daemon(&project_root)?;
+            return daemon::serve(&project_root);
         }
         #[cfg(windows)]
         {
@@ -215,6 +298,9 @@ fn main() -> Result<()> {
     } else if cli_args.watch_mode {
         tracing::info!("👁️  Running in WATCH mode");
         run_watch_mode(&project_root, Arc::clone(&ctx), no_cache, cli_args.min_severity)?;
+    } else if let Some(ref base_ref) = cli_args.diff_base {
+        tracing::info!("🔀 Running in DIFF mode against {}", base_ref);
+        run_diff_mode(&project_root, &ctx, base_ref, &cli_args)?;
     } else if cli_args.incremental_mode {
         tracing::info!("⚡ Running in INCREMENTAL mode");
         run_incremental_mode(&project_root, Arc::clone(&ctx), &cli_args)?;
@@ -227,12 +313,46 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Envía una petición al daemon e imprime el resultado JSON
+fn run_rpc(project_root: &Path, method: &str, cli_args: &cli::CliArgs) -> Result<()> {
+    let params = match method {
+        "analyze" => serde_json::json!({ "full": cli_args.rpc_full }),
+        "violations" => match cli_args.rpc_file {
+            Some(ref file) => serde_json::json!({ "file": file }),
+            None => return Err(miette::miette!("rpc violations requiere --file <FILE>")),
+        },
+        _ => serde_json::Value::Null,
+    };
+    let result = daemon::request(project_root, method, params)?;
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&result).into_diagnostic()?
+    );
+
+    // Como en el análisis normal, las violaciones bloqueantes hacen fallar hooks y scripts
+    let blocked = result["violations"]
+        .as_array()
+        .is_some_and(|v| v.iter().any(|cv| cv["category"] == "blocked"))
+        || result["blocked_violations"].as_u64().is_some_and(|n| n > 0);
+    if blocked {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 /// Ejecuta el análisis en modo normal (una sola vez)
 fn run_normal_mode(
     project_root: &Path,
     ctx: Arc<config::LinterContext>,
     cli_args: &cli::CliArgs,
 ) -> Result<()> {
+    // Monorepo: cada paquete se analiza con su propia configuración
+    if !cli_args.staged_mode {
+        if let Some(ws) = workspace::Workspace::discover(project_root)? {
+            return run_workspace_mode(project_root, &ctx, ws, cli_args);
+        }
+    }
+
     // Recolectar archivos de todos los lenguajes soportados
     let mut files = discovery::collect_files(project_root, &ctx.ignored_paths);
 
@@ -303,8 +423,9 @@ fn run_normal_mode(
         }
     }
 
-    // Análisis de Dependencias Cíclicas
+    // Análisis de Dependencias Cíclicas (sin el código generado omitido)
     pb.set_message("Checking circular deps...");
+    let files = analyzer::collector::without_skipped(&files, &analysis_result, &ctx);
     let cycles = circular::analyze_circular_dependencies(&files, project_root);
 
     match cycles {
@@ -320,13 +441,38 @@ fn run_normal_mode(
 
     pb.finish_and_clear();
 
+    print_generated_files(&analysis_result, ctx.generated_code.mode);
+
+    // Reglas que nunca se aplican (con --staged faltan archivos para saberlo)
+    if !cli_args.staged_mode {
+        print_dead_rules(&analyzer::dead_rules::find_dead_rules(
+            &ctx.forbidden_imports,
+            &files,
+            Some(&analysis_result.import_sources),
+        ));
+    }
+
+    if cli_args.blame {
+        blame::annotate(&mut analysis_result, project_root);
+    }
+
     // Calculate health score
     let health_score = scoring::calculate(&analysis_result);
     analysis_result.health_score = Some(health_score.clone());
 
+    // Persist score history (only full runs are comparable)
+    if ctx.history.enabled && !cli_args.staged_mode {
+        record_history(
+            project_root,
+            &analysis_result,
+            ctx.history.regression_threshold,
+        );
+    }
+
     // Handle report export if requested
     if let Some(format) = cli_args.report_format {
-        let report_content = report::generate_report(&analysis_result, format);
+        let report_content =
+            report::generate_project_report(&analysis_result, format, project_root);
 
         if let Some(output_path) = &cli_args.output_path {
             let path = std::path::Path::new(output_path);
@@ -349,6 +495,14 @@ fn run_normal_mode(
     // Print summary
     output::dashboard::print_summary(&analysis_result);
 
+    if cli_args.group_by_team {
+        output::dashboard::print_team_breakdown(&analysis_result);
+    }
+
+    if cli_args.blame {
+        print_attribution(&analysis_result);
+    }
+
     // Print circular dependency details if any
     if !analysis_result.circular_dependencies.is_empty() {
         println!();
@@ -363,43 +517,711 @@ fn run_normal_mode(
     }
 }
 
-/// Run a full analysis and return the AnalysisResult (reused by watch commands)
-fn run_full_analysis(
+/// Analiza un monorepo paquete a paquete y muestra un reporte agregado
+fn run_workspace_mode(
     project_root: &Path,
     ctx: &config::LinterContext,
-    analysis_cache: Option<&mut cache::AnalysisCache>,
-    min_severity: config::Severity,
-) -> Result<analysis_result::AnalysisResult> {
-    let files = discovery::collect_files(project_root, &ctx.ignored_paths);
+    ws: workspace::Workspace,
+    cli_args: &cli::CliArgs,
+) -> Result<()> {
+    println!(
+        "📦 Monorepo detectado: {} paquetes ({})",
+        ws.packages.len(),
+        ws.packages
+            .iter()
+            .map(|p| p.path.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
 
-    let mut analysis_result = analyzer::analyze_all_files(
-        &files,
-        project_root,
-        ctx.pattern.clone(),
-        ctx,
-        analysis_cache,
-    )?;
+    let mut ws_result =
+        workspace::analyze_workspace(Arc::new(ws), cli_args.min_severity, !cli_args.no_cache)?;
 
-    // Apply minimum severity filter from CLI
-    analysis_result.filter_by_severity(min_severity);
+    for package in &ws_result.packages {
+        if !package.result.generated_files.is_empty() {
+            print!("[{}] ", package.name);
+            print_generated_files(&package.result, package.generated_mode);
+        }
+        if !package.dead_rules.is_empty() {
+            eprint!("[{}] ", package.name);
+            print_dead_rules(&package.dead_rules);
+        }
+    }
 
-    // Circular dependencies
-    match circular::analyze_circular_dependencies(&files, project_root) {
-        Ok(detected_cycles) => {
-            for cycle in &detected_cycles {
-                analysis_result.add_circular_dependency(cycle.clone());
+    if cli_args.blame {
+        blame::annotate(&mut ws_result.combined, project_root);
+    }
+
+    if ctx.history.enabled {
+        record_history(
+            project_root,
+            &ws_result.combined,
+            ctx.history.regression_threshold,
+        );
+    }
+
+    if let Some(format) = cli_args.report_format {
+        let report_content = match format {
+            cli::ReportFormat::Json => ws_result.to_json(),
+            cli::ReportFormat::Markdown => ws_result.to_markdown(),
+            _ => report::generate_project_report(&ws_result.combined, format, project_root),
+        };
+
+        if let Some(output_path) = &cli_args.output_path {
+            report::write_report(&report_content, Path::new(output_path))?;
+            println!("📄 Report saved to: {}", output_path);
+        } else {
+            report::write_stdout(&report_content)?;
+        }
+
+        if ws_result.has_critical_issues() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    output::print_dashboard(&ws_result.combined);
+    output::dashboard::print_summary(&ws_result.combined);
+    print_workspace_packages(&ws_result);
+
+    if cli_args.group_by_team {
+        output::dashboard::print_team_breakdown(&ws_result.combined);
+    }
+
+    if cli_args.blame {
+        print_attribution(&ws_result.combined);
+    }
+
+    if !ws_result.combined.circular_dependencies.is_empty() {
+        println!();
+        circular::print_circular_dependency_report(&ws_result.combined.circular_dependencies);
+    }
+
+    if ws_result.has_critical_issues() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Tabla de puntuación por paquete del monorepo
+fn print_workspace_packages(result: &workspace::WorkspaceResult) {
+    println!("\n📦 Puntuación por paquete:");
+    for package in &result.packages {
+        let (score, grade) = package
+            .result
+            .health_score
+            .as_ref()
+            .map_or((0, "-"), |s| (s.total, s.grade.as_str()));
+        println!(
+            "   {:<32} {:<28} {:>3}/100 ({})  {:>4} violaciones  {:>5} archivos{}",
+            package.name,
+            package.path,
+            score,
+            grade,
+            package.result.violations.len(),
+            package.result.files_analyzed,
+            if package.has_config { "  ⚙️" } else { "" }
+        );
+    }
+}
+
+/// Muestra las violaciones agrupadas por autor y por equipo (CODEOWNERS)
+fn print_attribution(result: &analysis_result::AnalysisResult) {
+    if result.violations.is_empty() {
+        return;
+    }
+    let attribution = blame::attribution(result);
+
+    println!("\n👥 Violaciones por equipo:");
+    for (team, count) in attribution.top_teams() {
+        println!("   {:<40} {:>4}", team, count);
+    }
+    println!("\n✍️  Violaciones por autor:");
+    for (author, count) in attribution.top_authors().into_iter().take(10) {
+        println!("   {:<40} {:>4}", author, count);
+    }
+}
+
+/// Guarda el resultado en .architect/history.jsonl y avisa si hubo regresión
+fn record_history(project_root: &Path, result: &analysis_result::AnalysisResult, threshold: u8) {
+    let commit = git::head_commit_sha(project_root);
+    let Some(entry) = history::HistoryEntry::from_result(result, commit) else {
+        return;
+    };
+
+    let previous = history::load(project_root).ok().and_then(|entries| {
+        entries
+            .into_iter()
+            .rev()
+            .find(|e| e.commit.is_none() || e.commit != entry.commit)
+    });
+
+    if let Some(previous) = previous {
+        let drops = history::detect_regression(&previous, &entry, threshold);
+        for (metric, before, after) in drops {
+            eprintln!(
+                "📉 Regresión en {}: {} → {} (umbral {} puntos, desde {})",
+                metric,
+                before,
+                after,
+                threshold,
+                previous.short_commit()
+            );
+        }
+    }
+
+    if let Err(e) = history::record(project_root, entry) {
+        eprintln!("⚠️  No se pudo guardar el historial de puntuación: {}", e);
+    }
+}
+
+/// Muestra la evolución del Health Score guardada en .architect/history.jsonl
+fn run_trend_mode(project_root: &Path, cli_args: &cli::CliArgs) -> Result<()> {
+    let configured = config::load_config(project_root)
+        .map(|ctx| ctx.history.regression_threshold)
+        .unwrap_or(config::HistoryConfig::default().regression_threshold);
+    let threshold = cli_args.trend_threshold.unwrap_or(configured);
+
+    let mut entries = history::load(project_root)?;
+    if entries.is_empty() {
+        println!(
+            "ℹ️  No hay historial todavía en {}. Activa \"history\": {{ \"enabled\": true }} en architect.json y ejecuta un análisis completo (o usa `backfill`).",
+            history::history_path(project_root).display()
+        );
+        return Ok(());
+    }
+    if let Some(limit) = cli_args.trend_limit {
+        let skip = entries.len().saturating_sub(limit);
+        entries.drain(..skip);
+    }
+
+    println!("📈 Health Score ({} entradas)\n", entries.len());
+    print!("{}", history::render_ascii_chart(&entries, 10, threshold));
+    println!();
+    println!(
+        "{:<17} {:<8} {:>5} {:>5} {:>6} {:>6} {:>6} {:>6} {:>6}",
+        "Fecha", "Commit", "Score", "Grade", "Layer", "Cycles", "Cmplx", "Viol.", "Total"
+    );
+    for entry in &entries {
+        println!(
+            "{:<17} {:<8} {:>5} {:>5} {:>6} {:>6} {:>6} {:>6} {:>6}",
+            entry.timestamp.format("%Y-%m-%d %H:%M"),
+            entry.short_commit(),
+            entry.total,
+            entry.grade,
+            entry.components.layer_isolation,
+            entry.components.circular_deps,
+            entry.components.complexity,
+            entry.components.violations,
+            entry.total_violations
+        );
+    }
+
+    let regressions = history::find_regressions(&entries, threshold);
+    if regressions.is_empty() {
+        println!("\n✅ Sin regresiones mayores a {} puntos.", threshold);
+        return Ok(());
+    }
+
+    println!("\n📉 Regresiones (umbral {} puntos):", threshold);
+    for regression in &regressions {
+        let drops: Vec<String> = regression
+            .drops
+            .iter()
+            .map(|(metric, before, after)| format!("{} {} → {}", metric, before, after))
+            .collect();
+        println!(
+            "   {} {}",
+            entries[regression.index].short_commit(),
+            drops.join(", ")
+        );
+    }
+
+    // La última ejecución empeoró: útil como gate en CI
+    if regressions.last().map(|r| r.index) == Some(entries.len() - 1) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Rellena el historial analizando commits pasados sin tocar el working tree
+fn run_backfill_mode(project_root: &Path, cli_args: &cli::CliArgs) -> Result<()> {
+    if !git::is_git_repo(project_root) {
+        return Err(miette::miette!(
+            "El comando backfill requiere un repositorio git."
+        ));
+    }
+
+    // Configuración actual como respaldo para commits sin architect.json válido
+    let fallback = config::load_config(project_root).unwrap_or_default();
+    let options = history::backfill::BackfillOptions {
+        range: cli_args
+            .backfill_range
+            .clone()
+            .unwrap_or_else(|| "HEAD".to_string()),
+        every: cli_args.backfill_every,
+        tags_only: cli_args.backfill_tags,
+    };
+
+    println!(
+        "⏪ Backfill de historial: rango '{}', cada {} commit(s){}",
+        options.range,
+        options.every,
+        if options.tags_only { ", solo tags" } else { "" }
+    );
+
+    let summary =
+        history::backfill::backfill(project_root, &options, &fallback, |i, total, commit| {
+            println!(
+                "   [{}/{}] {} {} {}",
+                i + 1,
+                total,
+                &commit.sha[..7],
+                commit.time.format("%Y-%m-%d"),
+                commit.summary
+            );
+        })?;
+
+    for (sha, error) in &summary.failed {
+        eprintln!(
+            "⚠️  {} no se pudo analizar: {}",
+            &sha[..7.min(sha.len())],
+            error
+        );
+    }
+    println!(
+        "\n✅ {} entradas añadidas, {} ya existentes, {} fallidas → {}",
+        summary.added,
+        summary.skipped,
+        summary.failed.len(),
+        history::history_path(project_root).display()
+    );
+    Ok(())
+}
+
+/// Advierte de las reglas de forbidden_imports que no coinciden con nada
+fn print_dead_rules(dead: &[analyzer::dead_rules::DeadRule]) {
+    if dead.is_empty() {
+        return;
+    }
+    eprintln!("⚠️  Reglas muertas en forbidden_imports (nunca se aplican):");
+    for rule in dead {
+        let what = match rule.side {
+            analyzer::dead_rules::DeadSide::From => "ningún archivo",
+            analyzer::dead_rules::DeadSide::To => "ningún archivo ni import",
+        };
+        eprintln!(
+            "   - from '{}' → to '{}': {} '{}' no coincide con {}",
+            rule.rule.from,
+            rule.rule.to,
+            rule.side.key(),
+            rule.pattern(),
+            what
+        );
+    }
+    eprintln!("   Ejecuta --check para ver dónde están en la configuración.\n");
+}
+
+/// Resume los archivos generados o vendorizados detectados en el análisis
+fn print_generated_files(
+    result: &analysis_result::AnalysisResult,
+    mode: config::GeneratedCodeMode,
+) {
+    let count = result.generated_files.len();
+    if count == 0 {
+        return;
+    }
+    match mode {
+        config::GeneratedCodeMode::Skip => println!(
+            "🧩 Omitidos {} archivos generados o de terceros (generated_code en la configuración)",
+            count
+        ),
+        _ => println!(
+            "🧩 {} archivos generados o de terceros analizados con peso reducido (sus violaciones cuentan como info)",
+            count
+        ),
+    }
+}
+
+/// Valida la configuración y lista todos sus problemas de una vez
+fn run_check_mode(project_root: &Path) -> Result<()> {
+    let report = config::diagnostics::check_config(project_root)?;
+    if report.problems.is_empty() {
+        println!("✅ Configuración válida.");
+        return Ok(());
+    }
+
+    let has_errors = report.has_errors();
+    let summary = report.summary.clone();
+    for problem in report.problems {
+        eprintln!("{:?}", miette::Report::new(problem));
+    }
+    if has_errors {
+        eprintln!("❌ {}", summary);
+        std::process::exit(1);
+    }
+    println!("⚠️  {}", summary);
+    Ok(())
+}
+
+/// Explica qué regla excluye (o re-incluye) un archivo en la recolección
+fn run_explain_ignore(project_root: &Path, target: &str) -> Result<()> {
+    // La ruta puede ser relativa al directorio actual o al proyecto
+    let cwd = std::env::current_dir().into_diagnostic()?;
+    let candidate = cwd.join(target);
+    let path = if candidate.exists() {
+        candidate
+    } else {
+        project_root.join(target)
+    };
+    let path = path.canonicalize().unwrap_or(path);
+    let rel = path
+        .strip_prefix(project_root)
+        .map_err(|_| {
+            miette::miette!(
+                "{} está fuera del proyecto {}",
+                target,
+                project_root.display()
+            )
+        })?
+        .to_string_lossy()
+        .replace('\\', "/");
+    if rel.is_empty() {
+        return Err(miette::miette!("Indica un archivo dentro del proyecto"));
+    }
+
+    let ignored_paths = if config::find_config(project_root).is_some() {
+        config::load_config(project_root)?.ignored_paths
+    } else {
+        config::default_ignored_paths()
+    };
+
+    match discovery::explain_ignore(project_root, &ignored_paths, &rel, path.is_dir()) {
+        discovery::IgnoreExplanation::IgnoredPaths { path, pattern } => {
+            println!("🚫 {} está ignorado", rel);
+            println!("   Regla: ignored_paths de la configuración  {}", pattern);
+            if path != rel {
+                println!("   (excluye el directorio padre {}/)", path);
             }
         }
-        Err(e) => {
-            eprintln!("⚠️  No se pudo analizar dependencias cíclicas: {}", e);
+        discovery::IgnoreExplanation::IgnoreFile(found) => {
+            println!("🚫 {} está ignorado", rel);
+            println!(
+                "   Regla: {}  {}  ({})",
+                found.rule.location(),
+                found.rule.pattern.source,
+                found.rule.kind.label()
+            );
+            if found.path != rel {
+                println!("   (excluye el directorio padre {}/)", found.path);
+            }
+        }
+        discovery::IgnoreExplanation::NotIgnored { reincluded } => {
+            println!("✅ {} no está ignorado", rel);
+            if let Some(found) = reincluded {
+                println!(
+                    "   Re-incluido por: {}  {}  ({})",
+                    found.rule.location(),
+                    found.rule.pattern.source,
+                    found.rule.kind.label()
+                );
+            }
+        }
+        discovery::IgnoreExplanation::Unsupported => {
+            println!(
+                "ℹ️  {} no está ignorado, pero no se analiza: su extensión no es de un lenguaje soportado",
+                rel
+            );
         }
     }
+    Ok(())
+}
 
-    // Health score
-    let health_score = scoring::calculate(&analysis_result);
-    analysis_result.health_score = Some(health_score);
+/// Reescribe la configuración en el formato actual, mostrando antes el diff
+fn run_migrate_mode(project_root: &Path, dry_run: bool, force: bool) -> Result<()> {
+    let path = config::find_config(project_root).ok_or_else(|| {
+        miette::miette!(
+            "No se encontró architect.json (ni .yaml, .toml o [tool.architect]) en {}",
+            project_root.display()
+        )
+    })?;
+    let plan = config::migration::FileMigration::plan(config::formats::ConfigSource::read(&path)?)?;
+    let name = plan.source.name();
+
+    if plan.is_unchanged() {
+        println!(
+            "✅ {} ya está en el formato actual (v{}).",
+            name,
+            config::migration::CURRENT_VERSION
+        );
+        return Ok(());
+    }
+
+    print!("{}", plan.diff());
+    println!();
+    for step in &plan.migrated.applied {
+        println!("   • {}", step);
+    }
+    if plan.loses_comments() {
+        println!(
+            "⚠️  Los comentarios de {} no se conservan al reescribirlo.",
+            name
+        );
+    }
+
+    if dry_run {
+        println!("ℹ️  --dry-run: no se modificó {}.", name);
+        return Ok(());
+    }
+    // Sin migraciones que aplicar solo cambiaría `version`: no compensa
+    // perder los comentarios
+    if plan.loses_comments() && plan.migrated.applied.is_empty() && !force {
+        println!(
+            "ℹ️  {} solo necesita \"version\": {}. Añádelo a mano para conservar los comentarios, o usa --force para reescribirlo.",
+            name,
+            config::migration::CURRENT_VERSION
+        );
+        return Ok(());
+    }
+    plan.write()?;
+    println!(
+        "✅ {} migrado de v{} a v{}.",
+        name,
+        plan.migrated.from_version,
+        config::migration::CURRENT_VERSION
+    );
+    Ok(())
+}
+
+/// Audita las dependencias declaradas frente a las importadas
+fn run_deps_mode(project_root: &Path, cli_args: &cli::CliArgs) -> Result<()> {
+    // architect.json es opcional: sin él se usan las rutas ignoradas por defecto
+    let ctx = config::load_config(project_root).unwrap_or_else(|_| config::LinterContext {
+        ignored_paths: config::default_ignored_paths(),
+        ..Default::default()
+    });
+    let audit = dependencies::audit(project_root, &ctx.ignored_paths, &ctx.dependency_audit)?;
+
+    if let Some(format) = cli_args.report_format {
+        let content = match format {
+            cli::ReportFormat::Json => audit.to_json(),
+            cli::ReportFormat::Markdown => audit.to_markdown(),
+            _ => {
+                return Err(miette::miette!(
+                    "El comando deps solo exporta reportes json o markdown."
+                ))
+            }
+        };
+        if let Some(output_path) = &cli_args.output_path {
+            report::write_report(&content, Path::new(output_path))?;
+            println!("📄 Report saved to: {}", output_path);
+        } else {
+            report::write_stdout(&content)?;
+        }
+    } else {
+        print_dependency_audit(&audit);
+    }
+
+    if audit.has_errors() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn print_dependency_audit(audit: &dependencies::DependencyAudit) {
+    if audit.manifests.is_empty() {
+        println!(
+            "ℹ️  No se encontró package.json, requirements.txt, pyproject.toml ni composer.json."
+        );
+        return;
+    }
+    println!(
+        "📦 Dependencias: {} declaradas en {}, {} paquetes importados\n",
+        audit.declared,
+        audit.manifests.join(", "),
+        audit.imported
+    );
+
+    for (kind, title) in [
+        (
+            dependencies::IssueKind::Undeclared,
+            "❌ Importadas pero no declaradas",
+        ),
+        (
+            dependencies::IssueKind::DevInProduction,
+            "❌ Dependencias de desarrollo usadas en producción",
+        ),
+        (
+            dependencies::IssueKind::Unused,
+            "⚠️  Declaradas pero nunca importadas",
+        ),
+    ] {
+        let issues: Vec<_> = audit.issues_of(kind).collect();
+        if issues.is_empty() {
+            continue;
+        }
+        println!("{} ({}):", title, issues.len());
+        for issue in issues {
+            let location = issue
+                .locations
+                .first()
+                .map(|l| format!(" — {}:{}", l.file, l.line))
+                .unwrap_or_default();
+            let more = issue.locations.len().saturating_sub(1);
+            println!(
+                "   {} [{}]{}{}",
+                issue.package,
+                issue.ecosystem.as_str(),
+                location,
+                if more > 0 {
+                    format!(" (+{} más)", more)
+                } else {
+                    String::new()
+                }
+            );
+        }
+        println!();
+    }
+
+    if audit.issues.is_empty() {
+        println!("✅ Todas las dependencias importadas están declaradas y en uso.");
+    }
+}
+
+/// Compara la ref base con HEAD y reporta solo lo introducido o corregido
+fn run_diff_mode(
+    project_root: &Path,
+    ctx: &config::LinterContext,
+    base_ref: &str,
+    cli_args: &cli::CliArgs,
+) -> Result<()> {
+    if !git::is_git_repo(project_root) {
+        return Err(miette::miette!(
+            "El flag --diff-base requiere un repositorio git."
+        ));
+    }
+
+    let (base_commit, mut base) = diff::analyze_base(project_root, base_ref, ctx)?;
+    let mut head = analyzer::analyze_project(project_root, ctx)?;
+    base.filter_by_severity(cli_args.min_severity);
+    head.filter_by_severity(cli_args.min_severity);
+    if cli_args.blame {
+        blame::annotate(&mut head, project_root);
+    }
+
+    let report = diff::compare(&base, &head, project_root, base_ref, &base_commit);
+
+    if let Some(format) = cli_args.report_format {
+        let content = match format {
+            cli::ReportFormat::Json => report.to_json(),
+            cli::ReportFormat::Markdown => report.to_markdown(),
+            _ => report::generate_project_report(
+                &report.introduced_result(&head),
+                format,
+                project_root,
+            ),
+        };
+        if let Some(output_path) = &cli_args.output_path {
+            report::write_report(&content, Path::new(output_path))?;
+            println!("📄 Report saved to: {}", output_path);
+        } else {
+            report::write_stdout(&content)?;
+        }
+    } else {
+        print_diff_report(&report, project_root);
+    }
 
-    Ok(analysis_result)
+    if report.has_critical_issues() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn print_diff_report(report: &diff::DiffReport, project_root: &Path) {
+    let rel = |p: &Path| {
+        p.strip_prefix(project_root)
+            .unwrap_or(p)
+            .display()
+            .to_string()
+    };
+
+    println!(
+        "🔀 Comparando HEAD con {} ({})\n",
+        report.base_ref,
+        &report.base_commit[..7.min(report.base_commit.len())]
+    );
+    for delta in report.score_deltas() {
+        println!(
+            "   {:<16} {:>3} → {:>3} ({:+})",
+            delta.metric,
+            delta.base,
+            delta.head,
+            delta.delta()
+        );
+    }
+
+    println!("\n❌ Violaciones introducidas: {}", report.introduced.len());
+    for cv in &report.introduced {
+        let author = cv
+            .blame
+            .as_ref()
+            .map(|b| format!(" ({})", b.author))
+            .unwrap_or_default();
+        println!(
+            "   {}:{} {} → {}{}",
+            rel(&cv.violation.file_path),
+            cv.violation.line_number,
+            cv.violation.rule.from,
+            cv.violation.rule.to,
+            author
+        );
+    }
+    println!("✅ Violaciones corregidas: {}", report.fixed.len());
+    for cv in &report.fixed {
+        println!(
+            "   {}:{} {} → {}",
+            rel(&cv.violation.file_path),
+            cv.violation.line_number,
+            cv.violation.rule.from,
+            cv.violation.rule.to
+        );
+    }
+
+    println!(
+        "\n🔄 Ciclos: +{} / -{}",
+        report.introduced_cycles.len(),
+        report.fixed_cycles.len()
+    );
+    for cycle in &report.introduced_cycles {
+        println!("   + {}", cycle.cycle.join(" → "));
+    }
+    for cycle in &report.fixed_cycles {
+        println!("   - {}", cycle.cycle.join(" → "));
+    }
+
+    println!(
+        "📏 Funciones largas: +{} / -{}",
+        report.introduced_long_functions.len(),
+        report.fixed_long_functions.len()
+    );
+    for func in &report.introduced_long_functions {
+        println!(
+            "   + {}:{} {} ({} líneas)",
+            rel(&func.file_path),
+            func.line_start,
+            func.name,
+            func.lines
+        );
+    }
+    for func in &report.fixed_long_functions {
+        println!(
+            "   - {}:{} {} ({} líneas)",
+            rel(&func.file_path),
+            func.line_start,
+            func.name,
+            func.lines
+        );
+    }
 }
 
 /// Run the AI auto-fix flow (reused by watch commands)
@@ -771,7 +1593,7 @@ fn run_watch_mode(
 
     println!("🚀 Iniciando modo watch...\n");
 
-    // Análisis inicial completo
+    // Análisis inicial completo; después cada cambio solo re-analiza los archivos tocados
     let files = discovery::collect_files(project_root, &ctx.ignored_paths);
 
     if !ctx.ignored_paths.is_empty() {
@@ -785,52 +1607,42 @@ fn run_watch_mode(
 
     println!("📊 Análisis inicial de {} archivos...", files.len());
 
-    // Construir grafo de dependencias inicial
-    let mut dep_analyzer = circular::CircularDependencyAnalyzer::new(project_root);
-    dep_analyzer.build_graph(&files)?;
-
-    // Análisis inicial de violaciones
-    let severity_rank = |s: config::Severity| match s {
-        config::Severity::Error => 3,
-        config::Severity::Warning => 2,
-        config::Severity::Info => 1,
+    let watch_cache = if no_cache {
+        None
+    } else {
+        let config_hash = cache::hash_config(&ctx);
+        Some(
+            cache::AnalysisCache::load(project_root, &config_hash)
+                .unwrap_or_else(|| cache::AnalysisCache::new(config_hash)),
+        )
     };
-    let min_rank = severity_rank(min_severity);
-    let mut error_count = 0;
-    for file_path in &files {
-        match analyzer::collect_violations_from_file(file_path, &ctx) {
-            Ok(violations) => {
-                let filtered: Vec<_> = violations
-                    .into_iter()
-                    .filter(|v| severity_rank(v.rule.get_severity()) >= min_rank)
-                    .collect();
-                if !filtered.is_empty() {
-                    error_count += 1;
-                    if let Ok(src) = std::fs::read_to_string(file_path) {
-                        for v in &filtered {
-                            let report = analyzer::swc_parser::create_error_from_source(&src, v);
-                            let mut out = String::new();
-                            let _ = GraphicalReportHandler::new()
-                                .render_report(&mut out, report.as_ref());
-                            println!("\n📌 Violación en: {}", file_path.display());
-                            println!("{}", out);
-                        }
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!("⚠️  Error analizando {}: {}", file_path.display(), e);
-            }
-        }
+    let mut incremental = watch::incremental::IncrementalAnalyzer::new(
+        project_root,
+        Arc::clone(&ctx),
+        min_severity,
+        watch_cache,
+    )?;
+    save_watch_cache(&incremental, project_root);
+
+    // En una terminal interactiva el dashboard reemplaza la salida por líneas
+    if watch::tui::is_supported() {
+        watch::tui::run(
+            &mut incremental,
+            &ctx.ignored_paths,
+            &project_name_notification,
+            || run_fix_flow(project_root, &ctx),
+        )?;
+        save_watch_cache(&incremental, project_root);
+        return Ok(());
     }
 
-    // Análisis de ciclos inicial
-    let cycles = dep_analyzer.detect_cycles();
-    if !cycles.is_empty() {
-        circular::print_circular_dependency_report(&cycles);
+    let result = incremental.result();
+    let error_count = print_watch_violations(result, None);
+    if !result.circular_dependencies.is_empty() {
+        circular::print_circular_dependency_report(&result.circular_dependencies);
         println!(
             "\n⚠️  Se encontraron {} dependencias cíclicas.",
-            cycles.len()
+            result.circular_dependencies.len()
         );
     }
 
@@ -842,115 +1654,60 @@ fn run_watch_mode(
     } else {
         println!("\n✨ ¡Proyecto impecable! La arquitectura se respeta.");
     }
+    if let Some(score) = &result.health_score {
+        println!(
+            "📊 Health score: {} ({})",
+            score.total,
+            score.grade.as_str()
+        );
+    }
 
     println!();
 
-    // Initialize analysis cache for watch session
-    let config_hash = cache::hash_config(&ctx);
-    let watch_cache: Arc<Mutex<Option<cache::AnalysisCache>>> = if no_cache {
-        Arc::new(Mutex::new(None))
-    } else {
-        let c = cache::AnalysisCache::load(project_root, &config_hash)
-            .unwrap_or_else(|| cache::AnalysisCache::new(config_hash));
-        Arc::new(Mutex::new(Some(c)))
-    };
-
     // Shared state for the watch loop
-    let dep_analyzer = Arc::new(Mutex::new(dep_analyzer));
+    let incremental = Arc::new(Mutex::new(incremental));
+    let cmd_incremental = Arc::clone(&incremental);
     let project_root_arc = Arc::new(project_root.to_path_buf());
     let ignored_paths = ctx.ignored_paths.clone();
-
-    // Clone Arcs for the on_command closure
     let cmd_ctx = Arc::clone(&ctx);
-    let cmd_project_root = Arc::clone(&project_root_arc);
-    let cmd_cache = Arc::clone(&watch_cache);
-
-    let change_cache = Arc::clone(&watch_cache);
 
     watch::start_watch_mode(
         project_root_arc.as_ref(),
         ignored_paths,
-        // on_change: incremental analysis for file changes
+        // on_change: re-analiza solo los archivos cambiados y parchea el resultado
         |changed_files| {
-            let dep_analyzer = Arc::clone(&dep_analyzer);
-            let ctx = Arc::clone(&ctx);
-            let project_root = Arc::clone(&project_root_arc);
-            let change_cache = Arc::clone(&change_cache);
-            let project_name_notification = Arc::clone(&project_name_notification);
-
-            // Invalidate changed files in cache
-            {
-                let mut cache_guard = change_cache.lock().expect("Failed to lock mutex");
-                if let Some(ref mut c) = *cache_guard {
-                    for file_path in changed_files {
-                        let key = cache::AnalysisCache::normalize_path(file_path, &project_root);
-                        c.remove(&key);
-                    }
-                }
-            }
-
-            let severity_rank_fn = |s: config::Severity| match s {
-                config::Severity::Error => 3,
-                config::Severity::Warning => 2,
-                config::Severity::Info => 1,
-            };
-            let min_rank_watch = severity_rank_fn(min_severity);
-            let mut error_count = 0;
-            for file_path in changed_files {
-                match analyzer::collect_violations_from_file(file_path, &ctx) {
-                    Ok(violations) => {
-                        let filtered: Vec<_> = violations
-                            .into_iter()
-                            .filter(|v| severity_rank_fn(v.rule.get_severity()) >= min_rank_watch)
-                            .collect();
-                        if !filtered.is_empty() {
-                            error_count += 1;
-                            if let Ok(src) = std::fs::read_to_string(file_path) {
-                                for v in &filtered {
-                                    let report =
-                                        analyzer::swc_parser::create_error_from_source(&src, v);
-                                    let mut out = String::new();
-                                    let _ = GraphicalReportHandler::new()
-                                        .render_report(&mut out, report.as_ref());
-                                    println!("\n📌 Violación en: {}", file_path.display());
-                                    println!("{}", out);
-                                }
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("⚠️  Error analizando {}: {}", file_path.display(), e);
-                    }
-                }
+            let mut incremental = incremental.lock().expect("Failed to lock mutex");
+            let changes = incremental.apply_changes(changed_files)?;
 
-                let mut dep_analyzer = dep_analyzer.lock().expect("Failed to lock mutex");
-                if let Err(e) = dep_analyzer.update_file(file_path) {
-                    eprintln!("⚠️  Error actualizando grafo: {}", e);
-                    continue;
-                }
+            for removed in &changes.removed {
+                println!("   🗑️  Eliminado: {}", removed.display());
+            }
 
-                let normalized_path =
-                    if let Ok(relative) = file_path.strip_prefix(project_root.as_ref()) {
-                        relative.to_string_lossy().replace('\\', "/").to_lowercase()
-                    } else {
-                        file_path
-                            .to_string_lossy()
-                            .replace('\\', "/")
-                            .to_lowercase()
-                    };
+            let result = incremental.result();
+            let error_count = print_watch_violations(result, Some(&changes.reanalyzed));
 
-                let affected_nodes = dep_analyzer.get_affected_nodes(&normalized_path);
+            if !changes.cycles.is_empty() {
+                circular::print_circular_dependency_report(&changes.cycles);
+                println!(
+                    "\n⚠️  Se encontraron {} dependencias cíclicas.",
+                    changes.cycles.len()
+                );
+                notification::send_cycle_alert(&project_name_notification, changes.cycles.len());
+            }
 
-                if !affected_nodes.is_empty() {
-                    let cycles = dep_analyzer.detect_cycles_in_subgraph(&affected_nodes);
-                    if !cycles.is_empty() {
-                        circular::print_circular_dependency_report(&cycles);
-                        println!(
-                            "\n⚠️  Se encontraron {} dependencias cíclicas.",
-                            cycles.len()
-                        );
-                        notification::send_cycle_alert(&project_name_notification, cycles.len());
-                    }
+            if let Some(score) = &result.health_score {
+                match changes.previous_score {
+                    Some(previous) if previous != score.total => println!(
+                        "\n📊 Health score: {} → {} ({})",
+                        previous,
+                        score.total,
+                        score.grade.as_str()
+                    ),
+                    _ => println!(
+                        "\n📊 Health score: {} ({})",
+                        score.total,
+                        score.grade.as_str()
+                    ),
                 }
             }
 
@@ -967,28 +1724,16 @@ fn run_watch_mode(
 
             Ok(())
         },
-        // on_command: handle interactive commands
+        // on_command: handle interactive commands over the in-memory result
         |cmd| {
             let ctx = Arc::clone(&cmd_ctx);
-            let project_root = cmd_project_root.as_ref();
-            let cmd_cache = Arc::clone(&cmd_cache);
-
-            // Helper: run full analysis with cache, then save
-            let run_cached_analysis = |cache_arc: &Arc<Mutex<Option<cache::AnalysisCache>>>| -> Result<analysis_result::AnalysisResult> {
-                let mut guard = cache_arc.lock().expect("Failed to lock mutex");
-                let result = run_full_analysis(
-                    project_root,
-                    &ctx,
-                    guard.as_mut(),
-                    min_severity,
-                )?;
-                // Save cache after analysis
-                if let Some(ref c) = *guard {
-                    if let Err(e) = c.save(project_root) {
-                        eprintln!("⚠️  Could not save analysis cache: {}", e);
-                    }
-                }
-                Ok(result)
+            let project_root = project_root_arc.as_ref();
+            let current_result = || {
+                cmd_incremental
+                    .lock()
+                    .expect("Failed to lock mutex")
+                    .result()
+                    .clone()
             };
 
             match cmd {
@@ -1003,7 +1748,7 @@ fn run_watch_mode(
                 }
                 watch::WatchCommand::ReportJson => {
                     println!("\n📄 Generando reporte JSON...\n");
-                    let result = run_cached_analysis(&cmd_cache)?;
+                    let result = current_result();
                     let content = report::generate_report(&result, cli::ReportFormat::Json);
                     let path = project_root.join("report.json");
                     report::write_report(&content, &path)?;
@@ -1011,7 +1756,7 @@ fn run_watch_mode(
                 }
                 watch::WatchCommand::ReportMarkdown => {
                     println!("\n📄 Generando reporte Markdown...\n");
-                    let result = run_cached_analysis(&cmd_cache)?;
+                    let result = current_result();
                     let content = report::generate_report(&result, cli::ReportFormat::Markdown);
                     let path = project_root.join("report.md");
                     report::write_report(&content, &path)?;
@@ -1019,7 +1764,13 @@ fn run_watch_mode(
                 }
                 watch::WatchCommand::FullAnalysis => {
                     println!("\n📊 Ejecutando análisis completo...\n");
-                    let result = run_cached_analysis(&cmd_cache)?;
+                    // Rehace el análisis desde cero (el caché sigue sirviendo los archivos sin cambios)
+                    let result = {
+                        let mut incremental = cmd_incremental.lock().expect("Failed to lock mutex");
+                        incremental.reanalyze_all()?;
+                        save_watch_cache(&incremental, project_root);
+                        incremental.result().clone()
+                    };
                     output::print_dashboard(&result);
                     output::dashboard::print_summary(&result);
                     if !result.circular_dependencies.is_empty() {
@@ -1029,7 +1780,7 @@ fn run_watch_mode(
                 }
                 watch::WatchCommand::Violations => {
                     println!("\n🔍 Escaneando todas las violaciones...\n");
-                    let result = run_cached_analysis(&cmd_cache)?;
+                    let result = current_result();
 
                     if result.violations.is_empty() && result.circular_dependencies.is_empty() {
                         println!("✨ ¡No se encontraron violaciones! La arquitectura se respeta.");
@@ -1085,7 +1836,7 @@ fn run_watch_mode(
                 }
                 watch::WatchCommand::Dashboard => {
                     println!("\n📊 Calculando health score...\n");
-                    let result = run_cached_analysis(&cmd_cache)?;
+                    let result = current_result();
                     output::print_dashboard(&result);
                 }
                 watch::WatchCommand::Clear => {
@@ -1106,9 +1857,51 @@ fn run_watch_mode(
         },
     )?;
 
+    save_watch_cache(
+        &incremental.lock().expect("Failed to lock mutex"),
+        project_root,
+    );
     Ok(())
 }
 
+/// Muestra las violaciones del resultado (solo las de `files` si se indica) y
+/// devuelve cuántos archivos tienen violaciones
+fn print_watch_violations(
+    result: &analysis_result::AnalysisResult,
+    files: Option<&[PathBuf]>,
+) -> usize {
+    let mut by_file: BTreeMap<&Path, Vec<&autofix::Violation>> = BTreeMap::new();
+    for cv in &result.violations {
+        let file_path = cv.violation.file_path.as_path();
+        if files.is_none_or(|files| files.iter().any(|f| f == file_path)) {
+            by_file.entry(file_path).or_default().push(&cv.violation);
+        }
+    }
+
+    for (file_path, violations) in &by_file {
+        let Ok(src) = std::fs::read_to_string(file_path) else {
+            continue;
+        };
+        for v in violations {
+            let report = analyzer::swc_parser::create_error_from_source(&src, v);
+            let mut out = String::new();
+            let _ = GraphicalReportHandler::new().render_report(&mut out, report.as_ref());
+            println!("\n📌 Violación en: {}", file_path.display());
+            println!("{}", out);
+        }
+    }
+    by_file.len()
+}
+
+/// Guarda el caché de análisis del modo watch
+fn save_watch_cache(incremental: &watch::incremental::IncrementalAnalyzer, project_root: &Path) {
+    if let Some(c) = incremental.cache() {
+        if let Err(e) = c.save(project_root) {
+            eprintln!("⚠️  Could not save analysis cache: {}", e);
+        }
+    }
+}
+
 /// Ejecuta el análisis en modo fix (auto-reparación con IA)
 fn run_fix_mode(project_root: &Path, ctx: Arc<config::LinterContext>) -> Result<()> {
     println!("🔧 Modo Fix: Auto-reparación con IA\n");
@@ -1179,7 +1972,8 @@ fn run_incremental_mode(
 
     // Handle report export if requested
     if let Some(format) = cli_args.report_format {
-        let report_content = report::generate_report(&analysis_result, format);
+        let report_content =
+            report::generate_project_report(&analysis_result, format, project_root);
 
         if let Some(output_path) = &cli_args.output_path {
             let path = std::path::Path::new(output_path);
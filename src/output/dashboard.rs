@@ -243,6 +243,47 @@ fn print_violations_list(violations: &[CategorizedViolation]) {
     print_horizontal_line(BOX_BOTTOM_LEFT, BOX_BOTTOM_RIGHT, BOX_HORIZONTAL);
 }
 
+/// Print violations grouped by owning team (CODEOWNERS)
+pub fn print_team_breakdown(result: &AnalysisResult) {
+    let groups = result.violations_by_team();
+    if groups.is_empty() {
+        return;
+    }
+
+    println!();
+    print_horizontal_line(BOX_TOP_LEFT, BOX_TOP_RIGHT, BOX_HORIZONTAL);
+    print_centered_line("VIOLATIONS BY TEAM");
+    print_horizontal_line(BOX_T_LEFT, BOX_T_RIGHT, BOX_HORIZONTAL);
+
+    let mut teams: Vec<_> = groups.into_iter().collect();
+    teams.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then(a.0.cmp(b.0)));
+    for (team, violations) in teams {
+        let blocked = violations
+            .iter()
+            .filter(|cv| cv.category == crate::analysis_result::ViolationCategory::Blocked)
+            .count();
+        println!(
+            "{}  {} ({} total, {} blocked)",
+            BOX_VERTICAL,
+            team,
+            violations.len(),
+            blocked
+        );
+        for cv in violations {
+            println!(
+                "{}     {}:{}  {} -> {}",
+                BOX_VERTICAL,
+                cv.violation.file_path.display(),
+                cv.violation.line_number,
+                cv.violation.rule.from,
+                cv.violation.rule.to
+            );
+        }
+    }
+
+    print_horizontal_line(BOX_BOTTOM_LEFT, BOX_BOTTOM_RIGHT, BOX_HORIZONTAL);
+}
+
 /// Print a horizontal line
 fn print_horizontal_line(left: &str, right: &str, fill: &str) {
     let middle = fill.repeat(DASHBOARD_WIDTH - 2);
@@ -2,8 +2,22 @@
 //!
 //! This module provides functionality to generate interactive HTML reports
 //! with violations table, architecture score display, and CSS styling.
+//!
+//! `HtmlReporter::render` builds a self-contained report (inline CSS/JS, no
+//! network access) from a full `AnalysisResult`; it backs `--report html`.
+//! `render_with_history` adds the score trend from `.architect/history.jsonl`.
 
+use crate::analysis_result::AnalysisResult;
+use crate::history::HistoryEntry;
+use serde_json::{json, Value};
 use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Single-file report template; `__TITLE__` and `__DATA__` are substituted
+const REPORT_TEMPLATE: &str = include_str!("report_template.html");
+
+/// Lines of context shown above and below each violation
+const SNIPPET_CONTEXT: usize = 2;
 
 /// Escapes HTML special characters to prevent XSS attacks
 ///
@@ -115,6 +129,227 @@ impl HtmlReporter {
     }
 }
 
+impl HtmlReporter {
+    /// Render a self-contained HTML report from a full analysis result
+    ///
+    /// Includes health score components, violations grouped by rule/layer/file
+    /// with code snippets, a cycle viewer, long functions and an interactive
+    /// dependency graph. All assets are inlined so the file works offline.
+    pub fn render(result: &AnalysisResult) -> String {
+        Self::render_with_history(result, &[])
+    }
+
+    /// Same as `render`, adding a score trend chart from the history entries
+    pub fn render_with_history(result: &AnalysisResult, history: &[HistoryEntry]) -> String {
+        let mut data = report_data(result);
+        data["history"] = history
+            .iter()
+            .map(|entry| {
+                json!({
+                    "commit": entry.short_commit(),
+                    "timestamp": entry.timestamp.format("%Y-%m-%d %H:%M").to_string(),
+                    "total": entry.total,
+                    "violations": entry.total_violations,
+                })
+            })
+            .collect();
+        // Keep the embedded JSON from closing the <script> element early
+        let data = data.to_string().replace('<', "\\u003c");
+
+        fill_template(
+            REPORT_TEMPLATE,
+            &[
+                ("__TITLE__", &escape_html(&result.project_name)),
+                ("__DATA__", &data),
+            ],
+        )
+    }
+}
+
+/// Substitute the placeholders in one pass, so a value that contains a
+/// placeholder name (e.g. a project called `__DATA__`) is inserted verbatim
+fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some((at, placeholder, value)) = values
+        .iter()
+        .filter_map(|&(placeholder, value)| Some((rest.find(placeholder)?, placeholder, value)))
+        .min_by_key(|&(at, _, _)| at)
+    {
+        filled.push_str(&rest[..at]);
+        filled.push_str(value);
+        rest = &rest[at + placeholder.len()..];
+    }
+    filled.push_str(rest);
+    filled
+}
+
+/// Build the JSON payload consumed by the report's inline script
+fn report_data(result: &AnalysisResult) -> Value {
+    let health_score = result.health_score.as_ref().map(|s| {
+        json!({
+            "total": s.total,
+            "grade": s.grade.as_str(),
+            "components": [
+                { "name": "Layer Isolation", "value": s.components.layer_isolation },
+                { "name": "Circular Dependencies", "value": s.components.circular_deps },
+                { "name": "Complexity", "value": s.components.complexity },
+                { "name": "Violations", "value": s.components.violations },
+            ],
+        })
+    });
+
+    let mut graph = DependencyGraph::default();
+
+    let violations: Vec<Value> = result
+        .violations
+        .iter()
+        .map(|cv| {
+            let v = &cv.violation;
+            let file = display_path(&v.file_path);
+            graph.add_edge(
+                &file,
+                &import_target(&file, &v.offensive_import),
+                "violation",
+            );
+            json!({
+                "file": file,
+                "line": v.line_number,
+                "category": cv.category.as_str(),
+                "security": cv.security_severity.map(|s| format!("{:.1}", s)),
+                "rule": format!("{} → {}", v.rule.from, v.rule.to),
+                "layer": v.rule.from,
+                "team": if cv.owners.is_empty() {
+                    crate::codeowners::UNOWNED.to_string()
+                } else {
+                    cv.owners.join(", ")
+                },
+                "reason": v.rule.reason.clone().unwrap_or_else(|| {
+                    format!("'{}' cannot import from '{}'", v.rule.from, v.rule.to)
+                }),
+                "import": v.offensive_import,
+                "snippet": snippet(&v.file_content, v.line_number),
+            })
+        })
+        .collect();
+
+    let cycles: Vec<Value> = result
+        .circular_dependencies
+        .iter()
+        .map(|cd| {
+            for (i, from) in cd.cycle.iter().enumerate() {
+                let to = &cd.cycle[(i + 1) % cd.cycle.len()];
+                graph.add_edge(from, to, "cycle");
+            }
+            json!({ "files": cd.cycle, "description": cd.description })
+        })
+        .collect();
+
+    let long_functions: Vec<Value> = result
+        .long_functions
+        .iter()
+        .map(|lf| {
+            json!({
+                "file": display_path(&lf.file_path),
+                "name": lf.name,
+                "line": lf.line_start,
+                "lines": lf.lines,
+                "threshold": lf.threshold,
+            })
+        })
+        .collect();
+
+    json!({
+        "project": {
+            "name": result.project_name,
+            "pattern": result.pattern_display(),
+            "files_analyzed": result.files_analyzed,
+            "timestamp": result.timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+        },
+        "health_score": health_score,
+        "summary": [
+            { "name": "Total Violations", "value": result.violations.len() },
+            { "name": "Blocked", "value": result.blocked_count() },
+            { "name": "Warnings", "value": result.warning_count() },
+            { "name": "Circular Dependencies", "value": result.circular_dependencies.len() },
+            { "name": "Long Functions", "value": result.long_functions.len() },
+        ],
+        "violations": violations,
+        "cycles": cycles,
+        "long_functions": long_functions,
+        "graph": { "nodes": graph.nodes, "edges": graph.edges },
+        "history": [],
+    })
+}
+
+/// Nodes and edges shown in the dependency graph view
+#[derive(Default)]
+struct DependencyGraph {
+    nodes: Vec<String>,
+    edges: Vec<Value>,
+}
+
+impl DependencyGraph {
+    fn add_edge(&mut self, from: &str, to: &str, kind: &str) {
+        for node in [from, to] {
+            if !self.nodes.iter().any(|n| n == node) {
+                self.nodes.push(node.to_string());
+            }
+        }
+        self.edges
+            .push(json!({ "from": from, "to": to, "kind": kind }));
+    }
+}
+
+/// Lines around `line` (1-based) with their numbers
+fn snippet(content: &str, line: usize) -> Vec<Value> {
+    if content.is_empty() || line == 0 {
+        return Vec::new();
+    }
+    let first = line.saturating_sub(SNIPPET_CONTEXT).max(1);
+    content
+        .lines()
+        .enumerate()
+        .skip(first - 1)
+        .take(line + SNIPPET_CONTEXT + 1 - first)
+        .map(|(i, text)| json!({ "line": i + 1, "text": text }))
+        .collect()
+}
+
+/// Path relative to the working directory when possible, with `/` separators
+fn display_path(path: &Path) -> String {
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf());
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+/// Resolve the module an import points to, relative to the importing file
+fn import_target(file: &str, offensive_import: &str) -> String {
+    let specifier = offensive_import
+        .split(['\'', '"'])
+        .nth(1)
+        .unwrap_or(offensive_import)
+        .trim();
+    if !specifier.starts_with('.') {
+        return specifier.to_string();
+    }
+
+    let mut resolved = PathBuf::new();
+    let base = Path::new(file).parent().unwrap_or(Path::new(""));
+    for component in base.join(specifier).components() {
+        match component {
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            Component::CurDir => {}
+            other => resolved.push(other),
+        }
+    }
+    resolved.to_string_lossy().replace('\\', "/")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,4 +399,78 @@ mod tests {
         assert!(content.contains("Test violation"));
         assert!(content.contains("Another violation"));
     }
+
+    #[test]
+    fn test_render_full_report() {
+        use crate::analysis_result::{CategorizedViolation, LongFunction, ViolationCategory};
+        use crate::autofix::Violation;
+        use crate::circular::CircularDependency;
+        use crate::config::{ArchPattern, ForbiddenRule};
+        use std::path::PathBuf;
+
+        let mut result = AnalysisResult::new("demo".to_string(), ArchPattern::Hexagonal);
+        result.add_violation(CategorizedViolation::new(
+            Violation {
+                file_path: PathBuf::from("src/domain/user.ts"),
+                file_content: "import { Db } from '../infrastructure/db';\n// </script>\n"
+                    .to_string(),
+                offensive_import: "import { Db } from '../infrastructure/db';".to_string(),
+                rule: ForbiddenRule {
+                    from: "/domain/".to_string(),
+                    to: "/infrastructure/".to_string(),
+                    severity: None,
+                    reason: None,
+                },
+                line_number: 1,
+            },
+            ViolationCategory::Blocked,
+        ));
+        result.add_circular_dependency(CircularDependency {
+            cycle: vec!["src/a.ts".to_string(), "src/b.ts".to_string()],
+            description: "src/a.ts -> src/b.ts -> src/a.ts".to_string(),
+        });
+        result.add_long_function(LongFunction {
+            file_path: PathBuf::from("src/big.ts"),
+            name: "doEverything".to_string(),
+            line_start: 3,
+            lines: 90,
+            threshold: 40,
+        });
+
+        let html = HtmlReporter::render(&result);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(!html.contains("__DATA__"));
+        assert!(!html.contains("src=\"http"));
+        // Only the template's own closing tags remain
+        assert_eq!(html.matches("</script>").count(), 2);
+        assert!(html.contains("src/infrastructure/db"));
+        assert!(html.contains("doEverything"));
+    }
+
+    #[test]
+    fn test_placeholders_in_project_name_are_kept() {
+        use crate::config::ArchPattern;
+
+        let result = AnalysisResult::new("app__DATA____TITLE__".to_string(), ArchPattern::MVC);
+        let html = HtmlReporter::render(&result);
+
+        assert!(html.contains("<title>Architect Linter Pro Report - app__DATA____TITLE__</title>"));
+        assert_eq!(html.matches("id=\"report-data\"").count(), 1);
+        assert_eq!(html.matches("\"project\":").count(), 1);
+    }
+
+    #[test]
+    fn test_import_target_resolution() {
+        assert_eq!(
+            import_target(
+                "src/domain/user.ts",
+                "import { Db } from '../infrastructure/db';"
+            ),
+            "src/infrastructure/db"
+        );
+        assert_eq!(
+            import_target("src/app.ts", "import x from 'lodash'"),
+            "lodash"
+        );
+    }
 }
@@ -0,0 +1,664 @@
+//! Third-party packages
+//!
+//! Maps import specifiers to the package they come from, per ecosystem:
+//!
+//! - npm: `@scope/name/sub/path` → `@scope/name`, `lodash/fp` → `lodash`,
+//!   `node:fs` → `fs` (built-in)
+//! - pip: `yaml.constructor` → `yaml` (the top-level module, which is not
+//!   always the distribution name: `PyYAML` installs `yaml`)
+//! - composer: `Monolog\Handler\StreamHandler` → `monolog/monolog`, using the
+//!   autoload prefixes in `composer.lock` (or `vendor/composer/installed.json`)
+//!
+//! Imports that resolve to project files (relative paths, `@/` aliases, local
+//! Python packages, namespaces autoloaded from the project's `composer.json`)
+//! are not packages.
+//!
+//! `external_imports` rules in architect.json restrict which packages a path
+//! may import, either with a deny-list or an allow-list.
+
+use crate::autofix::Violation;
+use crate::config::{ExternalImportRule, ForbiddenRule, LinterContext};
+use crate::parsers::resolve::resolve_import;
+use crate::parsers::Import;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Node.js core modules (also importable with the `node:` prefix)
+const NODE_BUILTINS: &[&str] = &[
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "sqlite",
+    "stream",
+    "string_decoder",
+    "sys",
+    "test",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// Python standard library top-level modules
+const PYTHON_STDLIB: &[&str] = &[
+    "__future__",
+    "_thread",
+    "abc",
+    "aifc",
+    "argparse",
+    "array",
+    "ast",
+    "asyncio",
+    "atexit",
+    "base64",
+    "bdb",
+    "binascii",
+    "bisect",
+    "builtins",
+    "bz2",
+    "calendar",
+    "cgi",
+    "cmath",
+    "cmd",
+    "code",
+    "codecs",
+    "collections",
+    "colorsys",
+    "compileall",
+    "concurrent",
+    "configparser",
+    "contextlib",
+    "contextvars",
+    "copy",
+    "copyreg",
+    "cProfile",
+    "csv",
+    "ctypes",
+    "curses",
+    "dataclasses",
+    "datetime",
+    "dbm",
+    "decimal",
+    "difflib",
+    "dis",
+    "doctest",
+    "email",
+    "encodings",
+    "ensurepip",
+    "enum",
+    "errno",
+    "faulthandler",
+    "fcntl",
+    "filecmp",
+    "fileinput",
+    "fnmatch",
+    "fractions",
+    "ftplib",
+    "functools",
+    "gc",
+    "getopt",
+    "getpass",
+    "gettext",
+    "glob",
+    "graphlib",
+    "grp",
+    "gzip",
+    "hashlib",
+    "heapq",
+    "hmac",
+    "html",
+    "http",
+    "imaplib",
+    "importlib",
+    "inspect",
+    "io",
+    "ipaddress",
+    "itertools",
+    "json",
+    "keyword",
+    "linecache",
+    "locale",
+    "logging",
+    "lzma",
+    "mailbox",
+    "marshal",
+    "math",
+    "mimetypes",
+    "mmap",
+    "multiprocessing",
+    "netrc",
+    "numbers",
+    "operator",
+    "optparse",
+    "os",
+    "pathlib",
+    "pdb",
+    "pickle",
+    "pkgutil",
+    "platform",
+    "plistlib",
+    "poplib",
+    "posix",
+    "pprint",
+    "profile",
+    "pstats",
+    "pty",
+    "pwd",
+    "py_compile",
+    "pydoc",
+    "queue",
+    "random",
+    "re",
+    "readline",
+    "reprlib",
+    "resource",
+    "runpy",
+    "sched",
+    "secrets",
+    "select",
+    "selectors",
+    "shelve",
+    "shlex",
+    "shutil",
+    "signal",
+    "site",
+    "smtplib",
+    "socket",
+    "socketserver",
+    "sqlite3",
+    "ssl",
+    "stat",
+    "statistics",
+    "string",
+    "struct",
+    "subprocess",
+    "sys",
+    "sysconfig",
+    "tarfile",
+    "tempfile",
+    "termios",
+    "textwrap",
+    "threading",
+    "time",
+    "timeit",
+    "tkinter",
+    "token",
+    "tokenize",
+    "tomllib",
+    "traceback",
+    "types",
+    "typing",
+    "unicodedata",
+    "unittest",
+    "urllib",
+    "uuid",
+    "venv",
+    "warnings",
+    "weakref",
+    "xml",
+    "zipfile",
+    "zoneinfo",
+];
+
+/// Package ecosystem an import belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Ecosystem {
+    Npm,
+    Pip,
+    Composer,
+}
+
+impl Ecosystem {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Ecosystem::Npm => "npm",
+            Ecosystem::Pip => "pip",
+            Ecosystem::Composer => "composer",
+        }
+    }
+
+    /// Ecosystem of the files with this extension
+    pub fn of_file(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" => Some(Ecosystem::Npm),
+            "py" => Some(Ecosystem::Pip),
+            "php" => Some(Ecosystem::Composer),
+            _ => None,
+        }
+    }
+}
+
+/// The package an import comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageRef {
+    pub ecosystem: Ecosystem,
+    /// Normalized name (`@scope/name`, top-level module, `vendor/package`)
+    pub name: String,
+    /// Part of the runtime (Node core module, Python stdlib, global PHP class)
+    pub builtin: bool,
+}
+
+/// npm package of a bare specifier: `@scope/name/sub` → `@scope/name`, `name/sub` → `name`.
+/// None for relative/absolute paths, aliases (`@/`, `~/`, `#internal`) and URLs.
+pub fn npm_package_name(source: &str) -> Option<String> {
+    let source = source.trim();
+    let source = source.strip_prefix("node:").unwrap_or(source);
+    if source.is_empty()
+        || source.starts_with(['.', '/', '~', '#'])
+        || source.starts_with("@/")
+        || source.contains(':')
+    {
+        return None;
+    }
+    let mut parts = source.split('/');
+    let first = parts.next()?;
+    if first.starts_with('@') {
+        let name = parts.next().filter(|n| !n.is_empty())?;
+        Some(format!("{}/{}", first, name))
+    } else {
+        Some(first.to_string())
+    }
+}
+
+/// Top-level module of a Python import (`a.b.c` → `a`). None for relative imports.
+pub fn python_top_level(source: &str) -> Option<String> {
+    let source = source.trim();
+    if source.is_empty() || source.starts_with('.') {
+        return None;
+    }
+    source.split('.').next().map(String::from)
+}
+
+/// Python names compare case-insensitively with `-`, `_` and `.` equivalent (PEP 503)
+pub fn normalize_python_name(name: &str) -> String {
+    name.trim().to_lowercase().replace(['-', '.'], "_")
+}
+
+fn is_builtin(ecosystem: Ecosystem, name: &str) -> bool {
+    match ecosystem {
+        Ecosystem::Npm => NODE_BUILTINS.contains(&name),
+        Ecosystem::Pip => PYTHON_STDLIB.contains(&name),
+        // Global classes (`use DateTime;`) are never mapped to a package
+        Ecosystem::Composer => false,
+    }
+}
+
+/// Resolves imports to packages for one project
+#[derive(Debug, Clone, Default)]
+pub struct PackageResolver {
+    pub root: PathBuf,
+    /// Namespace prefix (`Monolog\`) → composer package, longest prefix first
+    composer_namespaces: Vec<(String, String)>,
+    /// Namespaces autoloaded from the project's own composer.json
+    local_namespaces: Vec<String>,
+    /// Whether composer.lock (or vendor/composer/installed.json) was found
+    composer_lock: bool,
+}
+
+impl PackageResolver {
+    pub fn new(root: &Path) -> Self {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+
+        let mut composer_namespaces = Vec::new();
+        let lock = read_json(&root.join("composer.lock"))
+            .or_else(|| read_json(&root.join("vendor/composer/installed.json")));
+        let composer_lock = lock.is_some();
+        if let Some(lock) = lock {
+            let packages = lock
+                .get("packages")
+                .and_then(|p| p.as_array())
+                .into_iter()
+                .chain(lock.get("packages-dev").and_then(|p| p.as_array()))
+                .flatten()
+                .chain(lock.as_array().into_iter().flatten());
+            for package in packages {
+                let Some(name) = package.get("name").and_then(|n| n.as_str()) else {
+                    continue;
+                };
+                for prefix in autoload_prefixes(package.get("autoload")) {
+                    composer_namespaces.push((prefix, name.to_string()));
+                }
+            }
+        }
+        composer_namespaces.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then(a.cmp(b)));
+
+        let local_namespaces = read_json(&root.join("composer.json"))
+            .map(|json| {
+                let mut prefixes = autoload_prefixes(json.get("autoload"));
+                prefixes.extend(autoload_prefixes(json.get("autoload-dev")));
+                prefixes
+            })
+            .unwrap_or_default();
+
+        Self {
+            root,
+            composer_namespaces,
+            local_namespaces,
+            composer_lock,
+        }
+    }
+
+    /// Whether composer namespaces can be mapped to their packages
+    pub fn has_composer_lock(&self) -> bool {
+        self.composer_lock
+    }
+
+    /// The package `source` (imported from `file_path`) comes from, if any
+    pub fn package_of(&self, file_path: &Path, source: &str) -> Option<PackageRef> {
+        let ecosystem = Ecosystem::of_file(file_path)?;
+        let name = match ecosystem {
+            Ecosystem::Npm => {
+                let name = npm_package_name(source)?;
+                if !source.starts_with("node:")
+                    && resolve_import(file_path, source, &self.root).is_some()
+                {
+                    return None;
+                }
+                name
+            }
+            Ecosystem::Pip => {
+                let name = python_top_level(source)?;
+                if self.is_local_python(file_path, &name) {
+                    return None;
+                }
+                name
+            }
+            Ecosystem::Composer => return self.composer_package(source),
+        };
+        Some(PackageRef {
+            builtin: is_builtin(ecosystem, &name),
+            ecosystem,
+            name,
+        })
+    }
+
+    /// Python module that lives in the project (root, `src/` layout or next to the file)
+    fn is_local_python(&self, file_path: &Path, top: &str) -> bool {
+        let mut dirs = vec![self.root.clone(), self.root.join("src")];
+        if let Some(parent) = file_path.parent() {
+            dirs.push(parent.to_path_buf());
+        }
+        dirs.iter()
+            .any(|dir| dir.join(top).is_dir() || dir.join(format!("{}.py", top)).is_file())
+    }
+
+    fn composer_package(&self, source: &str) -> Option<PackageRef> {
+        // require/include paths are files, not packages
+        if source.contains('/') || source.ends_with(".php") {
+            return None;
+        }
+        let class = source.trim().trim_start_matches('\\');
+        if !class.contains('\\') {
+            return None;
+        }
+        let qualified = format!("{}\\", class);
+        if self
+            .local_namespaces
+            .iter()
+            .any(|prefix| qualified.starts_with(prefix.as_str()))
+        {
+            return None;
+        }
+        let name = self
+            .composer_namespaces
+            .iter()
+            .find(|(prefix, _)| qualified.starts_with(prefix.as_str()))
+            .map(|(_, package)| package.clone())
+            .unwrap_or_else(|| {
+                // Without a lock file, fall back to the `Vendor\Package` convention
+                class
+                    .split('\\')
+                    .take(2)
+                    .collect::<Vec<_>>()
+                    .join("/")
+                    .to_lowercase()
+            });
+        Some(PackageRef {
+            ecosystem: Ecosystem::Composer,
+            name,
+            builtin: false,
+        })
+    }
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+/// PSR-4/PSR-0 namespace prefixes of an `autoload` section, with a trailing `\`
+fn autoload_prefixes(autoload: Option<&serde_json::Value>) -> Vec<String> {
+    let Some(autoload) = autoload else {
+        return Vec::new();
+    };
+    ["psr-4", "psr-0"]
+        .iter()
+        .filter_map(|kind| autoload.get(kind).and_then(|m| m.as_object()))
+        .flat_map(|map| map.keys())
+        .map(|prefix| prefix.trim_start_matches('\\').trim_end_matches('\\'))
+        .filter(|prefix| !prefix.is_empty())
+        .map(|prefix| format!("{}\\", prefix))
+        .collect()
+}
+
+/// Whether a package name matches a rule entry (`axios`, `@nestjs/*`, `django*`)
+pub fn package_matches(pattern: &str, package: &PackageRef) -> bool {
+    let pattern = pattern.trim();
+    match package.ecosystem {
+        Ecosystem::Pip => crate::gitpattern::glob_match(
+            &normalize_python_name(pattern),
+            &normalize_python_name(&package.name),
+        ),
+        Ecosystem::Composer => {
+            crate::gitpattern::glob_match(&pattern.to_lowercase(), &package.name.to_lowercase())
+        }
+        Ecosystem::Npm => crate::gitpattern::glob_match(
+            pattern.strip_prefix("node:").unwrap_or(pattern),
+            &package.name,
+        ),
+    }
+}
+
+/// The `external_imports` section of architect.json, ready to be evaluated
+#[derive(Debug, Clone)]
+pub struct ExternalImportRules {
+    pub rules: Vec<ExternalImportRule>,
+    pub resolver: PackageResolver,
+}
+
+impl ExternalImportRules {
+    /// None when there are no rules
+    pub fn new(root: &Path, rules: &[ExternalImportRule]) -> Option<Self> {
+        if rules.is_empty() {
+            return None;
+        }
+        Some(Self {
+            rules: rules.to_vec(),
+            resolver: PackageResolver::new(root),
+        })
+    }
+
+    /// Check the imports of a file against the rules
+    pub fn find_violations(
+        &self,
+        file_path: &Path,
+        source_code: &str,
+        imports: &[Import],
+    ) -> Vec<Violation> {
+        let file_str = file_path.to_string_lossy();
+        let applicable: Vec<&ExternalImportRule> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.applies_to(&file_str))
+            .collect();
+        if applicable.is_empty() {
+            return Vec::new();
+        }
+
+        let mut violations = Vec::new();
+        for import in imports {
+            let Some(package) = self.resolver.package_of(file_path, &import.source) else {
+                continue;
+            };
+            for rule in &applicable {
+                let Some(reason) = rule_breach(rule, &package) else {
+                    continue;
+                };
+                violations.push(Violation {
+                    file_path: file_path.to_path_buf(),
+                    file_content: source_code.to_string(),
+                    offensive_import: import.raw_statement.clone(),
+                    rule: ForbiddenRule {
+                        from: rule.from.clone().unwrap_or_else(|| "**".to_string()),
+                        to: package.name.clone(),
+                        severity: Some(rule.get_severity()),
+                        reason: Some(rule.reason.clone().unwrap_or(reason)),
+                    },
+                    line_number: import.line_number,
+                });
+                break;
+            }
+        }
+        violations
+    }
+}
+
+/// Why `package` breaks `rule`, if it does
+fn rule_breach(rule: &ExternalImportRule, package: &PackageRef) -> Option<String> {
+    let place = rule.from.as_deref().unwrap_or("this project");
+    if rule.deny.iter().any(|p| package_matches(p, package)) {
+        return Some(format!(
+            "{} package '{}' is not allowed in {}",
+            package.ecosystem.as_str(),
+            package.name,
+            place
+        ));
+    }
+    if let Some(allow) = &rule.allow {
+        if !package.builtin && !allow.iter().any(|p| package_matches(p, package)) {
+            return Some(format!(
+                "{} package '{}' is not in the allow-list for {}",
+                package.ecosystem.as_str(),
+                package.name,
+                place
+            ));
+        }
+    }
+    None
+}
+
+/// Check the imports of a file against the `external_imports` rules of `ctx`
+pub fn find_external_import_violations(
+    file_path: &Path,
+    source_code: &str,
+    imports: &[Import],
+    ctx: &LinterContext,
+) -> Vec<Violation> {
+    match ctx.external_imports.as_ref() {
+        Some(rules) => rules.find_violations(file_path, source_code, imports),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_npm_package_names() {
+        assert_eq!(
+            npm_package_name("@nestjs/core/injector").as_deref(),
+            Some("@nestjs/core")
+        );
+        assert_eq!(npm_package_name("lodash/fp").as_deref(), Some("lodash"));
+        assert_eq!(npm_package_name("node:fs/promises").as_deref(), Some("fs"));
+        assert_eq!(npm_package_name("./local"), None);
+        assert_eq!(npm_package_name("@/services/api"), None);
+        assert_eq!(npm_package_name("#internal/db"), None);
+        assert_eq!(npm_package_name("@scope"), None);
+    }
+
+    #[test]
+    fn test_python_names_are_normalized() {
+        assert_eq!(
+            python_top_level("yaml.constructor").as_deref(),
+            Some("yaml")
+        );
+        assert_eq!(python_top_level(".models"), None);
+        let package = PackageRef {
+            ecosystem: Ecosystem::Pip,
+            name: "ruamel_yaml".to_string(),
+            builtin: false,
+        };
+        assert!(package_matches("Ruamel-Yaml", &package));
+        assert!(package_matches("ruamel*", &package));
+    }
+
+    #[test]
+    fn test_composer_namespaces_map_to_packages() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::write(
+            root.join("composer.json"),
+            r#"{ "autoload": { "psr-4": { "App\\": "src/" } } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("composer.lock"),
+            r#"{ "packages": [
+                { "name": "monolog/monolog", "autoload": { "psr-4": { "Monolog\\": "src/Monolog" } } },
+                { "name": "symfony/http-foundation", "autoload": { "psr-4": { "Symfony\\Component\\HttpFoundation\\": "" } } }
+            ] }"#,
+        )
+        .unwrap();
+        let resolver = PackageResolver::new(root);
+        let file = root.join("src/Service.php");
+        let name = |source: &str| resolver.package_of(&file, source).map(|p| p.name);
+
+        assert_eq!(
+            name("Monolog\\Handler\\StreamHandler").as_deref(),
+            Some("monolog/monolog")
+        );
+        assert_eq!(
+            name("Symfony\\Component\\HttpFoundation\\Request").as_deref(),
+            Some("symfony/http-foundation")
+        );
+        assert_eq!(name("Acme\\Sdk\\Client").as_deref(), Some("acme/sdk"));
+        assert_eq!(name("App\\Models\\User"), None);
+        assert_eq!(name("DateTime"), None);
+        assert_eq!(name("vendor/autoload.php"), None);
+    }
+}
@@ -5,11 +5,13 @@
 
 use crate::autofix::Violation;
 use crate::config::LinterContext;
+use crate::security::SecurityFinding;
 use miette::Result;
 use std::path::Path;
 
 pub mod php;
 pub mod python;
+pub mod resolve;
 pub mod typescript;
 
 /// Represents an import statement extracted from source code
@@ -24,7 +26,7 @@ pub struct Import {
 }
 
 /// Language identifier
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
     TypeScript,
     JavaScript,
@@ -53,6 +55,18 @@ impl Language {
             Language::Php => &["php"],
         }
     }
+
+    /// Check a file or import path against a rule pattern with the matcher
+    /// this language's parser uses for `forbidden_imports`
+    pub fn matches_pattern(&self, path: &str, pattern: &str) -> bool {
+        match self {
+            Language::TypeScript | Language::JavaScript => {
+                typescript::matches_pattern(path, pattern)
+            }
+            Language::Python => python::PythonParser::matches_pattern(path, pattern),
+            Language::Php => php::PhpParser::matches_pattern(path, pattern),
+        }
+    }
 }
 
 /// Main trait for language parsers
@@ -102,7 +116,7 @@ pub trait ArchitectParser: Send + Sync {
         _source_code: &str,
         _file_path: &Path,
         _context: &LinterContext,
-    ) -> Result<Vec<Violation>> {
+    ) -> Result<Vec<SecurityFinding>> {
         Ok(Vec::new())
     }
 }
@@ -2,7 +2,8 @@
 
 use super::{ArchitectParser, Import};
 use crate::autofix::Violation;
-use crate::config::{ForbiddenRule, LinterContext};
+use crate::config::LinterContext;
+use crate::security::SecurityFinding;
 use miette::{IntoDiagnostic, Result};
 use std::path::Path;
 use std::sync::Mutex;
@@ -26,7 +27,7 @@ impl PhpParser {
     }
 
     /// Check if a file/import path matches a pattern (PHP-specific)
-    fn matches_pattern(path: &str, pattern: &str) -> bool {
+    pub(crate) fn matches_pattern(path: &str, pattern: &str) -> bool {
         let normalized_path = path.to_lowercase().replace('\\', "/");
         let normalized_pattern = pattern
             .to_lowercase()
@@ -162,23 +163,21 @@ impl ArchitectParser for PhpParser {
                 }
             }
 
-            // PHP-specific rules
-            // Example: Controllers shouldn't import Models directly
-            if file_path_str.contains("controller")
-                && import.source.to_lowercase().contains("model")
-            {
-                violations.push(Violation {
-                    file_path: file_path.to_path_buf(),
-                    file_content: source_code.to_string(),
-                    offensive_import: import.raw_statement.clone(),
-                    rule: ForbiddenRule {
-                        from: "controller".to_string(),
-                        to: "model".to_string(),
-                        severity: Some(crate::config::Severity::Error),
-                        reason: None,
-                    },
-                    line_number: import.line_number,
-                });
+            // Opt-in rules of the architecture pattern preset
+            // Example (MVC): Controllers shouldn't import Models directly
+            for preset in &context.preset_rules {
+                if preset.applies_to(file_path)
+                    && Self::matches_pattern(&file_path_str, &preset.rule.from)
+                    && Self::matches_pattern(&import.source, &preset.rule.to)
+                {
+                    violations.push(Violation {
+                        file_path: file_path.to_path_buf(),
+                        file_content: source_code.to_string(),
+                        offensive_import: import.raw_statement.clone(),
+                        rule: preset.rule.clone(),
+                        line_number: import.line_number,
+                    });
+                }
             }
         }
 
@@ -190,7 +189,7 @@ impl ArchitectParser for PhpParser {
         _source_code: &str,
         _file_path: &Path,
         _context: &LinterContext,
-    ) -> Result<Vec<Violation>> {
+    ) -> Result<Vec<SecurityFinding>> {
         // TODO: Implement CFG building that produces cfg_types::CFG
         // Currently disabled - parsers use cfg::CFG but TaintEngine expects cfg_types::CFG
         Ok(Vec::new())
@@ -2,7 +2,8 @@
 
 use super::{ArchitectParser, Import};
 use crate::autofix::Violation;
-use crate::config::{ForbiddenRule, LinterContext};
+use crate::config::LinterContext;
+use crate::security::SecurityFinding;
 use miette::{IntoDiagnostic, Result};
 use std::path::Path;
 use std::sync::Mutex;
@@ -26,7 +27,7 @@ impl PythonParser {
     }
 
     /// Check if a file/import path matches a pattern (Python-specific)
-    fn matches_pattern(path: &str, pattern: &str) -> bool {
+    pub(crate) fn matches_pattern(path: &str, pattern: &str) -> bool {
         let normalized_path = path.to_lowercase().replace('\\', "/");
         let normalized_pattern = pattern
             .to_lowercase()
@@ -149,21 +150,21 @@ impl ArchitectParser for PythonParser {
                 }
             }
 
-            // Python-specific rules (similar to Controller → Repository in TS)
-            // Example: views shouldn't import models directly
-            if file_path_str.contains("views") && import.source.contains("models") {
-                violations.push(Violation {
-                    file_path: file_path.to_path_buf(),
-                    file_content: source_code.to_string(),
-                    offensive_import: import.raw_statement.clone(),
-                    rule: ForbiddenRule {
-                        from: "views".to_string(),
-                        to: "models".to_string(),
-                        severity: Some(crate::config::Severity::Error),
-                        reason: None,
-                    },
-                    line_number: import.line_number,
-                });
+            // Opt-in rules of the architecture pattern preset
+            // Example (MVC): views shouldn't import models directly
+            for preset in &context.preset_rules {
+                if preset.applies_to(file_path)
+                    && Self::matches_pattern(&file_path_str, &preset.rule.from)
+                    && Self::matches_pattern(&import.source, &preset.rule.to)
+                {
+                    violations.push(Violation {
+                        file_path: file_path.to_path_buf(),
+                        file_content: source_code.to_string(),
+                        offensive_import: import.raw_statement.clone(),
+                        rule: preset.rule.clone(),
+                        line_number: import.line_number,
+                    });
+                }
             }
         }
 
@@ -175,7 +176,7 @@ impl ArchitectParser for PythonParser {
         _source_code: &str,
         _file_path: &Path,
         _context: &LinterContext,
-    ) -> Result<Vec<Violation>> {
+    ) -> Result<Vec<SecurityFinding>> {
         // TODO: Implement CFG building that produces cfg_types::CFG
         // Currently disabled - parsers use cfg::CFG but TaintEngine expects cfg_types::CFG
         Ok(Vec::new())
@@ -0,0 +1,136 @@
+//! Import resolution
+//!
+//! Maps an import specifier to a path inside the project, without a module
+//! resolver: relative imports are joined with the importing file, bare
+//! imports (and Python's dotted modules) are tried against the root, and
+//! `@/` / `~/` aliases are tried against `root/src` and `root`.
+
+use std::path::{Component, Path, PathBuf};
+
+/// Suffixes tried when resolving an extensionless import to a file
+const RESOLVE_SUFFIXES: [&str; 12] = [
+    "",
+    ".ts",
+    ".tsx",
+    ".js",
+    ".jsx",
+    ".py",
+    ".php",
+    "/index.ts",
+    "/index.tsx",
+    "/index.js",
+    "/index.jsx",
+    "/__init__.py",
+];
+
+/// Resolve an import to a path inside `root`.
+///
+/// Relative imports always resolve (to an existing file when one of the usual
+/// extensions or index files matches). Bare imports only resolve when they exist under
+/// `root`, so packages (`react`, `@nestjs/core`) are never mistaken for
+/// project files. Returns None for anything outside `root`.
+pub fn resolve_import(file_path: &Path, source: &str, root: &Path) -> Option<PathBuf> {
+    let python = file_path.extension().is_some_and(|e| e == "py");
+    let dots = source.len() - source.trim_start_matches('.').len();
+    let relative = dots > 0;
+
+    let bases: Vec<PathBuf> = if python {
+        let module = source.trim_start_matches('.').replace('.', "/");
+        if relative {
+            let mut dir = file_path.parent()?.to_path_buf();
+            for _ in 1..dots {
+                dir = dir.parent()?.to_path_buf();
+            }
+            vec![dir.join(module)]
+        } else {
+            vec![root.join(module)]
+        }
+    } else if relative {
+        vec![file_path.parent()?.join(source)]
+    } else if let Some(rest) = source
+        .strip_prefix("@/")
+        .or_else(|| source.strip_prefix("~/"))
+    {
+        vec![root.join("src").join(rest), root.join(rest)]
+    } else {
+        vec![root.join(source)]
+    };
+
+    for base in bases {
+        let base = normalize(&base);
+        if !base.starts_with(root) {
+            continue;
+        }
+        let existing = RESOLVE_SUFFIXES
+            .iter()
+            .map(|suffix| PathBuf::from(format!("{}{}", base.display(), suffix)))
+            .find(|candidate| candidate.is_file());
+        match existing {
+            Some(path) => return Some(path),
+            None if relative || base.is_dir() => return Some(base),
+            None => {}
+        }
+    }
+    None
+}
+
+/// Collapse `.` and `..` without touching the filesystem
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other.as_os_str()),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_resolve_relative_bare_and_alias() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("src/billing")).unwrap();
+        std::fs::write(root.join("src/billing/index.ts"), "").unwrap();
+        std::fs::write(root.join("src/billing/invoice.ts"), "").unwrap();
+        let importer = root.join("src/app/main.ts");
+
+        assert_eq!(
+            resolve_import(&importer, "../billing", root),
+            Some(root.join("src/billing/index.ts"))
+        );
+        assert_eq!(
+            resolve_import(&importer, "@/billing/invoice", root),
+            Some(root.join("src/billing/invoice.ts"))
+        );
+        assert_eq!(resolve_import(&importer, "react", root), None);
+        assert_eq!(resolve_import(&importer, "../../../outside", root), None);
+    }
+
+    #[test]
+    fn test_resolve_python_modules() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("app/billing")).unwrap();
+        std::fs::write(root.join("app/billing/__init__.py"), "").unwrap();
+        std::fs::write(root.join("app/billing/models.py"), "").unwrap();
+        let importer = root.join("app/users/views.py");
+
+        assert_eq!(
+            resolve_import(&importer, "app.billing.models", root),
+            Some(root.join("app/billing/models.py"))
+        );
+        assert_eq!(
+            resolve_import(&importer, "..billing", root),
+            Some(root.join("app/billing/__init__.py"))
+        );
+    }
+}
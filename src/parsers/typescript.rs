@@ -3,6 +3,7 @@
 use super::{ArchitectParser, Import};
 use crate::autofix::Violation;
 use crate::config::{ForbiddenRule, LinterContext};
+use crate::security::SecurityFinding;
 use miette::Result;
 use std::path::Path;
 use std::sync::Mutex;
@@ -93,7 +94,7 @@ impl ArchitectParser for TypeScriptParser {
         _source_code: &str,
         _file_path: &Path,
         _context: &LinterContext,
-    ) -> Result<Vec<Violation>> {
+    ) -> Result<Vec<SecurityFinding>> {
         // TEMP: Taint analysis disabled due to high false positive rate
         // The TaintEngine uses overly broad substring matching:
         // - Any function with "execute", "query", "eval" triggers as sink
@@ -395,51 +396,6 @@ pub fn matches_forbidden_rule(file_path: &str, import_source: &str, rule: &Forbi
     file_matches && import_matches
 }
 
-/// Check if a controller file imports from a repository (MVC pattern violation)
-///
-/// This is a special-case rule for common MVC architecture violations.
-/// Checks if the import path contains "repository" (with or without a dot prefix).
-///
-/// # Arguments
-/// * `file_path` - The path of the file to check
-/// * `import_source` - The import path being checked
-///
-/// # Returns
-/// true if this is a controller importing a repository, false otherwise
-///
-/// # Examples
-/// ```
-/// use architect_linter_pro::parsers::typescript::is_controller_to_repository_violation;
-///
-/// // Matches: ../repository/user
-/// assert!(is_controller_to_repository_violation(
-///     "src/controller/user.controller.ts",
-///     "../repository/user"
-/// ));
-///
-/// // Matches: ./user.repository
-/// assert!(is_controller_to_repository_violation(
-///     "src/controller/user.controller.ts",
-///     "./user.repository"
-/// ));
-///
-/// // Doesn't match: not a repository import
-/// assert!(!is_controller_to_repository_violation(
-///     "src/controller/user.controller.ts",
-///     "../service/user"
-/// ));
-/// ```
-pub fn is_controller_to_repository_violation(file_path: &str, import_source: &str) -> bool {
-    let normalized_file_path = normalize_path(file_path);
-    let normalized_import = normalize_path(import_source);
-
-    let is_controller = normalized_file_path.contains("controller");
-    let is_repository =
-        normalized_import.contains("repository") || normalized_import.contains(".repository");
-
-    is_controller && is_repository
-}
-
 /// Create violations for a matching rule
 ///
 /// # Arguments
@@ -497,19 +453,18 @@ pub fn find_violations_in_imports(
             }
         }
 
-        // Check for controller-to-repository violations
-        if is_controller_to_repository_violation(&file_path_str, &import.source) {
-            violations.push(create_violation(
-                file_path,
-                source_code,
-                import,
-                ForbiddenRule {
-                    from: "controller".to_string(),
-                    to: ".repository".to_string(),
-                    severity: Some(crate::config::Severity::Error),
-                    reason: None,
-                },
-            ));
+        // Check against the opt-in rules of the architecture pattern preset
+        for preset in &context.preset_rules {
+            if preset.applies_to(file_path)
+                && matches_forbidden_rule(&file_path_str, &import.source, &preset.rule)
+            {
+                violations.push(create_violation(
+                    file_path,
+                    source_code,
+                    import,
+                    preset.rule.clone(),
+                ));
+            }
         }
     }
 
@@ -768,39 +723,6 @@ mod tests {
         ));
     }
 
-    // =========================================================================
-    // is_controller_to_repository_violation tests
-    // =========================================================================
-
-    #[test]
-    fn test_is_controller_to_repository_violation_true() {
-        assert!(is_controller_to_repository_violation(
-            "src/controller/user.controller.ts",
-            "../repository/user"
-        ));
-
-        assert!(is_controller_to_repository_violation(
-            "CONTROLLER/User.Controller.ts",
-            "./user.repository"
-        ));
-    }
-
-    #[test]
-    fn test_is_controller_to_repository_violation_false_not_controller() {
-        assert!(!is_controller_to_repository_violation(
-            "src/service/user.service.ts",
-            "../repository/user"
-        ));
-    }
-
-    #[test]
-    fn test_is_controller_to_repository_violation_false_not_repository() {
-        assert!(!is_controller_to_repository_violation(
-            "src/controller/user.controller.ts",
-            "../service/user"
-        ));
-    }
-
     // =========================================================================
     // Integration-style tests
     // =========================================================================
@@ -832,6 +754,13 @@ mod tests {
             }],
             ignored_paths: vec![],
             ai_configs: vec![],
+            preset_rules: crate::config::presets::resolve(
+                &crate::config::ArchPattern::MVC,
+                &crate::config::PresetConfig {
+                    enabled: true,
+                    ..Default::default()
+                },
+            ),
             ..Default::default()
         };
 
@@ -844,10 +773,33 @@ mod tests {
 
         // Should have 4 violations:
         // - 2 from the configured forbidden rule (user.repository and product.repository)
-        // - 2 from the controller-to-repository rule (same imports)
+        // - 2 from the MVC preset controller-to-repository rule (same imports)
         assert_eq!(violations.len(), 4);
     }
 
+    #[test]
+    fn test_find_violations_in_imports_presets_disabled_by_default() {
+        let imports = vec![PureImport {
+            source: "./user.repository".to_string(),
+            line_number: 1,
+            raw_statement: "import { UserRepository } from './user.repository';".to_string(),
+        }];
+
+        let context = LinterContext {
+            pattern: crate::config::ArchPattern::Hexagonal,
+            ..Default::default()
+        };
+
+        let violations = find_violations_in_imports(
+            Path::new("src/infrastructure/http/user.controller.ts"),
+            "source code",
+            &imports,
+            &context,
+        );
+
+        assert!(violations.is_empty());
+    }
+
     #[test]
     fn test_find_violations_in_imports_no_violations() {
         let imports = vec![PureImport {
@@ -1,21 +1,51 @@
 //! Report generation for Architect Linter v4.0
 //!
-//! This module provides JSON and Markdown report export functionality.
+//! This module provides JSON, Markdown, Code Climate, SARIF, JUnit XML, Checkstyle
+//! reviewdog (rdjson) and HTML report export functionality.
 
-use crate::analysis_result::AnalysisResult;
+use crate::analysis_result::{AnalysisResult, CategorizedViolation, ViolationCategory};
 use crate::cli::ReportFormat;
+use crate::config::ForbiddenRule;
+use crate::source_span;
 use miette::{IntoDiagnostic, Result};
-use serde_json::json;
+use serde_json::{json, Value};
 use std::fs;
 use std::io::{self, Write};
 use std::path::Path;
 
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const CIRCULAR_RULE_ID: &str = "architect/circular-dependency";
+const LONG_FUNCTION_RULE_ID: &str = "architect/long-function";
+
 /// Generate a report in the specified format
 pub fn generate_report(result: &AnalysisResult, format: ReportFormat) -> String {
     match format {
         ReportFormat::Json => to_json(result),
         ReportFormat::Markdown => to_markdown(result),
         ReportFormat::CodeClimate => to_code_climate(result),
+        ReportFormat::Sarif => to_sarif(result),
+        ReportFormat::Junit => to_junit(result),
+        ReportFormat::Checkstyle => to_checkstyle(result),
+        ReportFormat::Rdjson => to_rdjson(result),
+        ReportFormat::Html => crate::output::html::HtmlReporter::render(result),
+    }
+}
+
+/// Generate a report for a project on disk.
+///
+/// Same as `generate_report`, but formats that can show the score history
+/// (HTML) read it from `.architect/history.jsonl` under `project_root`.
+pub fn generate_project_report(
+    result: &AnalysisResult,
+    format: ReportFormat,
+    project_root: &Path,
+) -> String {
+    match format {
+        ReportFormat::Html => {
+            let history = crate::history::load(project_root).unwrap_or_default();
+            crate::output::html::HtmlReporter::render_with_history(result, &history)
+        }
+        _ => generate_report(result, format),
     }
 }
 
@@ -30,6 +60,7 @@ pub fn to_json(result: &AnalysisResult) -> String {
             "name": result.project_name,
             "pattern": result.pattern_display(),
             "files_analyzed": result.files_analyzed,
+            "generated_files": result.generated_files,
         },
         "health_score": health_score.map(|s| json!({
             "total": s.total,
@@ -58,8 +89,12 @@ pub fn to_json(result: &AnalysisResult) -> String {
                     "to": cv.violation.rule.to,
                 },
                 "import": cv.violation.offensive_import,
+                "blame": cv.blame,
+                "owners": cv.owners,
             })
         }).collect::<Vec<_>>(),
+        "attribution": crate::blame::has_attribution(result)
+            .then(|| crate::blame::attribution(result)),
         "circular_dependencies": result.circular_dependencies.iter().map(|cd| {
             json!({
                 "cycle": cd.cycle,
@@ -166,12 +201,57 @@ pub fn to_markdown(result: &AnalysisResult) -> String {
                 cv.violation.rule.from, cv.violation.rule.to
             ));
             md.push_str(&format!(
-                "- **Import:** `{}`\n\n",
+                "- **Import:** `{}`\n",
                 cv.violation.offensive_import
             ));
+            if let Some(ref blame) = cv.blame {
+                md.push_str(&format!(
+                    "- **Blame:** {} ({}, {})\n",
+                    blame.author,
+                    &blame.commit[..7.min(blame.commit.len())],
+                    blame.date.format("%Y-%m-%d")
+                ));
+            }
+            if !cv.owners.is_empty() {
+                md.push_str(&format!("- **Owners:** {}\n", cv.owners.join(", ")));
+            }
+            md.push('\n');
         }
     }
 
+    // Ownership
+    if crate::blame::has_attribution(result) {
+        md.push_str("## Ownership\n\n");
+        md.push_str("| Team | Blocked | Warnings | Total |\n");
+        md.push_str("|------|---------|----------|-------|\n");
+        let mut teams: Vec<_> = result.violations_by_team().into_iter().collect();
+        teams.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then(a.0.cmp(b.0)));
+        for (team, violations) in &teams {
+            let count = |category| {
+                violations
+                    .iter()
+                    .filter(|cv| cv.category == category)
+                    .count()
+            };
+            md.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                team,
+                count(ViolationCategory::Blocked),
+                count(ViolationCategory::Warning),
+                violations.len()
+            ));
+        }
+
+        if result.violations.iter().any(|cv| cv.blame.is_some()) {
+            md.push_str("\n| Author | Violations |\n");
+            md.push_str("|--------|------------|\n");
+            for (author, count) in crate::blame::attribution(result).top_authors() {
+                md.push_str(&format!("| {} | {} |\n", author, count));
+            }
+        }
+        md.push('\n');
+    }
+
     // Circular Dependencies
     if !result.circular_dependencies.is_empty() {
         md.push_str("## Circular Dependencies\n\n");
@@ -271,6 +351,629 @@ pub fn to_code_climate(result: &AnalysisResult) -> String {
     serde_json::to_string_pretty(&reports).unwrap_or_else(|_| "[]".to_string())
 }
 
+/// Export analysis result to SARIF 2.1.0 (GitHub code scanning, Azure DevOps, IDEs)
+pub fn to_sarif(result: &AnalysisResult) -> String {
+    let mut rules: Vec<Value> = Vec::new();
+    let mut rule_ids: Vec<String> = Vec::new();
+    let mut results = Vec::new();
+
+    // 1. Forbidden import violations and security findings
+    for cv in &result.violations {
+        let rule_id = rule_id(cv);
+        let rule_index = match rule_ids.iter().position(|id| *id == rule_id) {
+            Some(index) => index,
+            None => {
+                rules.push(sarif_rule_descriptor(&rule_id, cv));
+                rule_ids.push(rule_id.clone());
+                rule_ids.len() - 1
+            }
+        };
+
+        let v = &cv.violation;
+        let mut sarif_result = json!({
+            "ruleId": rule_id,
+            "ruleIndex": rule_index,
+            "level": sarif_level(cv.category),
+            "message": {
+                "text": format!("'{}' cannot import from '{}' (Import: {})",
+                    v.rule.from, v.rule.to, v.offensive_import),
+            },
+            "locations": [sarif_location(
+                &v.file_path.to_string_lossy(),
+                sarif_import_region(&v.file_content, v.line_number, &v.offensive_import),
+            )],
+            "partialFingerprints": {
+                "primaryLocationLineHash": format!("{:x}", md5::compute(format!("{}:{}:{}",
+                    v.file_path.display(), v.line_number, v.offensive_import))),
+            },
+        });
+        if let Some(score) = cv.security_severity {
+            sarif_result["properties"] = json!({ "security-severity": format!("{:.1}", score) });
+        }
+        results.push(sarif_result);
+    }
+
+    // 2. Circular dependencies: one result per cycle, every file as related location
+    if !result.circular_dependencies.is_empty() {
+        rules.push(json!({
+            "id": CIRCULAR_RULE_ID,
+            "name": "CircularDependency",
+            "shortDescription": { "text": "Circular dependency between modules" },
+            "fullDescription": { "text": "Modules that import each other in a cycle cannot be changed, tested or deployed independently." },
+            "help": {
+                "text": "Break the cycle by extracting the shared code into a new module or inverting one of the dependencies through an interface.",
+            },
+            "defaultConfiguration": { "level": "error" },
+            "properties": { "tags": ["architecture", "circular-dependency"] },
+        }));
+        let rule_index = rules.len() - 1;
+
+        for cd in &result.circular_dependencies {
+            let Some(first_file) = cd.cycle.first() else {
+                continue;
+            };
+            let related: Vec<Value> = cd
+                .cycle
+                .iter()
+                .enumerate()
+                .map(|(i, file)| {
+                    let mut location = sarif_location(file, json!({ "startLine": 1 }));
+                    location["id"] = json!(i);
+                    location["message"] = json!({
+                        "text": format!("Step {} of {} in the cycle", i + 1, cd.cycle.len()),
+                    });
+                    location
+                })
+                .collect();
+
+            results.push(json!({
+                "ruleId": CIRCULAR_RULE_ID,
+                "ruleIndex": rule_index,
+                "level": "error",
+                "message": { "text": format!("Circular dependency detected: {}", cd.description) },
+                "locations": [sarif_location(first_file, json!({ "startLine": 1 }))],
+                "relatedLocations": related,
+                "partialFingerprints": {
+                    "primaryLocationLineHash": format!("{:x}", md5::compute(format!("circular:{}", cd.description))),
+                },
+            }));
+        }
+    }
+
+    // 3. Long functions
+    if !result.long_functions.is_empty() {
+        rules.push(json!({
+            "id": LONG_FUNCTION_RULE_ID,
+            "name": "LongFunction",
+            "shortDescription": { "text": "Function exceeds the configured line limit" },
+            "fullDescription": { "text": "Functions longer than max_lines_per_function are harder to read, test and review." },
+            "help": {
+                "text": "Split the function into smaller, well-named helpers or raise max_lines_per_function in architect.json.",
+            },
+            "defaultConfiguration": { "level": "warning" },
+            "properties": { "tags": ["maintainability", "complexity"] },
+        }));
+        let rule_index = rules.len() - 1;
+
+        for lf in &result.long_functions {
+            results.push(json!({
+                "ruleId": LONG_FUNCTION_RULE_ID,
+                "ruleIndex": rule_index,
+                "level": "warning",
+                "message": {
+                    "text": format!("Long function '{}' ({} lines) exceeds threshold ({} lines)",
+                        lf.name, lf.lines, lf.threshold),
+                },
+                "locations": [sarif_location(
+                    &lf.file_path.to_string_lossy(),
+                    json!({
+                        "startLine": lf.line_start.max(1),
+                        "endLine": (lf.line_start + lf.lines.saturating_sub(1)).max(1),
+                    }),
+                )],
+                "partialFingerprints": {
+                    "primaryLocationLineHash": format!("{:x}", md5::compute(format!("{}:{}:{}",
+                        lf.file_path.display(), lf.line_start, lf.name))),
+                },
+            }));
+        }
+    }
+
+    let report = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "architect-linter-pro",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                }
+            },
+            "originalUriBaseIds": {
+                "%SRCROOT%": { "description": { "text": "Project root" } }
+            },
+            "results": results,
+        }]
+    });
+
+    serde_json::to_string_pretty(&report).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Stable rule id for a violation (SARIF, rdjson, checkstyle)
+fn rule_id(cv: &CategorizedViolation) -> String {
+    let rule = &cv.violation.rule;
+    if cv.is_security() {
+        format!("architect/security/{}", slug(&rule.to))
+    } else {
+        format!(
+            "architect/forbidden-import/{}--{}",
+            slug(&rule.from),
+            slug(&rule.to)
+        )
+    }
+}
+
+/// SARIF `reportingDescriptor` for a forbidden-import or security rule
+fn sarif_rule_descriptor(id: &str, cv: &CategorizedViolation) -> Value {
+    let rule = &cv.violation.rule;
+    let reason = rule_reason(rule);
+    let help = cv.suggestion.clone().unwrap_or_else(|| {
+        format!(
+            "Code in '{}' must not depend on '{}'. Depend on an abstraction owned by the inner layer or move the code to a layer that is allowed to use it.",
+            rule.from, rule.to
+        )
+    });
+
+    let mut descriptor = json!({
+        "id": id,
+        "name": if cv.is_security() { "SecurityFinding" } else { "ForbiddenImport" },
+        "shortDescription": { "text": format!("'{}' cannot import from '{}'", rule.from, rule.to) },
+        "fullDescription": { "text": reason },
+        "help": { "text": help },
+        "defaultConfiguration": { "level": sarif_level(cv.category) },
+        "properties": {
+            "tags": [if cv.is_security() { "security" } else { "architecture" }],
+        },
+    });
+    if let Some(score) = cv.security_severity {
+        descriptor["properties"]["security-severity"] = json!(format!("{:.1}", score));
+    }
+    descriptor
+}
+
+fn rule_reason(rule: &ForbiddenRule) -> String {
+    rule.reason.clone().unwrap_or_else(|| {
+        format!(
+            "Files matching '{}' are not allowed to import files matching '{}'.",
+            rule.from, rule.to
+        )
+    })
+}
+
+fn sarif_level(category: ViolationCategory) -> &'static str {
+    match category {
+        ViolationCategory::Blocked => "error",
+        ViolationCategory::Warning => "warning",
+        ViolationCategory::Info => "note",
+    }
+}
+
+/// Region covering the offending import statement on its line
+fn sarif_import_region(content: &str, line: usize, import: &str) -> Value {
+    if content.is_empty() || line == 0 {
+        return json!({ "startLine": line.max(1) });
+    }
+    let span = source_span::span_for_import(content, line, import);
+    let (start_line, start_column) = source_span::offset_to_line_col(content, span.offset());
+    let (end_line, end_column) =
+        source_span::offset_to_line_col(content, span.offset() + span.len());
+    json!({
+        "startLine": start_line,
+        "startColumn": start_column,
+        "endLine": end_line,
+        "endColumn": end_column,
+    })
+}
+
+/// SARIF `physicalLocation`; relative paths are resolved against `%SRCROOT%`
+fn sarif_location(path: &str, region: Value) -> Value {
+    let artifact = match sarif_relative_uri(path) {
+        Some(uri) => json!({ "uri": uri, "uriBaseId": "%SRCROOT%" }),
+        None => json!({ "uri": format!("file://{}", path.replace('\\', "/")) }),
+    };
+    json!({
+        "physicalLocation": {
+            "artifactLocation": artifact,
+            "region": region,
+        }
+    })
+}
+
+/// Path relative to the working directory (the repository root in CI), if possible
+fn sarif_relative_uri(path: &str) -> Option<String> {
+    let p = Path::new(path);
+    let relative = if p.is_absolute() {
+        let cwd = std::env::current_dir().ok()?;
+        p.strip_prefix(&cwd).ok()?.to_path_buf()
+    } else {
+        p.to_path_buf()
+    };
+    Some(
+        relative
+            .to_string_lossy()
+            .replace('\\', "/")
+            .trim_start_matches("./")
+            .to_string(),
+    )
+}
+
+fn slug(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_lowercase());
+        } else if !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    out.trim_matches('-').to_string()
+}
+
+/// Export analysis result to JUnit XML (Jenkins, GitLab test reports)
+///
+/// Each violated rule is a testcase of the `architecture` suite; cycles and
+/// long functions get their own suites. Health score components are recorded
+/// as properties on every suite.
+pub fn to_junit(result: &AnalysisResult) -> String {
+    let properties = junit_properties(result);
+    let timestamp = result.timestamp.format("%Y-%m-%dT%H:%M:%S").to_string();
+
+    // 1. Forbidden imports and security findings, grouped by rule
+    let mut rules: Vec<(String, Vec<&CategorizedViolation>)> = Vec::new();
+    for cv in &result.violations {
+        let name = format!("{} -> {}", cv.violation.rule.from, cv.violation.rule.to);
+        match rules.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, group)) => group.push(cv),
+            None => rules.push((name, vec![cv])),
+        }
+    }
+    let mut rule_cases = Vec::new();
+    for (name, group) in &rules {
+        let details: Vec<String> = group
+            .iter()
+            .map(|cv| {
+                format!(
+                    "{}:{}: {} (Import: {})",
+                    cv.violation.file_path.display(),
+                    cv.violation.line_number,
+                    rule_reason(&cv.violation.rule),
+                    cv.violation.offensive_import
+                )
+            })
+            .collect();
+        rule_cases.push(junit_failed_case(
+            "architect.rules",
+            name,
+            &format!("{} violation(s) of '{}'", group.len(), name),
+            group[0].category.as_str(),
+            &details.join("\n"),
+        ));
+    }
+    if rule_cases.is_empty() {
+        rule_cases.push(junit_passed_case("architect.rules", "forbidden imports"));
+    }
+
+    // 2. Circular dependencies
+    let mut cycle_cases: Vec<String> = result
+        .circular_dependencies
+        .iter()
+        .map(|cd| {
+            junit_failed_case(
+                "architect.circular",
+                &cd.cycle.join(" -> "),
+                &format!("Circular dependency detected: {}", cd.description),
+                "circular-dependency",
+                &cd.cycle.join("\n"),
+            )
+        })
+        .collect();
+    if cycle_cases.is_empty() {
+        cycle_cases.push(junit_passed_case(
+            "architect.circular",
+            "no circular dependencies",
+        ));
+    }
+
+    // 3. Long functions
+    let mut long_function_cases: Vec<String> = result
+        .long_functions
+        .iter()
+        .map(|lf| {
+            junit_failed_case(
+                "architect.complexity",
+                &format!("{}::{}", lf.file_path.display(), lf.name),
+                &format!(
+                    "Long function '{}' ({} lines) exceeds threshold ({} lines)",
+                    lf.name, lf.lines, lf.threshold
+                ),
+                "long-function",
+                &format!("{}:{}", lf.file_path.display(), lf.line_start),
+            )
+        })
+        .collect();
+    if long_function_cases.is_empty() {
+        long_function_cases.push(junit_passed_case("architect.complexity", "function length"));
+    }
+
+    let suites = [
+        ("architecture", rule_cases, rules.len()),
+        (
+            "circular-dependencies",
+            cycle_cases,
+            result.circular_dependencies.len(),
+        ),
+        (
+            "long-functions",
+            long_function_cases,
+            result.long_functions.len(),
+        ),
+    ];
+    let total_tests: usize = suites.iter().map(|(_, cases, _)| cases.len()).sum();
+    let total_failures: usize = suites.iter().map(|(_, _, failures)| failures).sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"architect-linter-pro\" tests=\"{}\" failures=\"{}\" errors=\"0\" time=\"0\">\n",
+        total_tests, total_failures
+    ));
+    for (name, cases, failures) in &suites {
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"0\" timestamp=\"{}\">\n",
+            name,
+            cases.len(),
+            failures,
+            timestamp
+        ));
+        xml.push_str(&properties);
+        for case in cases {
+            xml.push_str(case);
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn junit_properties(result: &AnalysisResult) -> String {
+    let mut props = vec![
+        ("project".to_string(), result.project_name.clone()),
+        ("pattern".to_string(), result.pattern_display().to_string()),
+        (
+            "files_analyzed".to_string(),
+            result.files_analyzed.to_string(),
+        ),
+    ];
+    if let Some(ref score) = result.health_score {
+        props.push(("health_score.total".to_string(), score.total.to_string()));
+        props.push((
+            "health_score.grade".to_string(),
+            score.grade.as_str().to_string(),
+        ));
+        props.push((
+            "health_score.layer_isolation".to_string(),
+            score.components.layer_isolation.to_string(),
+        ));
+        props.push((
+            "health_score.circular_deps".to_string(),
+            score.components.circular_deps.to_string(),
+        ));
+        props.push((
+            "health_score.complexity".to_string(),
+            score.components.complexity.to_string(),
+        ));
+        props.push((
+            "health_score.violations".to_string(),
+            score.components.violations.to_string(),
+        ));
+    }
+
+    let mut xml = String::from("    <properties>\n");
+    for (name, value) in props {
+        xml.push_str(&format!(
+            "      <property name=\"{}\" value=\"{}\"/>\n",
+            xml_escape(&name),
+            xml_escape(&value)
+        ));
+    }
+    xml.push_str("    </properties>\n");
+    xml
+}
+
+fn junit_passed_case(classname: &str, name: &str) -> String {
+    format!(
+        "    <testcase classname=\"{}\" name=\"{}\" time=\"0\"/>\n",
+        xml_escape(classname),
+        xml_escape(name)
+    )
+}
+
+fn junit_failed_case(classname: &str, name: &str, message: &str, kind: &str, body: &str) -> String {
+    format!(
+        "    <testcase classname=\"{}\" name=\"{}\" time=\"0\">\n      <failure message=\"{}\" type=\"{}\">{}</failure>\n    </testcase>\n",
+        xml_escape(classname),
+        xml_escape(name),
+        xml_escape(message),
+        xml_escape(kind),
+        xml_escape(body)
+    )
+}
+
+/// A single finding flattened for line-oriented formats (Checkstyle, rdjson)
+struct Finding {
+    path: String,
+    /// 1-based `(line, column)` start and end; columns are UTF-8 byte offsets
+    start: (usize, usize),
+    end: Option<(usize, usize)>,
+    severity: ViolationCategory,
+    message: String,
+    rule_id: String,
+}
+
+/// Flatten violations, cycles and long functions into findings
+fn collect_findings(result: &AnalysisResult) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for cv in &result.violations {
+        let v = &cv.violation;
+        let (start, end) = match import_range(&v.file_content, v.line_number, &v.offensive_import) {
+            Some((start, end)) => (start, Some(end)),
+            None => ((v.line_number.max(1), 1), None),
+        };
+        findings.push(Finding {
+            path: v.file_path.to_string_lossy().to_string(),
+            start,
+            end,
+            severity: cv.category,
+            message: format!(
+                "{} ('{}' cannot import from '{}', Import: {})",
+                rule_reason(&v.rule),
+                v.rule.from,
+                v.rule.to,
+                v.offensive_import
+            ),
+            rule_id: rule_id(cv),
+        });
+    }
+
+    for cd in &result.circular_dependencies {
+        if let Some(first_file) = cd.cycle.first() {
+            findings.push(Finding {
+                path: first_file.clone(),
+                start: (1, 1),
+                end: None,
+                severity: ViolationCategory::Blocked,
+                message: format!("Circular dependency detected: {}", cd.description),
+                rule_id: CIRCULAR_RULE_ID.to_string(),
+            });
+        }
+    }
+
+    for lf in &result.long_functions {
+        findings.push(Finding {
+            path: lf.file_path.to_string_lossy().to_string(),
+            start: (lf.line_start.max(1), 1),
+            end: None,
+            severity: ViolationCategory::Warning,
+            message: format!(
+                "Long function '{}' ({} lines) exceeds threshold ({} lines)",
+                lf.name, lf.lines, lf.threshold
+            ),
+            rule_id: LONG_FUNCTION_RULE_ID.to_string(),
+        });
+    }
+
+    findings
+}
+
+/// Byte-column range of the offending import, computed with `span_for_import`
+fn import_range(
+    content: &str,
+    line: usize,
+    import: &str,
+) -> Option<((usize, usize), (usize, usize))> {
+    if content.is_empty() || line == 0 {
+        return None;
+    }
+    let span = source_span::span_for_import(content, line, import);
+    let line_start = source_span::line_to_byte_offset(content, line);
+    let start_column = span.offset().saturating_sub(line_start) + 1;
+    Some(((line, start_column), (line, start_column + span.len())))
+}
+
+/// Export analysis result to Checkstyle XML
+pub fn to_checkstyle(result: &AnalysisResult) -> String {
+    let mut files: Vec<(String, Vec<Finding>)> = Vec::new();
+    for finding in collect_findings(result) {
+        match files.iter_mut().find(|(path, _)| *path == finding.path) {
+            Some((_, group)) => group.push(finding),
+            None => files.push((finding.path.clone(), vec![finding])),
+        }
+    }
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<checkstyle version=\"8.0\">\n");
+    for (path, group) in &files {
+        xml.push_str(&format!("  <file name=\"{}\">\n", xml_escape(path)));
+        for finding in group {
+            let severity = match finding.severity {
+                ViolationCategory::Blocked => "error",
+                ViolationCategory::Warning => "warning",
+                ViolationCategory::Info => "info",
+            };
+            xml.push_str(&format!(
+                "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"{}\"/>\n",
+                finding.start.0,
+                finding.start.1,
+                severity,
+                xml_escape(&finding.message),
+                xml_escape(&finding.rule_id)
+            ));
+        }
+        xml.push_str("  </file>\n");
+    }
+    xml.push_str("</checkstyle>\n");
+    xml
+}
+
+/// Export analysis result to reviewdog Diagnostic Format (rdjson)
+pub fn to_rdjson(result: &AnalysisResult) -> String {
+    let diagnostics: Vec<Value> = collect_findings(result)
+        .into_iter()
+        .map(|finding| {
+            let mut range = json!({
+                "start": { "line": finding.start.0, "column": finding.start.1 },
+            });
+            if let Some(end) = finding.end {
+                range["end"] = json!({ "line": end.0, "column": end.1 });
+            }
+            json!({
+                "message": finding.message,
+                "location": {
+                    "path": finding.path,
+                    "range": range,
+                },
+                "severity": match finding.severity {
+                    ViolationCategory::Blocked => "ERROR",
+                    ViolationCategory::Warning => "WARNING",
+                    ViolationCategory::Info => "INFO",
+                },
+                "code": { "value": finding.rule_id },
+            })
+        })
+        .collect();
+
+    let report = json!({
+        "source": {
+            "name": "architect-linter-pro",
+            "url": env!("CARGO_PKG_REPOSITORY"),
+        },
+        "diagnostics": diagnostics,
+    });
+
+    serde_json::to_string_pretty(&report).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Escape text for XML attributes and content
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
 /// Write report to file
 pub fn write_report(content: &str, path: &Path) -> Result<()> {
     // Create parent directories if needed
@@ -320,4 +1023,148 @@ mod tests {
         assert!(md.contains("test-project"));
         assert!(md.contains("Hexagonal"));
     }
+
+    fn sarif_test_result() -> AnalysisResult {
+        use crate::analysis_result::LongFunction;
+        use crate::autofix::Violation;
+        use crate::circular::CircularDependency;
+        use std::path::PathBuf;
+
+        let mut result = AnalysisResult::new("test-project".to_string(), ArchPattern::Hexagonal);
+        let content = "import { Db } from '../infrastructure/db';\nexport class User {}\n";
+        let violation = Violation {
+            file_path: PathBuf::from("src/domain/user.ts"),
+            file_content: content.to_string(),
+            offensive_import: "'../infrastructure/db'".to_string(),
+            rule: ForbiddenRule {
+                from: "/domain/".to_string(),
+                to: "/infrastructure/".to_string(),
+                severity: None,
+                reason: Some("Domain must stay pure".to_string()),
+            },
+            line_number: 1,
+        };
+        result.add_violation(CategorizedViolation::new(
+            violation.clone(),
+            ViolationCategory::Blocked,
+        ));
+        result.add_violation(
+            CategorizedViolation::new(violation, ViolationCategory::Blocked)
+                .with_security_severity(9.5),
+        );
+        result.add_circular_dependency(CircularDependency {
+            cycle: vec!["src/a.ts".to_string(), "src/b.ts".to_string()],
+            description: "src/a.ts -> src/b.ts -> src/a.ts".to_string(),
+        });
+        result.add_long_function(LongFunction {
+            file_path: PathBuf::from("src/big.ts"),
+            name: "doEverything".to_string(),
+            line_start: 10,
+            lines: 80,
+            threshold: 40,
+        });
+        result
+    }
+
+    #[test]
+    fn test_sarif_report_structure() {
+        let sarif: Value = serde_json::from_str(&to_sarif(&sarif_test_result())).unwrap();
+        assert_eq!(sarif["version"], "2.1.0");
+
+        let run = &sarif["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(rules.len(), 4);
+        assert_eq!(results.len(), 4);
+
+        let forbidden = &rules[0];
+        assert_eq!(
+            forbidden["id"],
+            "architect/forbidden-import/domain--infrastructure"
+        );
+        assert_eq!(
+            forbidden["fullDescription"]["text"],
+            "Domain must stay pure"
+        );
+
+        let region = &results[0]["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 1);
+        assert_eq!(region["startColumn"], 20);
+        assert_eq!(region["endColumn"], 42);
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "src/domain/user.ts"
+        );
+    }
+
+    #[test]
+    fn test_sarif_security_and_cycles() {
+        let sarif: Value = serde_json::from_str(&to_sarif(&sarif_test_result())).unwrap();
+        let run = &sarif["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        let results = run["results"].as_array().unwrap();
+
+        assert_eq!(rules[1]["properties"]["security-severity"], "9.5");
+        assert_eq!(results[1]["properties"]["security-severity"], "9.5");
+
+        let cycle = results
+            .iter()
+            .find(|r| r["ruleId"] == CIRCULAR_RULE_ID)
+            .unwrap();
+        assert_eq!(cycle["relatedLocations"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_junit_report_structure() {
+        let mut result = sarif_test_result();
+        result.health_score = Some(HealthScore::new(ScoreComponents {
+            layer_isolation: 70,
+            circular_deps: 80,
+            complexity: 90,
+            violations: 60,
+        }));
+
+        let xml = to_junit(&result);
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("<testsuite name=\"architecture\" tests=\"1\" failures=\"1\""));
+        assert!(
+            xml.contains("<testsuite name=\"circular-dependencies\" tests=\"1\" failures=\"1\"")
+        );
+        assert!(xml.contains("<testsuite name=\"long-functions\" tests=\"1\" failures=\"1\""));
+        assert!(xml.contains("src/domain/user.ts:1: Domain must stay pure"));
+        assert!(xml.contains("<property name=\"health_score.layer_isolation\" value=\"70\"/>"));
+        assert!(xml.contains("&apos;../infrastructure/db&apos;"));
+    }
+
+    #[test]
+    fn test_junit_clean_project_passes() {
+        let result = AnalysisResult::new("clean".to_string(), ArchPattern::MVC);
+        let xml = to_junit(&result);
+        assert!(xml.contains("tests=\"3\" failures=\"0\""));
+        assert!(!xml.contains("<failure"));
+    }
+
+    #[test]
+    fn test_checkstyle_report_structure() {
+        let xml = to_checkstyle(&sarif_test_result());
+        assert!(xml.contains("<checkstyle version=\"8.0\">"));
+        assert!(xml.contains("<file name=\"src/domain/user.ts\">"));
+        assert!(xml.contains("line=\"1\" column=\"20\" severity=\"error\""));
+        assert!(xml.contains("<file name=\"src/a.ts\">"));
+        assert!(xml.contains("source=\"architect/long-function\""));
+    }
+
+    #[test]
+    fn test_rdjson_ranges_from_import_span() {
+        let rdjson: Value = serde_json::from_str(&to_rdjson(&sarif_test_result())).unwrap();
+        let diagnostics = rdjson["diagnostics"].as_array().unwrap();
+        assert_eq!(diagnostics.len(), 4);
+
+        let range = &diagnostics[0]["location"]["range"];
+        assert_eq!(range["start"]["line"], 1);
+        assert_eq!(range["start"]["column"], 20);
+        assert_eq!(range["end"]["column"], 42);
+        assert_eq!(diagnostics[0]["severity"], "ERROR");
+        assert_eq!(diagnostics[3]["severity"], "WARNING");
+    }
 }
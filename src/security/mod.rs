@@ -14,6 +14,22 @@ use crate::autofix::Violation;
 use crate::config::LinterContext;
 use miette::Result;
 use std::path::Path;
+use vulnerabilities::VulnerabilityType;
+
+/// Hallazgo de seguridad: la violación a reportar y la vulnerabilidad detectada
+#[derive(Debug, Clone)]
+pub struct SecurityFinding {
+    pub violation: Violation,
+    pub vulnerability: VulnerabilityType,
+}
+
+impl SecurityFinding {
+    /// Puntuación `security-severity` de SARIF, según la vulnerabilidad
+    /// (no según la severidad configurada de la regla)
+    pub fn security_severity(&self) -> f64 {
+        self.vulnerability.severity().score()
+    }
+}
 
 /// Trait que deben implementar los parsers que soporten auditoría de seguridad
 pub trait SecurityAuditor: Send + Sync {
@@ -22,7 +38,7 @@ pub trait SecurityAuditor: Send + Sync {
         source_code: &str,
         file_path: &Path,
         context: &LinterContext,
-    ) -> Result<Vec<Violation>>;
+    ) -> Result<Vec<SecurityFinding>>;
 }
 
 /// Analiza la seguridad de un archivo delegando al auditor correspondiente
@@ -30,7 +46,7 @@ pub fn audit_file(
     source_code: &str,
     file_path: &Path,
     context: &LinterContext,
-) -> Result<Vec<Violation>> {
+) -> Result<Vec<SecurityFinding>> {
     // Intentamos obtener el parser multi-lenguaje que ahora también actuará como auditor
     if let Some(parser) = crate::parsers::get_parser_for_file(file_path) {
         // En el futuro, los parsers implementarán SecurityAuditor
@@ -25,6 +25,18 @@ pub enum Severity {
     Low,
 }
 
+impl Severity {
+    /// CVSS-style score (0.0-10.0) used by SARIF's `security-severity` property
+    pub fn score(&self) -> f64 {
+        match self {
+            Self::Critical => 9.5,
+            Self::High => 8.0,
+            Self::Medium => 5.5,
+            Self::Low => 2.0,
+        }
+    }
+}
+
 impl VulnerabilityType {
     pub fn severity(&self) -> Severity {
         match self {
@@ -164,4 +176,24 @@ mod tests {
         assert_eq!(VulnerabilityType::HardcodedSecrets.severity(), Severity::High);
         assert_eq!(VulnerabilityType::DeprecatedDependency.severity(), Severity::Medium);
     }
+
+    #[test]
+    fn test_finding_score_follows_vulnerability_not_rule_severity() {
+        let finding = crate::security::SecurityFinding {
+            violation: crate::autofix::Violation {
+                file_path: std::path::PathBuf::from("src/db.ts"),
+                file_content: String::new(),
+                offensive_import: "db.query(`SELECT ${id}`)".to_string(),
+                rule: crate::config::ForbiddenRule {
+                    from: "*".to_string(),
+                    to: "sql".to_string(),
+                    severity: Some(crate::config::Severity::Info),
+                    reason: None,
+                },
+                line_number: 1,
+            },
+            vulnerability: VulnerabilityType::SqlInjection,
+        };
+        assert_eq!(finding.security_severity(), Severity::Critical.score());
+    }
 }
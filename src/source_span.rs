@@ -65,3 +65,18 @@ pub fn lines_between_offsets(content: &str, start: usize, end: usize) -> usize {
     content[start..end].chars().filter(|&c| c == '\n').count()
 }
 
+/// Convert a byte offset into a 1-based `(line, column)` pair.
+///
+/// Columns are counted in UTF-16 code units, the default used by SARIF
+/// and most editors. Offsets past the end of `content` clamp to the end.
+pub fn offset_to_line_col(content: &str, offset: usize) -> (usize, usize) {
+    let mut offset = offset.min(content.len());
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &content[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].encode_utf16().count() + 1;
+    (line, column)
+}
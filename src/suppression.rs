@@ -0,0 +1,62 @@
+//! Inline suppressions
+//!
+//! A comment containing `architect-ignore-next-line` silences the violations
+//! reported on the following line; `architect-ignore-line` silences the line
+//! it is on. Any comment syntax works (`//`, `#`, `/* */`) and text after the
+//! directive is free-form, e.g. `// architect-ignore-next-line: legacy adapter`.
+
+use std::path::Path;
+
+/// Directive that silences the next line
+pub const IGNORE_NEXT_LINE: &str = "architect-ignore-next-line";
+
+/// Directive that silences its own line
+pub const IGNORE_LINE: &str = "architect-ignore-line";
+
+/// Whether violations on the 1-based `line_number` of `source` are suppressed
+pub fn is_suppressed(source: &str, line_number: usize) -> bool {
+    if line_number == 0 {
+        return false;
+    }
+    let mut lines = source.lines().skip(line_number.saturating_sub(2));
+    let previous = if line_number >= 2 { lines.next() } else { None };
+    let current = lines.next();
+    previous.is_some_and(|line| line.contains(IGNORE_NEXT_LINE))
+        || current.is_some_and(|line| line.contains(IGNORE_LINE))
+}
+
+/// Line comment that suppresses the line below it, with `indent` and the
+/// comment syntax of the file's language
+pub fn next_line_comment(path: &Path, indent: &str) -> String {
+    let prefix = match path.extension().and_then(|e| e.to_str()) {
+        Some("py" | "rb") => "#",
+        _ => "//",
+    };
+    format!("{}{} {}\n", indent, prefix, IGNORE_NEXT_LINE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_line_and_same_line() {
+        let source = "// architect-ignore-next-line: legacy\nimport a from 'a';\nimport b from 'b'; // architect-ignore-line\nimport c from 'c';\n";
+        assert!(!is_suppressed(source, 1));
+        assert!(is_suppressed(source, 2));
+        assert!(is_suppressed(source, 3));
+        assert!(!is_suppressed(source, 4));
+    }
+
+    #[test]
+    fn test_comment_syntax_follows_language() {
+        assert_eq!(
+            next_line_comment(Path::new("app/models.py"), "    "),
+            "    # architect-ignore-next-line\n"
+        );
+        assert_eq!(
+            next_line_comment(Path::new("src/app.ts"), ""),
+            "// architect-ignore-next-line\n"
+        );
+    }
+}
@@ -1,66 +1,404 @@
 //! Incremental analysis for watch mode
+//!
+//! The initial analysis keeps the findings of every file. After that, a
+//! change re-parses only the changed files, replaces their contribution to
+//! the in-memory `AnalysisResult`, updates their edges in the import graph
+//! and re-checks cycles only in the part of the graph connected to them.
+//! The health score is recomputed from the patched result.
 
-use std::collections::{HashSet, HashMap};
-use std::path::PathBuf;
+use crate::analysis_result::AnalysisResult;
+use crate::analyzer::collector::{
+    analyze_file, analyze_files, empty_result, merge_file_analysis, unmerge_file_analysis,
+    without_skipped, FileAnalysis,
+};
+use crate::analyzer::generated::GeneratedCodeDetector;
+use crate::cache::AnalysisCache;
+use crate::circular::{CircularDependency, CircularDependencyAnalyzer};
+use crate::config::{GeneratedCodeMode, LinterContext, Severity};
+use crate::discovery::{self, IgnoreExplanation};
+use crate::scoring;
+use chrono::Utc;
+use miette::Result;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
+/// What one call to `apply_changes` did
+#[derive(Debug, Default)]
+pub struct ChangeSet {
+    /// Files parsed again (changed or created)
+    pub reanalyzed: Vec<PathBuf>,
+    /// Files that no longer exist (deleted, or the old name of a rename)
+    pub removed: Vec<PathBuf>,
+    /// Cycles through the changed part of the graph, after the change
+    pub cycles: Vec<CircularDependency>,
+    /// Health score before the change
+    pub previous_score: Option<u8>,
+}
+
+/// Project analysis kept in memory and patched file by file
 pub struct IncrementalAnalyzer {
-    last_files: HashSet<PathBuf>,
-    last_analysis: HashMap<PathBuf, usize>, // violations per file
-    threshold: i32,
+    root: PathBuf,
+    ctx: Arc<LinterContext>,
+    min_severity: Severity,
+    cache: Option<Mutex<AnalysisCache>>,
+    detector: Option<GeneratedCodeDetector>,
+    graph: CircularDependencyAnalyzer,
+    /// Findings of each analyzed file, as merged into `result`
+    files: HashMap<PathBuf, FileAnalysis>,
+    result: AnalysisResult,
 }
 
 impl IncrementalAnalyzer {
-    pub fn new() -> Self {
-        Self {
-            last_files: HashSet::new(),
-            last_analysis: HashMap::new(),
-            threshold: 5,
+    /// Analyze the whole project once. Unchanged files are served from
+    /// `cache` when one is given.
+    pub fn new(
+        project_root: &Path,
+        ctx: Arc<LinterContext>,
+        min_severity: Severity,
+        mut cache: Option<AnalysisCache>,
+    ) -> Result<Self> {
+        let root = project_root
+            .canonicalize()
+            .unwrap_or_else(|_| project_root.to_path_buf());
+        let files = discovery::collect_files(&root, &ctx.ignored_paths);
+
+        let mut result = empty_result(&files, &root, ctx.pattern.clone(), &ctx);
+        let mut analyses = HashMap::new();
+        for (file_path, analysis) in analyze_files(&files, &root, &ctx, cache.as_mut()) {
+            analyses.insert(file_path.clone(), analysis.clone());
+            merge_file_analysis(&mut result, &ctx, file_path, analysis);
         }
+
+        // Generated code the analysis skips stays out of the cycles too
+        let mut graph = CircularDependencyAnalyzer::new(&root);
+        graph.build_graph(&without_skipped(&files, &result, &ctx))?;
+        for cycle in graph.detect_cycles() {
+            result.add_circular_dependency(cycle);
+        }
+
+        let detector = (ctx.generated_code.mode != GeneratedCodeMode::Analyze)
+            .then(|| GeneratedCodeDetector::new(&ctx.generated_code));
+        let mut analyzer = Self {
+            root,
+            ctx,
+            min_severity,
+            cache: cache.map(Mutex::new),
+            detector,
+            graph,
+            files: analyses,
+            result,
+        };
+        analyzer.finish();
+        Ok(analyzer)
+    }
+
+    /// Current result, with cycles and health score
+    pub fn result(&self) -> &AnalysisResult {
+        &self.result
+    }
+
+    /// Project root, canonicalized
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Analyze the whole project again, keeping the cache
+    pub fn reanalyze_all(&mut self) -> Result<()> {
+        let root = self.root.clone();
+        *self = Self::new(
+            &root,
+            Arc::clone(&self.ctx),
+            self.min_severity,
+            self.cache(),
+        )?;
+        Ok(())
+    }
+
+    /// File of step `step` of a cycle, with the line (1-based) where it
+    /// imports the next step
+    pub fn cycle_edge(
+        &self,
+        cycle: &CircularDependency,
+        step: usize,
+    ) -> Option<(PathBuf, Option<usize>)> {
+        let node = cycle.cycle.get(step)?;
+        let file = self
+            .files
+            .keys()
+            .find(|file| &self.graph.node_key(file) == node)?
+            .clone();
+        let line = cycle.cycle.get(step + 1).and_then(|next| {
+            let content = std::fs::read_to_string(&file).ok()?;
+            self.graph
+                .import_line_to(&file, &content, next)
+                .map(|line| line + 1)
+        });
+        Some((file, line))
     }
 
-    /// Detect files that changed since last analysis
-    pub fn detect_changes(&mut self, current_files: HashSet<PathBuf>) -> Vec<PathBuf> {
-        let new_files: Vec<_> = current_files
+    /// Analysis cache, to persist between sessions
+    pub fn cache(&self) -> Option<AnalysisCache> {
+        self.cache
+            .as_ref()
+            .and_then(|cache| cache.lock().ok().map(|cache| cache.clone()))
+    }
+
+    /// Patch the result after `changed` files were modified, created,
+    /// deleted or renamed (both names of a rename are expected). Directories
+    /// stand for every file under them.
+    pub fn apply_changes(&mut self, changed: &[PathBuf]) -> Result<ChangeSet> {
+        let mut changes = ChangeSet {
+            previous_score: self.result.health_score.as_ref().map(|s| s.total),
+            ..Default::default()
+        };
+        let paths = self.expand(changed);
+
+        // Cycles that went through the changed files before the change
+        let mut affected: HashSet<String> = HashSet::new();
+        for path in &paths {
+            affected.extend(self.graph.get_affected_nodes(&self.graph.node_key(path)));
+        }
+
+        let mut created = Vec::new();
+        for path in paths {
+            if let Some(cache) = &self.cache {
+                if let Ok(mut cache) = cache.lock() {
+                    cache.remove(&AnalysisCache::normalize_path(&path, &self.root));
+                }
+            }
+            let previous = self.files.remove(&path);
+            if let Some(ref previous) = previous {
+                unmerge_file_analysis(&mut self.result, &self.ctx, &path, previous);
+                self.result.files_analyzed -= 1;
+            }
+
+            if path.is_file() && (previous.is_some() || self.is_discovered(&path)) {
+                if previous.is_none() {
+                    created.push(path.clone());
+                }
+                self.reanalyze(&path);
+                if self.is_skipped(&path) {
+                    self.graph.clear_imports(&path);
+                } else if let Err(e) = self.graph.update_file(&path) {
+                    tracing::warn!("Could not update the import graph: {}", e);
+                }
+                changes.reanalyzed.push(path);
+            } else {
+                self.graph.invalidate_node(&self.graph.node_key(&path));
+                if previous.is_some() {
+                    changes.removed.push(path);
+                }
+            }
+        }
+
+        // Imports of a file that did not exist were unresolved: only the
+        // files whose unresolved imports point at a new file are re-read
+        let importers: BTreeSet<PathBuf> = created
             .iter()
-            .filter(|f| !self.last_files.contains(*f))
-            .cloned()
+            .flat_map(|path| self.graph.files_importing(path))
+            .filter(|file| !created.contains(file) && !self.is_skipped(file))
             .collect();
+        for file_path in &importers {
+            if let Err(e) = self.graph.update_file(file_path) {
+                tracing::debug!(
+                    "Could not re-read imports of {}: {}",
+                    file_path.display(),
+                    e
+                );
+            }
+        }
+        for path in &changes.reanalyzed {
+            affected.extend(self.graph.get_affected_nodes(&self.graph.node_key(path)));
+        }
+
+        self.result
+            .circular_dependencies
+            .retain(|cycle| !cycle.cycle.iter().any(|node| affected.contains(node)));
+        changes.cycles = self.graph.detect_cycles_in_subgraph(&affected);
+        for cycle in &changes.cycles {
+            self.result.add_circular_dependency(cycle.clone());
+        }
+
+        self.finish();
+        Ok(changes)
+    }
+
+    /// Changed paths as files: a directory (or a path that no longer exists)
+    /// stands for the known files under it, plus the files now under it
+    fn expand(&self, changed: &[PathBuf]) -> BTreeSet<PathBuf> {
+        let mut paths = BTreeSet::new();
+        for path in changed {
+            if path.is_file() {
+                paths.insert(path.clone());
+                continue;
+            }
+            paths.extend(
+                self.files
+                    .keys()
+                    .filter(|known| known.starts_with(path))
+                    .cloned(),
+            );
+            if path.is_dir() {
+                paths.extend(
+                    discovery::collect_files(&self.root, &self.ctx.ignored_paths)
+                        .into_iter()
+                        .filter(|file| file.starts_with(path)),
+                );
+            }
+        }
+        paths
+    }
+
+    /// Whether discovery would pick up a file that is not analyzed yet
+    fn is_discovered(&self, path: &Path) -> bool {
+        let Ok(rel) = path.strip_prefix(&self.root) else {
+            return false;
+        };
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        matches!(
+            discovery::explain_ignore(&self.root, &self.ctx.ignored_paths, &rel, false),
+            IgnoreExplanation::NotIgnored { .. }
+        )
+    }
 
-        self.last_files = current_files;
-        new_files
+    /// Whether `path` is generated code left out of the analysis
+    fn is_skipped(&self, path: &Path) -> bool {
+        self.files
+            .get(path)
+            .is_some_and(|analysis| analysis.is_skipped(&self.ctx))
     }
 
-    /// Decide if full rescan is needed
-    pub fn should_full_rescan(&self, violations_now: usize, violations_last: usize) -> bool {
-        let diff = (violations_now as i32 - violations_last as i32).abs();
-        diff > self.threshold
+    fn reanalyze(&mut self, path: &Path) {
+        let Some(analysis) = analyze_file(
+            path,
+            &self.root,
+            &self.ctx,
+            self.detector.as_ref(),
+            self.cache.as_ref(),
+        ) else {
+            return;
+        };
+        self.result.files_analyzed += 1;
+        self.files.insert(path.to_path_buf(), analysis.clone());
+        merge_file_analysis(&mut self.result, &self.ctx, path.to_path_buf(), analysis);
     }
 
-    /// Update analysis state
-    pub fn update(&mut self, file: PathBuf, violation_count: usize) {
-        self.last_analysis.insert(file, violation_count);
+    /// Severity filter, counters and score, as after a full analysis
+    fn finish(&mut self) {
+        self.result.filter_by_severity(self.min_severity);
+        self.result.layer_stats.blocked_violations = self.result.blocked_count();
+        self.result.timestamp = Utc::now();
+        self.result.health_score = Some(scoring::calculate(&self.result));
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn project() -> (TempDir, IncrementalAnalyzer) {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("architect.json"),
+            r#"{ "max_lines_per_function": 40, "architecture_pattern": "Hexagonal", "forbidden_imports": [{ "from": "src/domain/", "to": "src/infra/" }] }"#,
+        )
+        .unwrap();
+        fs::create_dir_all(dir.path().join("src/domain")).unwrap();
+        fs::create_dir_all(dir.path().join("src/infra")).unwrap();
+        fs::write(dir.path().join("src/infra/db.ts"), "export const db = 1;\n").unwrap();
+        fs::write(
+            dir.path().join("src/domain/user.ts"),
+            "import { db } from '../infra/db';\nexport const user = db;\n",
+        )
+        .unwrap();
+        let ctx = Arc::new(config::load_config(dir.path()).unwrap());
+        let analyzer = IncrementalAnalyzer::new(dir.path(), ctx, Severity::Info, None).unwrap();
+        (dir, analyzer)
+    }
 
     #[test]
-    fn test_detect_new_files() {
-        let mut analyzer = IncrementalAnalyzer::new();
-        let files = vec![PathBuf::from("test.rs"), PathBuf::from("main.rs")]
-            .into_iter()
-            .collect();
+    fn test_edit_patches_violations_cycles_and_score() {
+        let (dir, mut analyzer) = project();
+        let root = dir.path().canonicalize().unwrap();
+        assert_eq!(analyzer.result().files_analyzed, 2);
+        assert_eq!(analyzer.result().violations.len(), 1);
+        let score = analyzer.result().health_score.as_ref().unwrap().total;
+
+        let db = root.join("src/infra/db.ts");
+        fs::write(
+            &db,
+            "import { user } from '../domain/user';\nexport const db = user;\n",
+        )
+        .unwrap();
+        let changes = analyzer.apply_changes(std::slice::from_ref(&db)).unwrap();
+        assert_eq!(changes.reanalyzed, [db.clone()]);
+        assert_eq!(changes.cycles.len(), 1);
+        assert_eq!(analyzer.result().circular_dependencies.len(), 1);
+        assert!(analyzer.result().health_score.as_ref().unwrap().total < score);
+        // Both files import the other on their first line
+        let (file, line) = analyzer.cycle_edge(&changes.cycles[0], 0).unwrap();
+        assert!(file == db || file == root.join("src/domain/user.ts"));
+        assert_eq!(line, Some(1));
+
+        let user = root.join("src/domain/user.ts");
+        fs::write(&user, "export const user = 1;\n").unwrap();
+        analyzer.apply_changes(&[user]).unwrap();
+        assert!(analyzer.result().violations.is_empty());
+        assert!(analyzer.result().circular_dependencies.is_empty());
+        assert_eq!(analyzer.result().files_analyzed, 2);
+    }
+
+    #[test]
+    fn test_delete_and_rename() {
+        let (dir, mut analyzer) = project();
+        let root = dir.path().canonicalize().unwrap();
+        let user = root.join("src/domain/user.ts");
+        let renamed = root.join("src/domain/account.ts");
 
-        let new = analyzer.detect_changes(files);
-        assert_eq!(new.len(), 2);
+        fs::rename(&user, &renamed).unwrap();
+        let changes = analyzer
+            .apply_changes(&[user.clone(), renamed.clone()])
+            .unwrap();
+        assert_eq!(changes.removed, [user]);
+        assert_eq!(changes.reanalyzed, [renamed.clone()]);
+        assert_eq!(analyzer.result().files_analyzed, 2);
+        assert_eq!(analyzer.result().violations.len(), 1);
+        assert_eq!(analyzer.result().violations[0].violation.file_path, renamed);
+
+        // Deleting the directory removes every file under it
+        fs::remove_dir_all(root.join("src/domain")).unwrap();
+        let changes = analyzer.apply_changes(&[root.join("src/domain")]).unwrap();
+        assert_eq!(changes.removed, [renamed]);
+        assert!(analyzer.result().violations.is_empty());
+        assert_eq!(analyzer.result().files_analyzed, 1);
     }
 
     #[test]
-    fn test_rescan_threshold() {
-        let analyzer = IncrementalAnalyzer::new();
-        assert!(!analyzer.should_full_rescan(10, 5)); // diff = 5, not > threshold
-        assert!(analyzer.should_full_rescan(20, 5)); // diff = 15 > threshold
+    fn test_new_file_links_existing_importers() {
+        let (dir, mut analyzer) = project();
+        let root = dir.path().canonicalize().unwrap();
+        let db = root.join("src/infra/db.ts");
+        fs::write(
+            &db,
+            "import { cfg } from './config';\nexport const db = cfg;\n",
+        )
+        .unwrap();
+        analyzer.apply_changes(&[db]).unwrap();
+        assert!(analyzer.result().circular_dependencies.is_empty());
+
+        // config.ts did not exist when db.ts was parsed
+        let config = root.join("src/infra/config.ts");
+        fs::write(
+            &config,
+            "import { db } from './db';\nexport const cfg = db;\n",
+        )
+        .unwrap();
+        let changes = analyzer.apply_changes(&[config]).unwrap();
+        assert_eq!(changes.cycles.len(), 1);
+        assert_eq!(analyzer.result().files_analyzed, 3);
     }
 }
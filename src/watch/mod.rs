@@ -1,8 +1,9 @@
 //! Watch mode module for incremental analysis
 
 pub mod incremental;
+pub mod tui;
 
-
+use crate::discovery::is_supported_file;
 use miette::{IntoDiagnostic, Result};
 use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use std::io::Write;
@@ -28,6 +29,43 @@ pub fn resume_stdin_reader() {
     STDIN_PAUSED.store(false, Ordering::SeqCst);
 }
 
+/// Quiet period after the last file event before re-analyzing
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Source files worth re-analyzing. Paths without extension may be
+/// directories that were moved or deleted; the incremental analyzer expands
+/// them to the files under them.
+fn is_relevant_file(path: &Path) -> bool {
+    path.extension().is_none() || is_supported_file(path)
+}
+
+/// Whether `path` falls under one of the `ignored` directories
+fn should_ignore_path(project_root: &Path, ignored: &[String], path: &Path) -> bool {
+    let relative_path = path
+        .strip_prefix(project_root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    for pattern in ignored {
+        let normalized_pattern = pattern.replace('\\', "/");
+        if relative_path.contains(normalized_pattern.trim_end_matches('/'))
+            || relative_path.starts_with(&normalized_pattern)
+            || relative_path.starts_with(&format!("{}/", normalized_pattern.trim_end_matches('/')))
+        {
+            return true;
+        }
+    }
+    false
+}
+
+fn is_relevant_event(event: &Event) -> bool {
+    matches!(
+        event.kind,
+        EventKind::Modify(_) | EventKind::Create(_) | EventKind::Remove(_)
+    )
+}
+
 /// Commands available in interactive watch mode
 #[derive(Debug, Clone)]
 pub enum WatchCommand {
@@ -165,49 +203,6 @@ where
     // Spawn stdin reader thread
     spawn_stdin_reader(unified_tx);
 
-    // Build file-filtering helpers (reuse FileWatcher logic inline)
-    let project_root_buf = project_root.to_path_buf();
-    let ignored = ignored_paths.clone();
-    let debounce_duration = Duration::from_millis(300);
-
-    let is_relevant_file = |path: &Path| -> bool {
-        if let Some(ext) = path.extension() {
-            matches!(
-                ext.to_str(),
-                Some("ts") | Some("tsx") | Some("js") | Some("jsx")
-            )
-        } else {
-            false
-        }
-    };
-
-    let should_ignore_path = |path: &Path| -> bool {
-        let relative_path = path
-            .strip_prefix(&project_root_buf)
-            .unwrap_or(path)
-            .to_string_lossy()
-            .replace('\\', "/");
-
-        for pattern in &ignored {
-            let normalized_pattern = pattern.replace('\\', "/");
-            if relative_path.contains(normalized_pattern.trim_end_matches('/'))
-                || relative_path.starts_with(&normalized_pattern)
-                || relative_path
-                    .starts_with(&format!("{}/", normalized_pattern.trim_end_matches('/')))
-            {
-                return true;
-            }
-        }
-        false
-    };
-
-    let is_relevant_event = |event: &Event| -> bool {
-        matches!(
-            event.kind,
-            EventKind::Modify(_) | EventKind::Create(_) | EventKind::Remove(_)
-        )
-    };
-
     print!("👁️  Esperando cambios o comandos... (h + Enter = ayuda)\n> ");
     let _ = std::io::stdout().flush();
 
@@ -220,7 +215,9 @@ where
             Ok(InternalEvent::FileNotify(Ok(event))) => {
                 if is_relevant_event(&event) {
                     for path in &event.paths {
-                        if is_relevant_file(path) && !should_ignore_path(path) {
+                        if is_relevant_file(path)
+                            && !should_ignore_path(project_root, &ignored_paths, path)
+                        {
                             if !changed_files.contains(path) {
                                 changed_files.push(path.clone());
                             }
@@ -256,7 +253,7 @@ where
         }
 
         // Process accumulated file changes after debounce period
-        if !changed_files.is_empty() && last_event_time.elapsed() >= debounce_duration {
+        if !changed_files.is_empty() && last_event_time.elapsed() >= DEBOUNCE {
             println!(
                 "\n🔄 Cambios detectados en {} archivo(s):",
                 changed_files.len()
@@ -0,0 +1,373 @@
+//! State of the watch dashboard: selection, filter and key handling
+//!
+//! Kept free of terminal I/O so navigation can be unit tested; the event
+//! loop turns the returned `Action`s into work.
+
+use crate::analysis_result::{AnalysisResult, CategorizedViolation};
+use crate::circular::CircularDependency;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::path::{Path, PathBuf};
+
+/// List shown on the left of the dashboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    Violations,
+    Cycles,
+}
+
+/// What the violation filter matches against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterField {
+    Any,
+    File,
+    Rule,
+}
+
+impl FilterField {
+    pub fn label(self) -> &'static str {
+        match self {
+            FilterField::Any => "archivo o regla",
+            FilterField::File => "archivo",
+            FilterField::Rule => "regla",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            FilterField::Any => FilterField::File,
+            FilterField::File => FilterField::Rule,
+            FilterField::Rule => FilterField::Any,
+        }
+    }
+}
+
+/// Work requested by a key press
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    None,
+    Quit,
+    OpenEditor { file: PathBuf, line: usize },
+    Reanalyze,
+    ReportJson,
+    ReportMarkdown,
+    Fix,
+}
+
+/// Item under the cursor
+pub enum Selection<'a> {
+    Violation(&'a CategorizedViolation),
+    /// A cycle and the step (file) of it being explored
+    CycleStep(&'a CircularDependency, usize),
+}
+
+/// Lines of the file shown in the preview pane
+pub struct Preview {
+    pub file: PathBuf,
+    /// Line to highlight (1-based)
+    pub line: Option<usize>,
+    pub lines: Vec<String>,
+}
+
+pub struct App {
+    pub result: AnalysisResult,
+    pub root: PathBuf,
+    pub pane: Pane,
+    pub filter: String,
+    pub filter_field: FilterField,
+    /// Keys go to the filter instead of navigation
+    pub editing_filter: bool,
+    pub violation_index: usize,
+    pub cycle_index: usize,
+    pub cycle_step: usize,
+    pub preview: Option<Preview>,
+    /// Health score before the last change, to show the delta
+    pub previous_score: Option<u8>,
+    /// Last event, shown in the status line
+    pub status: String,
+}
+
+impl App {
+    pub fn new(result: AnalysisResult, root: &Path) -> Self {
+        Self {
+            result,
+            root: root.to_path_buf(),
+            pane: Pane::Violations,
+            filter: String::new(),
+            filter_field: FilterField::Any,
+            editing_filter: false,
+            violation_index: 0,
+            cycle_index: 0,
+            cycle_step: 0,
+            preview: None,
+            previous_score: None,
+            status: String::new(),
+        }
+    }
+
+    /// Replace the result after a re-analysis, keeping the cursor in range
+    pub fn set_result(&mut self, result: AnalysisResult, previous_score: Option<u8>) {
+        self.result = result;
+        self.previous_score = previous_score;
+        self.clamp();
+    }
+
+    /// Path relative to the project root, as shown in the lists
+    pub fn relative(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+
+    /// Violations that match the filter, in result order
+    pub fn visible_violations(&self) -> Vec<&CategorizedViolation> {
+        let needle = self.filter.to_lowercase();
+        self.result
+            .violations
+            .iter()
+            .filter(|cv| {
+                if needle.is_empty() {
+                    return true;
+                }
+                let file = self.relative(&cv.violation.file_path).to_lowercase();
+                let rule = &cv.violation.rule;
+                let rule = format!(
+                    "{} {} {}",
+                    rule.from,
+                    rule.to,
+                    rule.reason.as_deref().unwrap_or_default()
+                )
+                .to_lowercase();
+                match self.filter_field {
+                    FilterField::Any => file.contains(&needle) || rule.contains(&needle),
+                    FilterField::File => file.contains(&needle),
+                    FilterField::Rule => rule.contains(&needle),
+                }
+            })
+            .collect()
+    }
+
+    pub fn selection(&self) -> Option<Selection<'_>> {
+        match self.pane {
+            Pane::Violations => self
+                .visible_violations()
+                .get(self.violation_index)
+                .map(|cv| Selection::Violation(cv)),
+            Pane::Cycles => self
+                .result
+                .circular_dependencies
+                .get(self.cycle_index)
+                .map(|cycle| Selection::CycleStep(cycle, self.cycle_step)),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Action::Quit;
+        }
+        if self.editing_filter {
+            self.edit_filter(key.code);
+            return Action::None;
+        }
+
+        match key.code {
+            KeyCode::Char('q') => return Action::Quit,
+            KeyCode::Esc if !self.filter.is_empty() => {
+                self.filter.clear();
+                self.violation_index = 0;
+            }
+            KeyCode::Esc => return Action::Quit,
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.pane = match self.pane {
+                    Pane::Violations => Pane::Cycles,
+                    Pane::Cycles => Pane::Violations,
+                };
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_by(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_by(1),
+            KeyCode::PageUp => self.move_by(-10),
+            KeyCode::PageDown => self.move_by(10),
+            KeyCode::Home | KeyCode::Char('g') => self.move_by(isize::MIN),
+            KeyCode::End | KeyCode::Char('G') => self.move_by(isize::MAX),
+            KeyCode::Left | KeyCode::Char('h') if self.pane == Pane::Cycles => {
+                self.cycle_step = self.cycle_step.saturating_sub(1);
+            }
+            KeyCode::Right | KeyCode::Char('l') if self.pane == Pane::Cycles => {
+                self.cycle_step += 1;
+                self.clamp();
+            }
+            KeyCode::Char('/') => {
+                self.pane = Pane::Violations;
+                self.editing_filter = true;
+            }
+            KeyCode::Char('t') => {
+                self.filter_field = self.filter_field.next();
+                self.violation_index = 0;
+            }
+            KeyCode::Enter | KeyCode::Char('e') => {
+                if let Some(preview) = &self.preview {
+                    return Action::OpenEditor {
+                        file: preview.file.clone(),
+                        line: preview.line.unwrap_or(1),
+                    };
+                }
+            }
+            KeyCode::Char('a') => return Action::Reanalyze,
+            KeyCode::Char('r') => return Action::ReportJson,
+            KeyCode::Char('m') => return Action::ReportMarkdown,
+            KeyCode::Char('f') => return Action::Fix,
+            _ => {}
+        }
+        Action::None
+    }
+
+    fn edit_filter(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Enter => self.editing_filter = false,
+            KeyCode::Esc => {
+                self.editing_filter = false;
+                self.filter.clear();
+            }
+            KeyCode::Backspace => {
+                self.filter.pop();
+            }
+            KeyCode::Char(c) => self.filter.push(c),
+            _ => {}
+        }
+        self.violation_index = 0;
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        let index = match self.pane {
+            Pane::Violations => &mut self.violation_index,
+            Pane::Cycles => {
+                self.cycle_step = 0;
+                &mut self.cycle_index
+            }
+        };
+        *index = index.saturating_add_signed(delta);
+        self.clamp();
+    }
+
+    fn clamp(&mut self) {
+        let violations = self.visible_violations().len();
+        self.violation_index = self.violation_index.min(violations.saturating_sub(1));
+        let cycles = &self.result.circular_dependencies;
+        self.cycle_index = self.cycle_index.min(cycles.len().saturating_sub(1));
+        // The last node of a cycle repeats the first one
+        let steps = cycles
+            .get(self.cycle_index)
+            .map_or(0, |cycle| cycle.cycle.len().saturating_sub(1));
+        self.cycle_step = self.cycle_step.min(steps.saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis_result::ViolationCategory;
+    use crate::autofix::Violation;
+    use crate::config::{ArchPattern, ForbiddenRule};
+
+    fn violation(file: &str, from: &str, to: &str) -> CategorizedViolation {
+        CategorizedViolation::new(
+            Violation {
+                file_path: PathBuf::from("/project").join(file),
+                file_content: String::new(),
+                offensive_import: format!("import x from '{}'", to),
+                rule: ForbiddenRule {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    ..Default::default()
+                },
+                line_number: 3,
+            },
+            ViolationCategory::Blocked,
+        )
+    }
+
+    fn app() -> App {
+        let mut result = AnalysisResult::new("demo".to_string(), ArchPattern::Hexagonal);
+        result.add_violation(violation("src/domain/user.ts", "src/domain/", "src/infra/"));
+        result.add_violation(violation("src/app/api.ts", "src/app/", "src/db/"));
+        result.add_circular_dependency(CircularDependency {
+            cycle: vec!["a.ts".to_string(), "b.ts".to_string(), "a.ts".to_string()],
+            description: String::new(),
+        });
+        App::new(result, Path::new("/project"))
+    }
+
+    fn press(app: &mut App, code: KeyCode) -> Action {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_filter_by_file_and_rule() {
+        let mut app = app();
+        press(&mut app, KeyCode::Char('/'));
+        for c in "domain".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert!(!app.editing_filter);
+        assert_eq!(app.visible_violations().len(), 1);
+
+        // "src/db/" is only in the rule of the second violation
+        app.filter = "db".to_string();
+        press(&mut app, KeyCode::Char('t'));
+        assert_eq!(app.filter_field, FilterField::File);
+        assert!(app.visible_violations().is_empty());
+        press(&mut app, KeyCode::Char('t'));
+        assert_eq!(app.filter_field, FilterField::Rule);
+        assert_eq!(app.visible_violations().len(), 1);
+
+        // Esc clears the filter before it quits
+        assert_eq!(press(&mut app, KeyCode::Esc), Action::None);
+        assert_eq!(app.visible_violations().len(), 2);
+        assert_eq!(press(&mut app, KeyCode::Esc), Action::Quit);
+    }
+
+    #[test]
+    fn test_navigation_stays_in_range() {
+        let mut app = app();
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.violation_index, 1);
+        press(&mut app, KeyCode::Home);
+        assert_eq!(app.violation_index, 0);
+
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.pane, Pane::Cycles);
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Right);
+        assert_eq!(app.cycle_step, 1);
+        assert!(matches!(app.selection(), Some(Selection::CycleStep(_, 1))));
+
+        // A shorter result keeps the cursor on an existing item
+        let mut result = app.result.clone();
+        result.violations.truncate(1);
+        result.circular_dependencies.clear();
+        app.set_result(result, Some(80));
+        assert_eq!(app.violation_index, 0);
+        assert!(app.selection().is_none());
+    }
+
+    #[test]
+    fn test_enter_opens_the_previewed_line() {
+        let mut app = app();
+        assert_eq!(press(&mut app, KeyCode::Enter), Action::None);
+        app.preview = Some(Preview {
+            file: PathBuf::from("/project/src/domain/user.ts"),
+            line: Some(3),
+            lines: Vec::new(),
+        });
+        assert_eq!(
+            press(&mut app, KeyCode::Enter),
+            Action::OpenEditor {
+                file: PathBuf::from("/project/src/domain/user.ts"),
+                line: 3
+            }
+        );
+        assert_eq!(press(&mut app, KeyCode::Char('a')), Action::Reanalyze);
+    }
+}
@@ -0,0 +1,360 @@
+//! Full-screen dashboard for watch mode
+//!
+//! Shows the health score with its components, the violations (filterable
+//! by file or rule), a cycle explorer and a preview of the offending import,
+//! and keeps them current as files change. Enter opens `$EDITOR` at the
+//! selected line.
+//!
+//! - `app`: selection, filter and key handling
+//! - `view`: drawing
+//!
+//! While the dashboard owns the terminal, the log goes to
+//! `.architect/watch.log`.
+
+pub mod app;
+pub mod view;
+
+use super::incremental::IncrementalAnalyzer;
+use super::{is_relevant_event, is_relevant_file, should_ignore_path, DEBOUNCE};
+use crate::cli::ReportFormat;
+use crate::history::HISTORY_DIR;
+use crate::{logging, notification, report};
+use app::{Action, App, Preview, Selection};
+use miette::{IntoDiagnostic, Result};
+use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher};
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use ratatui::crossterm::terminal::{enable_raw_mode, EnterAlternateScreen};
+use ratatui::crossterm::ExecutableCommand;
+use ratatui::DefaultTerminal;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{channel, Receiver};
+use std::time::{Duration, Instant};
+
+/// Log file inside `HISTORY_DIR` while the dashboard is shown
+pub const LOG_FILE: &str = "watch.log";
+
+/// Whether the dashboard can take over the terminal (not a pipe or CI log)
+pub fn is_supported() -> bool {
+    std::io::stdin().is_terminal() && std::io::stdout().is_terminal()
+}
+
+/// Show the dashboard until the user quits. `on_fix` runs the AI fix flow
+/// with the terminal back in normal mode.
+pub fn run<F>(
+    analyzer: &mut IncrementalAnalyzer,
+    ignored_paths: &[String],
+    project_name: &str,
+    mut on_fix: F,
+) -> Result<()>
+where
+    F: FnMut() -> Result<()>,
+{
+    let root = analyzer.root().to_path_buf();
+    let (tx, rx) = channel();
+    let mut watcher = RecommendedWatcher::new(
+        move |res| {
+            let _ = tx.send(res);
+        },
+        Config::default(),
+    )
+    .into_diagnostic()?;
+    watcher
+        .watch(&root, RecursiveMode::Recursive)
+        .into_diagnostic()?;
+
+    let log_path = root.join(HISTORY_DIR).join(LOG_FILE);
+    if let Err(e) = logging::redirect_to_file(&log_path) {
+        eprintln!("⚠️  No se pudo abrir {}: {}", log_path.display(), e);
+    }
+
+    let mut app = App::new(analyzer.result().clone(), &root);
+    app.status = format!(
+        "👁️  Observando {} · log en {}",
+        root.display(),
+        app.relative(&log_path)
+    );
+    refresh_preview(&mut app, analyzer);
+
+    let mut terminal = ratatui::try_init().into_diagnostic()?;
+    let outcome = event_loop(
+        &mut terminal,
+        &mut app,
+        analyzer,
+        &rx,
+        ignored_paths,
+        project_name,
+        &mut on_fix,
+    );
+    ratatui::restore();
+    logging::restore_stdout();
+    drop(watcher);
+    outcome
+}
+
+fn event_loop(
+    terminal: &mut DefaultTerminal,
+    app: &mut App,
+    analyzer: &mut IncrementalAnalyzer,
+    rx: &Receiver<notify::Result<notify::Event>>,
+    ignored_paths: &[String],
+    project_name: &str,
+    on_fix: &mut dyn FnMut() -> Result<()>,
+) -> Result<()> {
+    let root = analyzer.root().to_path_buf();
+    let mut changed: Vec<PathBuf> = Vec::new();
+    let mut last_event = Instant::now();
+
+    loop {
+        terminal
+            .draw(|frame| view::draw(frame, app))
+            .into_diagnostic()?;
+
+        if event::poll(Duration::from_millis(100)).into_diagnostic()? {
+            if let Event::Key(key) = event::read().into_diagnostic()? {
+                if key.kind == KeyEventKind::Press {
+                    let action = app.handle_key(key);
+                    if action == Action::Quit {
+                        return Ok(());
+                    }
+                    perform(terminal, app, analyzer, action, on_fix)?;
+                    refresh_preview(app, analyzer);
+                }
+            }
+        }
+
+        while let Ok(res) = rx.try_recv() {
+            match res {
+                Ok(event) if is_relevant_event(&event) => {
+                    for path in event.paths {
+                        if is_relevant_file(&path)
+                            && !should_ignore_path(&root, ignored_paths, &path)
+                            && !changed.contains(&path)
+                        {
+                            changed.push(path);
+                        }
+                    }
+                    last_event = Instant::now();
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("File watcher error: {:?}", e),
+            }
+        }
+
+        if !changed.is_empty() && last_event.elapsed() >= DEBOUNCE {
+            apply_changes(app, analyzer, &changed, project_name);
+            refresh_preview(app, analyzer);
+            changed.clear();
+        }
+    }
+}
+
+/// Re-analyze the changed files and report the outcome in the status line
+fn apply_changes(
+    app: &mut App,
+    analyzer: &mut IncrementalAnalyzer,
+    changed: &[PathBuf],
+    project_name: &str,
+) {
+    let changes = match analyzer.apply_changes(changed) {
+        Ok(changes) => changes,
+        Err(e) => {
+            app.status = format!("❌ Error durante re-análisis: {}", e);
+            return;
+        }
+    };
+    app.set_result(analyzer.result().clone(), changes.previous_score);
+
+    let violating = app
+        .result
+        .violations
+        .iter()
+        .filter(|cv| changes.reanalyzed.contains(&cv.violation.file_path))
+        .map(|cv| &cv.violation.file_path)
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+    let mut status = format!("🔄 {} archivo(s) re-analizados", changes.reanalyzed.len());
+    if !changes.removed.is_empty() {
+        status.push_str(&format!(", {} eliminado(s)", changes.removed.len()));
+    }
+    if !changes.cycles.is_empty() {
+        status.push_str(&format!(" · ⚠️  {} ciclo(s)", changes.cycles.len()));
+        notification::send_cycle_alert(project_name, changes.cycles.len());
+    }
+    if violating > 0 {
+        status.push_str(&format!(" · ❌ {} archivo(s) con violaciones", violating));
+        notification::send_violation_alert(project_name, violating);
+    } else {
+        status.push_str(" · ✨ Todo correcto");
+        notification::send_success_notification(project_name);
+    }
+    app.status = status;
+}
+
+fn perform(
+    terminal: &mut DefaultTerminal,
+    app: &mut App,
+    analyzer: &mut IncrementalAnalyzer,
+    action: Action,
+    on_fix: &mut dyn FnMut() -> Result<()>,
+) -> Result<()> {
+    match action {
+        Action::None | Action::Quit => {}
+        Action::OpenEditor { file, line } => {
+            let mut command = editor_command(&editor(), &file, line);
+            let status = suspend(terminal, || command.status())?;
+            app.status = match status {
+                Ok(status) if status.success() => {
+                    format!("✏️  {}:{}", app.relative(&file), line)
+                }
+                Ok(status) => format!("⚠️  El editor terminó con {}", status),
+                Err(e) => format!("❌ No se pudo abrir $EDITOR ({}): {}", editor(), e),
+            };
+        }
+        Action::Reanalyze => {
+            let previous = app.result.health_score.as_ref().map(|s| s.total);
+            // Full analysis prints its progress bar on the normal screen
+            match suspend(terminal, || analyzer.reanalyze_all())? {
+                Ok(()) => {
+                    app.set_result(analyzer.result().clone(), previous);
+                    app.status = format!(
+                        "📊 Análisis completo: {} archivos",
+                        app.result.files_analyzed
+                    );
+                }
+                Err(e) => app.status = format!("❌ Error en el análisis: {}", e),
+            }
+        }
+        Action::ReportJson | Action::ReportMarkdown => {
+            let (format, name) = if action == Action::ReportJson {
+                (ReportFormat::Json, "report.json")
+            } else {
+                (ReportFormat::Markdown, "report.md")
+            };
+            let path = analyzer.root().join(name);
+            let content = report::generate_report(&app.result, format);
+            app.status = match report::write_report(&content, &path) {
+                Ok(()) => format!("📄 Reporte guardado en: {}", path.display()),
+                Err(e) => format!("❌ No se pudo guardar el reporte: {}", e),
+            };
+        }
+        Action::Fix => {
+            if let Err(e) = suspend(terminal, &mut *on_fix)? {
+                app.status = format!("❌ Error en auto-fix: {}", e);
+            } else {
+                app.status = "🔧 Auto-fix terminado".to_string();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Give the terminal back to `f` (an editor, prompts) and restore the
+/// dashboard afterwards
+fn suspend<T>(terminal: &mut DefaultTerminal, f: impl FnOnce() -> T) -> Result<T> {
+    ratatui::restore();
+    logging::restore_stdout();
+    let outcome = f();
+    enable_raw_mode().into_diagnostic()?;
+    std::io::stdout()
+        .execute(EnterAlternateScreen)
+        .into_diagnostic()?;
+    terminal.clear().into_diagnostic()?;
+    Ok(outcome)
+}
+
+/// Load the lines of the file behind the selection
+fn refresh_preview(app: &mut App, analyzer: &IncrementalAnalyzer) {
+    let target = match app.selection() {
+        Some(Selection::Violation(cv)) => Some((
+            cv.violation.file_path.clone(),
+            Some(cv.violation.line_number),
+        )),
+        Some(Selection::CycleStep(cycle, step)) => analyzer.cycle_edge(cycle, step),
+        None => None,
+    };
+    app.preview = target.map(|(file, line)| Preview {
+        lines: std::fs::read_to_string(&file)
+            .map(|content| content.lines().map(str::to_string).collect())
+            .unwrap_or_default(),
+        file,
+        line,
+    });
+}
+
+fn editor() -> String {
+    std::env::var("EDITOR")
+        .ok()
+        .filter(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| {
+            if cfg!(windows) {
+                "notepad".to_string()
+            } else {
+                "vi".to_string()
+            }
+        })
+}
+
+/// Command that opens `file` at `line` in `editor` (`$EDITOR` may carry
+/// arguments, as in `code -w`)
+fn editor_command(editor: &str, file: &Path, line: usize) -> Command {
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+    let mut command = Command::new(program);
+    command.args(parts);
+
+    let name = Path::new(program)
+        .file_stem()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let file = file.display().to_string();
+    match name.as_str() {
+        "code" | "code-insiders" | "codium" | "cursor" => {
+            command.arg("--goto").arg(format!("{}:{}", file, line));
+        }
+        "subl" | "zed" | "hx" | "helix" => {
+            command.arg(format!("{}:{}", file, line));
+        }
+        "notepad" => {
+            command.arg(file);
+        }
+        // vi, vim, nvim, nano, emacs, micro, kak...
+        _ => {
+            command.arg(format!("+{}", line)).arg(file);
+        }
+    }
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(editor: &str) -> (String, Vec<String>) {
+        let command = editor_command(editor, Path::new("src/app.ts"), 12);
+        (
+            command.get_program().to_string_lossy().to_string(),
+            command
+                .get_args()
+                .map(|arg| arg.to_string_lossy().to_string())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_editor_command_jumps_to_line() {
+        assert_eq!(
+            args("nvim"),
+            ("nvim".to_string(), vec!["+12".into(), "src/app.ts".into()])
+        );
+        assert_eq!(
+            args("code -w"),
+            (
+                "code".to_string(),
+                vec!["-w".into(), "--goto".into(), "src/app.ts:12".into()]
+            )
+        );
+        assert_eq!(args("/usr/bin/hx").1, ["src/app.ts:12"]);
+    }
+}
@@ -0,0 +1,348 @@
+//! Drawing of the watch dashboard
+
+use super::app::{App, Pane, Selection};
+use crate::analysis_result::ViolationCategory;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Gauge, List, ListItem, ListState, Paragraph, Tabs};
+use ratatui::Frame;
+
+const KEYS: &str = "↑↓ mover · Tab violaciones/ciclos · ←→ paso del ciclo · / filtrar · t campo · Enter abrir en $EDITOR · a analizar · r/m reporte · f fix · q salir";
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [score, body, status, keys] = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Min(6),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [list, preview] =
+        Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)]).areas(body);
+
+    draw_score(frame, app, score);
+    match app.pane {
+        Pane::Violations => draw_violations(frame, app, list),
+        Pane::Cycles => draw_cycles(frame, app, list),
+    }
+    draw_preview(frame, app, preview);
+    frame.render_widget(Paragraph::new(app.status.as_str()), status);
+    frame.render_widget(
+        Paragraph::new(KEYS).style(Style::default().fg(Color::DarkGray)),
+        keys,
+    );
+}
+
+fn score_color(score: u8) -> Color {
+    match score {
+        80.. => Color::Green,
+        60..=79 => Color::Yellow,
+        _ => Color::Red,
+    }
+}
+
+fn draw_score(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(score) = &app.result.health_score else {
+        frame.render_widget(
+            Paragraph::new("Sin health score").block(Block::bordered()),
+            area,
+        );
+        return;
+    };
+
+    let mut title = vec![Span::styled(
+        format!(" Health score {} ({}) ", score.total, score.grade.as_str()),
+        Style::default()
+            .fg(score_color(score.total))
+            .add_modifier(Modifier::BOLD),
+    )];
+    if let Some(previous) = app.previous_score.filter(|p| *p != score.total) {
+        let delta = i16::from(score.total) - i16::from(previous);
+        title.push(Span::styled(
+            format!("{:+} ", delta),
+            Style::default().fg(if delta > 0 { Color::Green } else { Color::Red }),
+        ));
+    }
+    title.push(Span::raw(format!(
+        "· {} archivos · {} violaciones · {} ciclos ",
+        app.result.files_analyzed,
+        app.result.violations.len(),
+        app.result.circular_dependencies.len()
+    )));
+    let block = Block::bordered().title(Line::from(title));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let components = [
+        ("Aislamiento de capas", score.components.layer_isolation),
+        ("Dependencias cíclicas", score.components.circular_deps),
+        ("Complejidad", score.components.complexity),
+        ("Violaciones", score.components.violations),
+    ];
+    let columns = Layout::horizontal([Constraint::Ratio(1, 4); 4]).split(inner);
+    for ((label, value), column) in components.into_iter().zip(columns.iter()) {
+        let [name, gauge] =
+            Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(*column);
+        frame.render_widget(Paragraph::new(label), name);
+        frame.render_widget(
+            Gauge::default()
+                .gauge_style(Style::default().fg(score_color(value)))
+                .percent(u16::from(value.min(100))),
+            gauge,
+        );
+    }
+}
+
+fn pane_tabs(app: &App) -> Tabs<'static> {
+    Tabs::new(vec![
+        format!("Violaciones ({})", app.visible_violations().len()),
+        format!("Ciclos ({})", app.result.circular_dependencies.len()),
+    ])
+    .select(match app.pane {
+        Pane::Violations => 0,
+        Pane::Cycles => 1,
+    })
+    .highlight_style(Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED))
+}
+
+fn draw_violations(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::bordered();
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [tabs, list, filter] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+    frame.render_widget(pane_tabs(app), tabs);
+
+    let items: Vec<ListItem> = app
+        .visible_violations()
+        .into_iter()
+        .map(|cv| {
+            let (icon, color) = match cv.category {
+                ViolationCategory::Blocked => ("✖", Color::Red),
+                ViolationCategory::Warning => ("▲", Color::Yellow),
+                ViolationCategory::Info => ("●", Color::Blue),
+            };
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(format!("{} ", icon), Style::default().fg(color)),
+                    Span::raw(format!(
+                        "{}:{}",
+                        app.relative(&cv.violation.file_path),
+                        cv.violation.line_number
+                    )),
+                ]),
+                Line::styled(
+                    format!("  {} ↛ {}", cv.violation.rule.from, cv.violation.rule.to),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        })
+        .collect();
+    let empty = items.is_empty();
+    let mut state = ListState::default().with_selected(Some(app.violation_index));
+    frame.render_stateful_widget(
+        List::new(items).highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+        list,
+        &mut state,
+    );
+    if empty {
+        let message = if app.filter.is_empty() {
+            "✨ Sin violaciones"
+        } else {
+            "Ninguna violación coincide con el filtro"
+        };
+        frame.render_widget(Paragraph::new(message), list);
+    }
+
+    let cursor = if app.editing_filter { "▏" } else { "" };
+    let filter_style = if app.editing_filter {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    frame.render_widget(
+        Paragraph::new(format!(
+            "/ Filtro ({}): {}{}",
+            app.filter_field.label(),
+            app.filter,
+            cursor
+        ))
+        .style(filter_style),
+        filter,
+    );
+}
+
+fn draw_cycles(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::bordered();
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [tabs, list] = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(inner);
+    frame.render_widget(pane_tabs(app), tabs);
+
+    if app.result.circular_dependencies.is_empty() {
+        frame.render_widget(
+            Paragraph::new("✅ No se detectaron dependencias cíclicas."),
+            list,
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .result
+        .circular_dependencies
+        .iter()
+        .enumerate()
+        .map(|(i, cycle)| {
+            let mut lines = vec![Line::from(format!("Ciclo #{}", i + 1))];
+            for (step, node) in cycle.cycle.iter().enumerate() {
+                let current = i == app.cycle_index && step == app.cycle_step;
+                let arrow = if step == 0 { "  " } else { "  → " };
+                let style = if current {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                lines.push(Line::from(vec![
+                    Span::raw(arrow),
+                    Span::styled(node.clone(), style),
+                ]));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+    let mut state = ListState::default().with_selected(Some(app.cycle_index));
+    frame.render_stateful_widget(List::new(items).highlight_symbol("▌"), list, &mut state);
+}
+
+fn draw_preview(frame: &mut Frame, app: &App, area: Rect) {
+    let subtitle = match app.selection() {
+        Some(Selection::Violation(cv)) => cv.violation.rule.reason.clone().unwrap_or_else(|| {
+            format!(
+                "'{}' no puede importar de '{}'",
+                cv.violation.rule.from, cv.violation.rule.to
+            )
+        }),
+        Some(Selection::CycleStep(cycle, step)) => format!(
+            "paso {}/{}: {} → {}",
+            step + 1,
+            cycle.cycle.len().saturating_sub(1),
+            cycle.cycle.get(step).map_or("", String::as_str),
+            cycle.cycle.get(step + 1).map_or("", String::as_str)
+        ),
+        None => String::new(),
+    };
+
+    let Some(preview) = &app.preview else {
+        frame.render_widget(
+            Paragraph::new("Selecciona una violación o un ciclo").block(Block::bordered()),
+            area,
+        );
+        return;
+    };
+    let title = match preview.line {
+        Some(line) => format!(" {}:{} ", app.relative(&preview.file), line),
+        None => format!(" {} ", app.relative(&preview.file)),
+    };
+    let block = Block::bordered().title(title).title_bottom(Line::styled(
+        format!(" {} ", subtitle),
+        Style::default().fg(Color::DarkGray),
+    ));
+    let inner = block.inner(area);
+
+    // Keep the highlighted line in the middle of the pane
+    let height = usize::from(inner.height);
+    let target = preview.line.unwrap_or(1).saturating_sub(1);
+    let first = target
+        .saturating_sub(height / 2)
+        .min(preview.lines.len().saturating_sub(height));
+    let width = preview.lines.len().to_string().len();
+    let lines: Vec<Line> = preview
+        .lines
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(height)
+        .map(|(i, text)| {
+            let number = Span::styled(
+                format!("{:>width$} │ ", i + 1, width = width),
+                Style::default().fg(Color::DarkGray),
+            );
+            if preview.line == Some(i + 1) {
+                Line::from(vec![
+                    number,
+                    Span::styled(
+                        text.clone(),
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    ),
+                ])
+            } else {
+                Line::from(vec![number, Span::raw(text.clone())])
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::app::Preview;
+    use super::*;
+    use crate::analysis_result::{AnalysisResult, CategorizedViolation};
+    use crate::autofix::Violation;
+    use crate::config::{ArchPattern, ForbiddenRule};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn test_draw_shows_score_violation_and_offending_import() {
+        let mut result = AnalysisResult::new("demo".to_string(), ArchPattern::Hexagonal);
+        result.files_analyzed = 2;
+        result.add_violation(CategorizedViolation::new(
+            Violation {
+                file_path: PathBuf::from("/project/src/domain/user.ts"),
+                file_content: String::new(),
+                offensive_import: "import { db } from '../infra/db';".to_string(),
+                rule: ForbiddenRule {
+                    from: "src/domain/".to_string(),
+                    to: "src/infra/".to_string(),
+                    reason: Some("El dominio es puro".to_string()),
+                    ..Default::default()
+                },
+                line_number: 2,
+            },
+            ViolationCategory::Blocked,
+        ));
+        result.health_score = Some(crate::scoring::calculate(&result));
+        let mut app = App::new(result, Path::new("/project"));
+        app.preview = Some(Preview {
+            file: PathBuf::from("/project/src/domain/user.ts"),
+            line: Some(2),
+            lines: vec![
+                "// user".to_string(),
+                "import { db } from '../infra/db';".to_string(),
+            ],
+        });
+
+        let mut terminal = Terminal::new(TestBackend::new(140, 20)).unwrap();
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Health score"));
+        assert!(screen.contains("src/domain/user.ts:2"));
+        assert!(screen.contains("import { db } from '../infra/db';"));
+        assert!(screen.contains("El dominio es puro"));
+    }
+}
@@ -0,0 +1,780 @@
+//! Monorepo workspaces
+//!
+//! Packages are discovered from the workspace manifests found at the root:
+//! npm/yarn `workspaces` (Turborepo uses the same field), `pnpm-workspace.yaml`,
+//! Nx `project.json` files, Cargo `[workspace].members`, uv workspace members
+//! and Python namespace packages (PEP 420). `workspace.packages` in the root
+//! config replaces the automatic detection. Once the project is a workspace
+//! (the root config has a `workspace` section or a manifest declares
+//! members), any directory with its own `architect.json` is a package too;
+//! otherwise nested configs (test fixtures, examples, templates) are ignored.
+//!
+//! Every package is analyzed with its own architect.json merged over the root
+//! one (see `config::merge`), and the results are aggregated into one report
+//! with a score per package. Files outside every package form the `(root)`
+//! package.
+//!
+//! Rules between packages live in the root config, matching package paths or
+//! names: `"workspace": { "rules": [{ "from": "libs/*", "to": "apps/*" }] }`.
+
+use crate::analysis_result::AnalysisResult;
+use crate::analyzer::collector::without_skipped;
+use crate::analyzer::dead_rules::{find_dead_rules, DeadRule};
+use crate::autofix::Violation;
+use crate::cache::{self, AnalysisCache};
+use crate::config::formats::ConfigSource;
+use crate::config::{
+    self, ForbiddenRule, GeneratedCodeMode, LinterContext, Severity, WorkspaceConfig,
+};
+use crate::gitpattern::{glob_match, GitPattern};
+use crate::parsers::resolve::resolve_import;
+use crate::parsers::Import;
+use miette::{miette, Result, WrapErr};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use walkdir::WalkDir;
+
+/// Name of the package holding the files outside every workspace package
+pub const ROOT_PACKAGE: &str = "(root)";
+
+/// Directories never searched for packages
+const SKIPPED_DIRS: [&str; 8] = [
+    "node_modules",
+    "target",
+    "dist",
+    "build",
+    "vendor",
+    "venv",
+    "__pycache__",
+    "coverage",
+];
+
+/// How deep below the root packages are searched for
+const MAX_DEPTH: usize = 6;
+
+/// One package of the workspace
+#[derive(Debug, Clone)]
+pub struct WorkspacePackage {
+    /// Manifest name (package.json, Cargo.toml, pyproject.toml, project.json),
+    /// or the path when there is none
+    pub name: String,
+    /// Directory relative to the workspace root, `/`-separated
+    pub path: String,
+    /// Absolute directory
+    pub dir: PathBuf,
+    /// The package's own architect.json, if any
+    pub config: Option<PathBuf>,
+    /// Specifiers other packages import it by (npm name, Python module, crate)
+    import_names: Vec<String>,
+}
+
+/// A discovered monorepo
+#[derive(Debug)]
+pub struct Workspace {
+    pub root: PathBuf,
+    /// Packages sorted by path
+    pub packages: Vec<WorkspacePackage>,
+    /// Rules between packages (`workspace.rules`)
+    pub rules: Vec<ForbiddenRule>,
+    /// Root architect.json, without the `workspace` section
+    root_config: Value,
+}
+
+impl Workspace {
+    /// Discover the workspace rooted at `root`. None when the root has no
+    /// architect.json, declares no workspace or no packages are found.
+    pub fn discover(root: &Path) -> Result<Option<Self>> {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let Some(config_path) = config::find_config(&root) else {
+            return Ok(None);
+        };
+        let root_config = ConfigSource::read(&config_path)?.parse()?;
+        let mut root_config = config::extends::resolve_extends(&root, root_config)?;
+        let section = root_config
+            .as_object_mut()
+            .and_then(|o| o.remove("workspace"));
+        let declared = section.is_some();
+        let settings: WorkspaceConfig = match section {
+            Some(value) => serde_json::from_value(value)
+                .map_err(|e| miette!("Sección workspace inválida en architect.json: {}", e))?,
+            None => WorkspaceConfig::default(),
+        };
+        let ignored: Vec<String> = match root_config.get("ignored_paths") {
+            Some(Value::Array(paths)) => paths
+                .iter()
+                .filter_map(|p| p.as_str().map(String::from))
+                .collect(),
+            _ => config::default_ignored_paths(),
+        };
+
+        let dirs = walk_dirs(&root, &ignored);
+        let mut found: Vec<String> = if settings.packages.is_empty() {
+            detect_members(&root, &dirs)
+        } else {
+            expand_members(&settings.packages, &dirs, |_| true)
+        };
+        if declared || !found.is_empty() {
+            found.extend(
+                dirs.iter()
+                    .filter(|dir| config::find_config(&root.join(dir)).is_some())
+                    .cloned(),
+            );
+        }
+        found.sort();
+        found.dedup();
+
+        if found.is_empty() {
+            return Ok(None);
+        }
+        let packages = found
+            .into_iter()
+            .map(|path| WorkspacePackage::read(&root, path))
+            .collect();
+
+        Ok(Some(Self {
+            root,
+            packages,
+            rules: settings.rules,
+            root_config,
+        }))
+    }
+
+    /// `path` relative to the workspace root, `/`-separated
+    fn relative(&self, path: &Path) -> Option<String> {
+        let rel = match path.strip_prefix(&self.root) {
+            Ok(rel) => rel.to_path_buf(),
+            Err(_) => path
+                .canonicalize()
+                .ok()?
+                .strip_prefix(&self.root)
+                .ok()?
+                .to_path_buf(),
+        };
+        Some(rel.to_string_lossy().replace('\\', "/"))
+    }
+
+    /// The innermost package containing `path`
+    pub fn package_of(&self, path: &Path) -> Option<&WorkspacePackage> {
+        let rel = self.relative(path)?;
+        self.packages
+            .iter()
+            .filter(|p| rel == p.path || rel.starts_with(&format!("{}/", p.path)))
+            .max_by_key(|p| p.path.len())
+    }
+
+    /// The package an import of `file_path` points to
+    fn target_package(&self, file_path: &Path, source: &str) -> Option<&WorkspacePackage> {
+        if let Some(package) = resolve_import(file_path, source, &self.root)
+            .and_then(|target| self.package_of(&target))
+        {
+            return Some(package);
+        }
+        self.packages.iter().find(|p| {
+            p.import_names.iter().any(|name| {
+                source == name
+                    || [b'/', b'.', b':']
+                        .iter()
+                        .any(|&sep| source.as_bytes().get(name.len()) == Some(&sep))
+                        && source.starts_with(name.as_str())
+            })
+        })
+    }
+
+    /// Check the imports of a file against the rules between packages
+    pub fn find_violations(
+        &self,
+        file_path: &Path,
+        source_code: &str,
+        imports: &[Import],
+    ) -> Vec<Violation> {
+        if self.rules.is_empty() {
+            return Vec::new();
+        }
+        let Some(from) = self.package_of(file_path) else {
+            return Vec::new();
+        };
+
+        let mut violations = Vec::new();
+        for import in imports {
+            let Some(to) = self.target_package(file_path, &import.source) else {
+                continue;
+            };
+            if to.path == from.path {
+                continue;
+            }
+            let rule = self
+                .rules
+                .iter()
+                .find(|rule| from.matches(&rule.from) && to.matches(&rule.to));
+            if let Some(rule) = rule {
+                violations.push(Violation {
+                    file_path: file_path.to_path_buf(),
+                    file_content: source_code.to_string(),
+                    offensive_import: import.raw_statement.clone(),
+                    rule: ForbiddenRule {
+                        reason: Some(rule.reason.clone().unwrap_or_else(|| {
+                            format!("Package '{}' must not import '{}'", from.name, to.name)
+                        })),
+                        ..rule.clone()
+                    },
+                    line_number: import.line_number,
+                });
+            }
+        }
+        violations
+    }
+
+    /// Rules and packages as text, so caches notice when either changes
+    pub fn fingerprint(&self) -> String {
+        let mut data = String::new();
+        for rule in &self.rules {
+            data.push_str(&format!("workspace:{}:{};", rule.from, rule.to));
+        }
+        for package in &self.packages {
+            data.push_str(&format!(
+                "package:{}={};",
+                package.path,
+                package.import_names.join(",")
+            ));
+        }
+        data
+    }
+
+    /// Configuration of a package: its architect.json merged over the root one
+    pub fn load_package_config(&self, package: &WorkspacePackage) -> Result<LinterContext> {
+        let value = match &package.config {
+            Some(path) => {
+                let mut overlay = ConfigSource::read(path)?.parse()?;
+                if let Some(object) = overlay.as_object_mut() {
+                    object.remove("workspace");
+                }
+                let overlay = config::extends::resolve_extends(&package.dir, overlay)?;
+                config::merge::merge(self.root_config.clone(), overlay)
+            }
+            None => self.root_config.clone(),
+        };
+        config::load_config_value(&package.dir, value)
+            .wrap_err_with(|| format!("Configuración del paquete '{}'", package.path))
+    }
+}
+
+impl WorkspacePackage {
+    fn read(root: &Path, path: String) -> Self {
+        let dir = root.join(&path);
+        let mut names: Vec<String> = Vec::new();
+        let mut import_names = Vec::new();
+
+        if let Some(name) = read_json(&dir.join("package.json"))
+            .and_then(|v| v.get("name")?.as_str().map(String::from))
+        {
+            import_names.push(name.clone());
+            names.push(name);
+        }
+        if let Some(name) = read_toml(&dir.join("Cargo.toml"))
+            .and_then(|t| t.get("package")?.get("name")?.as_str().map(String::from))
+        {
+            import_names.push(name.replace('-', "_"));
+            names.push(name);
+        }
+        if let Some(pyproject) = read_toml(&dir.join("pyproject.toml")) {
+            let name = pyproject
+                .get("project")
+                .and_then(|p| p.get("name"))
+                .or_else(|| pyproject.get("tool")?.get("poetry")?.get("name"))
+                .and_then(|n| n.as_str());
+            if let Some(name) = name {
+                import_names.push(crate::packages::normalize_python_name(name));
+                names.push(name.to_string());
+            }
+        }
+        if let Some(name) = read_json(&dir.join("project.json"))
+            .and_then(|v| v.get("name")?.as_str().map(String::from))
+        {
+            names.push(name);
+        }
+        if is_python_package(&dir) {
+            // Namespace packages are imported by their dotted path
+            let module = path.strip_prefix("src/").unwrap_or(&path).replace('/', ".");
+            import_names.push(module.clone());
+            names.push(module);
+        }
+
+        let config = config::find_config(&dir);
+        Self {
+            name: names.into_iter().next().unwrap_or_else(|| path.clone()),
+            path,
+            dir,
+            config,
+            import_names,
+        }
+    }
+
+    /// Whether a rule side (glob) designates this package, by path or name
+    pub fn matches(&self, pattern: &str) -> bool {
+        let pattern = pattern
+            .trim()
+            .trim_start_matches("./")
+            .trim_end_matches('/');
+        glob_match(pattern, &self.path) || glob_match(pattern, &self.name)
+    }
+}
+
+/// Package directories declared by the workspace manifests at the root
+fn detect_members(root: &Path, dirs: &[String]) -> Vec<String> {
+    let has = |dir: &str, file: &str| root.join(dir).join(file).is_file();
+    let mut members = Vec::new();
+
+    // npm / yarn / Turborepo: "workspaces": [...] or { "packages": [...] }
+    if let Some(package) = read_json(&root.join("package.json")) {
+        let workspaces = package.get("workspaces");
+        let patterns = workspaces
+            .and_then(|w| w.get("packages"))
+            .or(workspaces)
+            .map(string_list)
+            .unwrap_or_default();
+        members.extend(expand_members(&patterns, dirs, |d| has(d, "package.json")));
+    }
+
+    // pnpm
+    if let Ok(content) = fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        let patterns = pnpm_packages(&content);
+        members.extend(expand_members(&patterns, dirs, |d| has(d, "package.json")));
+    }
+
+    // Nx: every project.json is a project
+    if root.join("nx.json").is_file() {
+        members.extend(dirs.iter().filter(|d| has(d, "project.json")).cloned());
+    }
+
+    // Cargo workspaces and uv workspaces
+    if let Some(cargo) = read_toml(&root.join("Cargo.toml")) {
+        if let Some(workspace) = cargo.get("workspace") {
+            let mut patterns = workspace.get("members").map(toml_list).unwrap_or_default();
+            let excluded = workspace.get("exclude").map(toml_list).unwrap_or_default();
+            patterns.extend(excluded.into_iter().map(|e| format!("!{}", e)));
+            members.extend(expand_members(&patterns, dirs, |d| has(d, "Cargo.toml")));
+        }
+    }
+    if let Some(pyproject) = read_toml(&root.join("pyproject.toml")) {
+        if let Some(workspace) = pyproject
+            .get("tool")
+            .and_then(|t| t.get("uv"))
+            .and_then(|u| u.get("workspace"))
+        {
+            let mut patterns = workspace.get("members").map(toml_list).unwrap_or_default();
+            let excluded = workspace.get("exclude").map(toml_list).unwrap_or_default();
+            patterns.extend(excluded.into_iter().map(|e| format!("!{}", e)));
+            members.extend(expand_members(&patterns, dirs, |d| {
+                has(d, "pyproject.toml")
+            }));
+        }
+    }
+
+    // Python namespace packages: each regular package below a directory
+    // without __init__.py (e.g. acme/billing, acme/identity)
+    let python_project = ["pyproject.toml", "setup.py", "setup.cfg"]
+        .iter()
+        .any(|f| root.join(f).is_file());
+    if python_project {
+        members.extend(
+            dirs.iter()
+                .filter(|d| is_namespace_member(root, d))
+                .cloned(),
+        );
+    }
+
+    members
+}
+
+/// Directories matching the patterns (`!` excludes) and `accept`
+fn expand_members(
+    patterns: &[String],
+    dirs: &[String],
+    accept: impl Fn(&str) -> bool,
+) -> Vec<String> {
+    let compile = |p: &str| {
+        let p = p.trim().trim_start_matches("./").trim_end_matches('/');
+        GitPattern::parse(&format!("/{}", p))
+    };
+    let (excluded, included): (Vec<&String>, Vec<&String>) =
+        patterns.iter().partition(|p| p.starts_with('!'));
+    let included: Vec<GitPattern> = included.iter().filter_map(|p| compile(p)).collect();
+    let excluded: Vec<GitPattern> = excluded.iter().filter_map(|p| compile(&p[1..])).collect();
+
+    dirs.iter()
+        .filter(|d| included.iter().any(|p| p.matches(d, true)))
+        .filter(|d| !excluded.iter().any(|p| p.matches(d, true)))
+        .filter(|d| accept(d))
+        .cloned()
+        .collect()
+}
+
+/// Every directory below `root` (relative), skipping ignored and hidden ones
+fn walk_dirs(root: &Path, ignored: &[String]) -> Vec<String> {
+    WalkDir::new(root)
+        .min_depth(1)
+        .max_depth(MAX_DEPTH)
+        .into_iter()
+        .filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            e.file_type().is_dir()
+                && !name.starts_with('.')
+                && !SKIPPED_DIRS.contains(&name.as_ref())
+                && crate::discovery::is_not_ignored_with_patterns(e, root, ignored)
+        })
+        .filter_map(|e| e.ok())
+        .filter_map(|e| {
+            let rel = e.path().strip_prefix(root).ok()?;
+            Some(rel.to_string_lossy().replace('\\', "/"))
+        })
+        .collect()
+}
+
+/// `packages:` entries of a pnpm-workspace.yaml
+fn pnpm_packages(content: &str) -> Vec<String> {
+    let mut packages = Vec::new();
+    let mut in_packages = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) && !trimmed.starts_with('-') {
+            in_packages = trimmed.starts_with("packages:");
+            continue;
+        }
+        if let Some(entry) = trimmed.strip_prefix('-').filter(|_| in_packages) {
+            let entry = entry.split(" #").next().unwrap_or(entry);
+            packages.push(
+                entry
+                    .trim()
+                    .trim_matches(|c| c == '\'' || c == '"')
+                    .to_string(),
+            );
+        }
+    }
+    packages
+}
+
+/// A regular Python package (has `__init__.py`)
+fn is_python_package(dir: &Path) -> bool {
+    dir.join("__init__.py").is_file()
+}
+
+/// A regular package directly inside a PEP 420 namespace package
+fn is_namespace_member(root: &Path, rel: &str) -> bool {
+    let dir = root.join(rel);
+    let Some(parent) = dir.parent() else {
+        return false;
+    };
+    if parent == root || parent == root.join("src") || !is_python_package(&dir) {
+        return false;
+    }
+    // The namespace holds no code of its own, only packages
+    let namespace_has_modules = fs::read_dir(parent)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .any(|e| {
+            let path = e.path();
+            path.is_file() && path.extension().is_some_and(|ext| ext == "py")
+        });
+    !is_python_package(parent)
+        && !namespace_has_modules
+        && parent
+            .parent()
+            .is_some_and(|grand| grand == root || grand == root.join("src"))
+}
+
+fn read_json(path: &Path) -> Option<Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+fn read_toml(path: &Path) -> Option<toml::Table> {
+    fs::read_to_string(path).ok()?.parse().ok()
+}
+
+fn string_list(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|a| {
+            a.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn toml_list(value: &toml::Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|a| {
+            a.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Check the imports of a file against the workspace rules of `ctx`
+pub fn find_workspace_violations(
+    file_path: &Path,
+    source_code: &str,
+    imports: &[Import],
+    ctx: &LinterContext,
+) -> Vec<Violation> {
+    match ctx.workspace.as_ref() {
+        Some(workspace) => workspace.find_violations(file_path, source_code, imports),
+        None => Vec::new(),
+    }
+}
+
+/// Result of one package
+#[derive(Debug, Clone)]
+pub struct PackageAnalysis {
+    pub name: String,
+    /// Directory relative to the workspace root (`.` for the root package)
+    pub path: String,
+    /// Whether the package has its own architect.json
+    pub has_config: bool,
+    /// How the package's config treats generated code
+    pub generated_mode: GeneratedCodeMode,
+    /// `forbidden_imports` rules of the package's config that never apply
+    pub dead_rules: Vec<DeadRule>,
+    pub result: AnalysisResult,
+}
+
+/// Result of a whole workspace
+#[derive(Debug, Clone)]
+pub struct WorkspaceResult {
+    pub packages: Vec<PackageAnalysis>,
+    /// Every package together, scored as one project
+    pub combined: AnalysisResult,
+}
+
+/// Analyze every package with its own configuration and aggregate the results.
+/// With `use_cache`, each package keeps its analysis cache in its own directory.
+pub fn analyze_workspace(
+    workspace: Arc<Workspace>,
+    min_severity: Severity,
+    use_cache: bool,
+) -> Result<WorkspaceResult> {
+    let root = workspace.root.clone();
+    let root_package = WorkspacePackage {
+        name: ROOT_PACKAGE.to_string(),
+        path: ".".to_string(),
+        dir: root.clone(),
+        config: None,
+        import_names: Vec::new(),
+    };
+    let root_ctx = config::load_config_value(&root, workspace.root_config.clone())?;
+
+    // Files of each package, excluding those of nested packages
+    let mut files: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    for file in crate::discovery::collect_files(&root, &root_ctx.ignored_paths) {
+        let owner = workspace
+            .package_of(&file)
+            .map_or_else(|| root_package.path.clone(), |p| p.path.clone());
+        files.entry(owner).or_default().push(file);
+    }
+
+    let mut packages = Vec::new();
+    let mut cycle_files = Vec::new();
+    for package in std::iter::once(&root_package).chain(&workspace.packages) {
+        let package_files = files.remove(&package.path).unwrap_or_default();
+        if package.path == root_package.path && package_files.is_empty() {
+            continue;
+        }
+        let mut ctx = if package.path == root_package.path {
+            config::load_config_value(&root, workspace.root_config.clone())?
+        } else {
+            workspace.load_package_config(package)?
+        };
+        ctx.workspace = Some(Arc::clone(&workspace));
+
+        let config_hash = cache::hash_config(&ctx);
+        let mut analysis_cache = if use_cache {
+            AnalysisCache::load(&package.dir, &config_hash)
+                .unwrap_or_else(|| AnalysisCache::new(config_hash.clone()))
+        } else {
+            AnalysisCache::new(config_hash)
+        };
+        let mut result = crate::analyzer::analyze_all_files(
+            &package_files,
+            &package.dir,
+            ctx.pattern.clone(),
+            &ctx,
+            use_cache.then_some(&mut analysis_cache),
+        )?;
+        if use_cache {
+            if let Err(e) = analysis_cache.save(&package.dir) {
+                eprintln!("⚠️  Could not save analysis cache: {}", e);
+            }
+        }
+        result.project_name = package.name.clone();
+        result.filter_by_severity(min_severity);
+
+        let package_files = without_skipped(&package_files, &result, &ctx);
+        let dead_rules = find_dead_rules(
+            &ctx.forbidden_imports,
+            &package_files,
+            Some(&result.import_sources),
+        );
+        cycle_files.extend(package_files);
+
+        packages.push(PackageAnalysis {
+            name: package.name.clone(),
+            path: package.path.clone(),
+            has_config: package.config.is_some(),
+            generated_mode: ctx.generated_code.mode,
+            dead_rules,
+            result,
+        });
+    }
+
+    // Cycles are searched across the whole workspace (without the generated
+    // code each package skips) and reported in the package of their first file
+    let mut cycles: BTreeMap<String, Vec<_>> = BTreeMap::new();
+    match crate::circular::analyze_circular_dependencies(&cycle_files, &root) {
+        Ok(found) => {
+            for cycle in found {
+                let owner = cycle
+                    .cycle
+                    .first()
+                    .and_then(|node| workspace.package_of(&root.join(node)))
+                    .map_or_else(|| root_package.path.clone(), |p| p.path.clone());
+                cycles.entry(owner).or_default().push(cycle);
+            }
+        }
+        Err(e) => eprintln!("⚠️  No se pudo analizar dependencias cíclicas: {}", e),
+    }
+    for package in &mut packages {
+        for cycle in cycles.remove(&package.path).unwrap_or_default() {
+            package.result.add_circular_dependency(cycle);
+        }
+        package.result.health_score = Some(crate::scoring::calculate(&package.result));
+    }
+
+    let mut combined = AnalysisResult::new(
+        root.file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("project")
+            .to_string(),
+        root_ctx.pattern.clone(),
+    );
+    combined.complexity_stats.max_lines_threshold = root_ctx.max_lines;
+    for package in &packages {
+        let result = &package.result;
+        combined.files_analyzed += result.files_analyzed;
+        combined
+            .violations
+            .extend(result.violations.iter().cloned());
+        combined
+            .circular_dependencies
+            .extend(result.circular_dependencies.iter().cloned());
+        combined
+            .long_functions
+            .extend(result.long_functions.iter().cloned());
+        combined
+            .generated_files
+            .extend(result.generated_files.iter().cloned());
+        combined.layer_stats.total_imports += result.layer_stats.total_imports;
+        combined.layer_stats.blocked_violations += result.layer_stats.blocked_violations;
+        combined.complexity_stats.total_functions += result.complexity_stats.total_functions;
+        combined.complexity_stats.long_functions += result.complexity_stats.long_functions;
+    }
+    combined.health_score = Some(crate::scoring::calculate(&combined));
+
+    Ok(WorkspaceResult { packages, combined })
+}
+
+impl WorkspaceResult {
+    /// Whether any package has critical issues
+    pub fn has_critical_issues(&self) -> bool {
+        self.combined.has_critical_issues()
+    }
+
+    /// Per-package summary, as included in the JSON report
+    pub fn packages_json(&self) -> Value {
+        Value::Array(
+            self.packages
+                .iter()
+                .map(|p| {
+                    let score = p.result.health_score.as_ref();
+                    json!({
+                        "name": p.name,
+                        "path": p.path,
+                        "has_config": p.has_config,
+                        "score": score.map(|s| s.total),
+                        "grade": score.map(|s| s.grade.as_str()),
+                        "files_analyzed": p.result.files_analyzed,
+                        "violations": p.result.violations.len(),
+                        "circular_dependencies": p.result.circular_dependencies.len(),
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// JSON report of the combined result with a `packages` array
+    pub fn to_json(&self) -> String {
+        let report = crate::report::to_json(&self.combined);
+        let mut value: Value = serde_json::from_str(&report).unwrap_or_else(|_| json!({}));
+        if let Some(object) = value.as_object_mut() {
+            object.insert("packages".to_string(), self.packages_json());
+        }
+        serde_json::to_string_pretty(&value).unwrap_or(report)
+    }
+
+    /// Markdown report of the combined result followed by a package table
+    pub fn to_markdown(&self) -> String {
+        let mut md = crate::report::to_markdown(&self.combined);
+        md.push_str("\n## Packages\n\n");
+        md.push_str("| Package | Path | Score | Grade | Violations | Files |\n");
+        md.push_str("|---------|------|-------|-------|------------|-------|\n");
+        for p in &self.packages {
+            let score = p.result.health_score.as_ref();
+            md.push_str(&format!(
+                "| {} | `{}` | {} | {} | {} | {} |\n",
+                p.name,
+                p.path,
+                score.map_or("-".to_string(), |s| s.total.to_string()),
+                score.map_or("-", |s| s.grade.as_str()),
+                p.result.violations.len(),
+                p.result.files_analyzed,
+            ));
+        }
+        md
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pnpm_packages() {
+        let content = "packages:\n  - 'apps/*'\n  - \"libs/**\" # shared\n  - '!**/test/**'\ncatalog:\n  - react\n";
+        assert_eq!(pnpm_packages(content), ["apps/*", "libs/**", "!**/test/**"]);
+    }
+
+    #[test]
+    fn test_expand_members_with_negation() {
+        let dirs: Vec<String> = ["apps", "apps/web", "apps/legacy", "libs", "libs/ui"]
+            .iter()
+            .map(|d| d.to_string())
+            .collect();
+        let patterns = vec![
+            "apps/*".to_string(),
+            "./libs/*/".to_string(),
+            "!apps/legacy".to_string(),
+        ];
+        assert_eq!(
+            expand_members(&patterns, &dirs, |_| true),
+            ["apps/web", "libs/ui"]
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Tests for git blame attribution (`--blame`)
+//!
+//! These tests verify that violations are annotated with the commit that
+//! introduced the offending line and with their CODEOWNERS teams.
+
+use architect_linter_pro::{analyzer, blame, config, report};
+use tempfile::TempDir;
+
+/// Helper to commit the given files on top of HEAD as `author`
+fn commit_as(repo: &git2::Repository, author: &str, files: &[(&str, &str)]) {
+    let workdir = repo.workdir().unwrap().to_path_buf();
+    let mut index = repo.index().unwrap();
+    for (path, content) in files {
+        let full = workdir.join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(&full, content).unwrap();
+        index.add_path(std::path::Path::new(path)).unwrap();
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let email = format!("{}@example.com", author.to_lowercase());
+    let sig = git2::Signature::now(author, &email).unwrap();
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, "change", &tree, &parents)
+        .unwrap();
+}
+
+#[test]
+fn test_blame_attributes_violations_to_authors_and_teams() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    let repo = git2::Repository::init(&root).unwrap();
+
+    commit_as(
+        &repo,
+        "Ana",
+        &[
+            (
+                "architect.json",
+                r#"{
+  "max_lines_per_function": 50,
+  "architecture_pattern": "MVC",
+  "forbidden_imports": [{ "from": "controller", "to": "repository" }]
+}"#,
+            ),
+            (
+                ".github/CODEOWNERS",
+                "*  @acme/platform\n/src/billing/  @acme/payments\n",
+            ),
+            (
+                "src/billing/invoice.repository.ts",
+                "export class InvoiceRepository {}\n",
+            ),
+            (
+                "src/users/user.repository.ts",
+                "export class UserRepository {}\n",
+            ),
+            (
+                "src/users/user.controller.ts",
+                "export class UserController {}\n",
+            ),
+        ],
+    );
+    commit_as(
+        &repo,
+        "Bruno",
+        &[(
+            "src/billing/invoice.controller.ts",
+            "import { InvoiceRepository } from './invoice.repository';\nexport class InvoiceController {}\n",
+        )],
+    );
+    // Uncommitted edit: no author to blame yet
+    std::fs::write(
+        root.join("src/users/user.controller.ts"),
+        "import { UserRepository } from './user.repository';\nexport class UserController {}\n",
+    )
+    .unwrap();
+
+    let ctx = config::load_config(&root).unwrap();
+    let mut result = analyzer::analyze_project(&root, &ctx).unwrap();
+    assert_eq!(result.violations.len(), 2);
+
+    let blamed = blame::annotate(&mut result, &root);
+    assert_eq!(blamed, 1);
+
+    let billing = result
+        .violations
+        .iter()
+        .find(|cv| cv.violation.file_path.ends_with("invoice.controller.ts"))
+        .unwrap();
+    let info = billing.blame.as_ref().unwrap();
+    assert_eq!(info.author, "Bruno");
+    assert_eq!(
+        info.commit,
+        repo.head().unwrap().target().unwrap().to_string()
+    );
+    assert_eq!(billing.owners, ["@acme/payments"]);
+
+    let attribution = blame::attribution(&result);
+    assert_eq!(attribution.by_author["Bruno <bruno@example.com>"], 1);
+    assert_eq!(attribution.by_author[blame::UNCOMMITTED], 1);
+    assert_eq!(attribution.by_team["@acme/payments"], 1);
+    assert_eq!(attribution.by_team["@acme/platform"], 1);
+
+    let json: serde_json::Value = serde_json::from_str(&report::to_json(&result)).unwrap();
+    assert_eq!(json["attribution"]["by_team"]["@acme/payments"], 1);
+    assert!(report::to_markdown(&result).contains("## Ownership"));
+}
@@ -0,0 +1,96 @@
+//! Tests for module boundaries (`modules` in architect.json)
+//!
+//! These tests verify that deep imports into a module from outside are
+//! reported while imports through its public entry point are allowed.
+
+mod common;
+use architect_linter_pro::{analyzer, config};
+use common::TestProject;
+
+fn create_modular_project(modules: &str) -> TestProject {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.json",
+        &format!(
+            r#"{{
+  "max_lines_per_function": 50,
+  "architecture_pattern": "Ninguno",
+  "forbidden_imports": [],
+  "modules": {}
+}}"#,
+            modules
+        ),
+    );
+    project.create_file(
+        "src/modules/billing/index.ts",
+        "export { Invoice } from './invoice';\n",
+    );
+    project.create_file(
+        "src/modules/billing/invoice.ts",
+        "import { helper } from './internal/helper';\nexport class Invoice {}\n",
+    );
+    project.create_file(
+        "src/modules/billing/internal/helper.ts",
+        "export const helper = 1;\n",
+    );
+    project.create_file(
+        "src/modules/users/user.ts",
+        "import { Invoice } from '../billing';\nimport { helper } from '../billing/internal/helper';\nexport class User {}\n",
+    );
+    project.create_file(
+        "src/app.ts",
+        "import { Invoice } from './modules/billing/invoice';\nimport { User } from '@/modules/users/user';\n",
+    );
+    project
+}
+
+#[test]
+fn test_deep_imports_into_modules_are_reported() {
+    let project = create_modular_project(r#"[{ "path": "src/modules/*" }]"#);
+    let root = project.path().canonicalize().unwrap();
+
+    let ctx = config::load_config(&root).unwrap();
+    let result = analyzer::analyze_project(&root, &ctx).unwrap();
+
+    let mut offending: Vec<String> = result
+        .violations
+        .iter()
+        .map(|cv| cv.violation.offensive_import.clone())
+        .collect();
+    offending.sort();
+    assert_eq!(offending.len(), 3, "{:?}", offending);
+    assert!(offending[0].contains("'../billing/internal/helper'"));
+    assert!(offending[1].contains("'./modules/billing/invoice'"));
+    assert!(offending[2].contains("'@/modules/users/user'"));
+}
+
+#[test]
+fn test_module_exports_and_severity() {
+    let project = create_modular_project(
+        r#"[{ "path": "src/modules/billing", "exports": ["invoice.ts"], "severity": "warning" }]"#,
+    );
+    let root = project.path().canonicalize().unwrap();
+
+    let ctx = config::load_config(&root).unwrap();
+    let result = analyzer::analyze_project(&root, &ctx).unwrap();
+
+    // invoice.ts is exported; the internal helper is not
+    assert_eq!(result.violations.len(), 1);
+    let cv = &result.violations[0];
+    assert!(cv
+        .violation
+        .file_path
+        .ends_with("src/modules/users/user.ts"));
+    assert!(cv
+        .violation
+        .offensive_import
+        .contains("../billing/internal/helper"));
+    assert_eq!(cv.violation.rule.severity, Some(config::Severity::Warning));
+}
+
+#[test]
+fn test_duplicate_module_paths_are_rejected() {
+    let project =
+        create_modular_project(r#"[{ "path": "src/modules/*" }, { "path": "src/modules/*/" }]"#);
+    assert!(config::load_config(project.path()).is_err());
+}
@@ -18,6 +18,7 @@ fn test_analysis_cache_basic_ops() {
         long_functions: vec![],
         import_count: 1,
         function_count: 1,
+        import_sources: vec![],
     };
 
     cache.insert(key.clone(), entry);
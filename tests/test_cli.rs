@@ -219,9 +219,16 @@ fn test_analysis_without_config() {
 fn test_analysis_with_violations() {
     let project = TestProject::new();
 
-    // Create config that forbids controller -> repository
-    let rule = common::forbidden_rule("/controller/", "/repository/");
-    project.create_config("MVC", 100, &rule);
+    // Create config that forbids controller -> repository via the MVC preset
+    project.create_file(
+        "architect.json",
+        r#"{
+  "max_lines_per_function": 100,
+  "architecture_pattern": "MVC",
+  "forbidden_imports": [{"from": "/controller/", "to": "/repository/"}],
+  "presets": { "enabled": true }
+}"#,
+    );
 
     // Create violation
     project.create_file(
@@ -0,0 +1,85 @@
+//! Tests for CODEOWNERS-aware rules and team grouping
+//!
+//! These tests verify that `owner:@team` rules are resolved through
+//! CODEOWNERS and that violations carry their owning teams.
+
+mod common;
+use architect_linter_pro::{analyzer, config, report};
+use common::TestProject;
+
+fn create_owned_project(rules: &str) -> TestProject {
+    let project = TestProject::new();
+    project.create_config("Ninguno", 50, rules);
+    project.create_file(
+        ".github/CODEOWNERS",
+        "*                 @acme/platform\n/src/payments/    @acme/payments\n/src/identity/    @acme/identity\n",
+    );
+    project.create_file("src/identity/session.ts", "export class Session {}\n");
+    project.create_file(
+        "src/payments/charge.ts",
+        "import { Session } from '../identity/session';\nimport { Client } from '@acme/identity';\nexport class Charge {}\n",
+    );
+    project.create_file(
+        "src/identity/user.ts",
+        "import { Charge } from '../payments/charge';\nexport class User {}\n",
+    );
+    project
+}
+
+#[test]
+fn test_owner_rule_flags_cross_team_imports() {
+    let project =
+        create_owned_project(r#"{ "from": "owner:@acme/payments", "to": "owner:@acme/identity" }"#);
+    let root = project.path().canonicalize().unwrap();
+
+    let ctx = config::load_config(&root).unwrap();
+    assert_eq!(ctx.owner_rules.len(), 1);
+    assert!(ctx.forbidden_imports.is_empty());
+
+    let result = analyzer::analyze_project(&root, &ctx).unwrap();
+
+    // Only the relative import into identity code; the npm package is not owned code
+    assert_eq!(result.violations.len(), 1);
+    let cv = &result.violations[0];
+    assert!(cv.violation.file_path.ends_with("src/payments/charge.ts"));
+    assert!(cv
+        .violation
+        .offensive_import
+        .contains("../identity/session"));
+    assert_eq!(cv.owners, ["@acme/payments"]);
+
+    let by_team = result.violations_by_team();
+    assert_eq!(by_team["@acme/payments"].len(), 1);
+
+    let markdown = report::to_markdown(&result);
+    assert!(markdown.contains("## Ownership"));
+    assert!(markdown.contains("| @acme/payments | 1 | 0 | 1 |"));
+}
+
+#[test]
+fn test_owner_rule_mixed_with_path() {
+    let project =
+        create_owned_project(r#"{ "from": "src/identity/", "to": "owner:@acme/payments" }"#);
+    let root = project.path().canonicalize().unwrap();
+
+    let ctx = config::load_config(&root).unwrap();
+    let result = analyzer::analyze_project(&root, &ctx).unwrap();
+
+    assert_eq!(result.violations.len(), 1);
+    assert!(result.violations[0]
+        .violation
+        .file_path
+        .ends_with("src/identity/user.ts"));
+}
+
+#[test]
+fn test_owner_rule_requires_codeowners() {
+    let project = TestProject::new();
+    project.create_config(
+        "Ninguno",
+        50,
+        r#"{ "from": "owner:@acme/payments", "to": "src/db/" }"#,
+    );
+
+    assert!(config::load_config(project.path()).is_err());
+}
@@ -381,3 +381,75 @@ fn test_load_config_default_ignored_paths() {
     // Should have at least .git as default ignored path
     assert!(!ctx.ignored_paths.is_empty());
 }
+
+// ============================================================================
+// Tests for pattern presets
+// ============================================================================
+
+#[test]
+fn test_presets_disabled_by_default() {
+    let project = TestProject::new();
+    create_config_file(
+        &project,
+        r#"{
+  "max_lines_per_function": 50,
+  "architecture_pattern": "MVC",
+  "forbidden_imports": []
+}"#,
+    );
+
+    let ctx = architect_linter_pro::config::load_config(project.path()).unwrap();
+    assert!(ctx.preset_rules.is_empty());
+}
+
+#[test]
+fn test_presets_enabled_with_disable_and_override() {
+    let project = TestProject::new();
+    create_config_file(
+        &project,
+        r#"{
+  "max_lines_per_function": 50,
+  "architecture_pattern": "Hexagonal",
+  "forbidden_imports": [],
+  "presets": {
+    "enabled": true,
+    "disabled": ["hexagonal/domain-application"],
+    "overrides": {
+      "hexagonal/application-infrastructure": { "severity": "warning" }
+    }
+  }
+}"#,
+    );
+
+    let ctx = architect_linter_pro::config::load_config(project.path()).unwrap();
+    let ids: Vec<&str> = ctx.preset_rules.iter().map(|r| r.id.as_str()).collect();
+    assert!(!ids.contains(&"hexagonal/domain-application"));
+    assert!(ids.contains(&"hexagonal/domain-infrastructure"));
+
+    let app = ctx
+        .preset_rules
+        .iter()
+        .find(|r| r.id == "hexagonal/application-infrastructure")
+        .unwrap();
+    assert_eq!(
+        app.rule.get_severity(),
+        architect_linter_pro::config::Severity::Warning
+    );
+}
+
+#[test]
+fn test_presets_unknown_rule_id_is_rejected() {
+    let project = TestProject::new();
+    create_config_file(
+        &project,
+        r#"{
+  "max_lines_per_function": 50,
+  "architecture_pattern": "MVC",
+  "forbidden_imports": [],
+  "presets": { "enabled": true, "disabled": ["mvc/does-not-exist"] }
+}"#,
+    );
+
+    let result = architect_linter_pro::config::load_config(project.path());
+    assert!(result.is_err());
+}
@@ -0,0 +1,159 @@
+//! Tests for config diagnostics (`--check`)
+//!
+//! These tests verify that every problem in the config is reported in one
+//! pass, pointing at the exact key or value, with suggestions for typos.
+
+mod common;
+use architect_linter_pro::config::diagnostics::check_config;
+use architect_linter_pro::config::load_config;
+use common::TestProject;
+use miette::Diagnostic;
+
+#[test]
+fn test_check_lists_all_problems_with_suggestions() {
+    let project = TestProject::new();
+    project.create_file("src/domain/user.ts", "export const user = 1;\n");
+    project.create_file(
+        "architect.json",
+        r#"{
+  "max_lines_per_function": 40,
+  "architecture_pattern": "Hexagnal",
+  "ignored_pahts": ["dist/"],
+  "forbidden_imports": [
+    { "from": "src/domain/", "to": "src/infra/", "severity": "warnign" },
+    { "from": "src/lib/", "to": "axios" }
+  ]
+}"#,
+    );
+
+    let report = check_config(project.path()).unwrap();
+    assert!(report.has_errors());
+    let messages: Vec<(String, Option<String>)> = report
+        .problems
+        .iter()
+        .map(|p| (p.message.clone(), p.help.clone()))
+        .collect();
+
+    // In file order: pattern typo, unknown key, invalid severity, dead rule
+    assert_eq!(messages.len(), 4, "{:?}", messages);
+    assert_eq!(
+        messages[0].1.as_deref(),
+        Some("¿Quisiste decir 'Hexagonal'?")
+    );
+    assert_eq!(
+        messages[1].1.as_deref(),
+        Some("¿Quisiste decir 'ignored_paths'?")
+    );
+    assert_eq!(messages[2].1.as_deref(), Some("¿Quisiste decir 'warning'?"));
+    assert!(messages[3].0.contains("'src/lib/'"));
+    assert!(!report.problems[3].is_error());
+}
+
+#[test]
+fn test_load_errors_point_at_the_value() {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.toml",
+        "max_lines_per_function = 40\narchitecture_pattern = \"MVC\"\nforbidden_imports = [{ from = \"a\", to = \"b\" }, { from = \"a\", to = \"b\" }]\n",
+    );
+
+    let err = match load_config(project.path()) {
+        Ok(_) => panic!("duplicated rules should be rejected"),
+        Err(err) => format!("{:?}", err),
+    };
+    assert!(err.contains("Regla duplicada: from 'a' to 'b'"), "{}", err);
+    assert!(err.contains("architect.toml:3:48"), "{}", err);
+}
+
+#[test]
+fn test_valid_config_has_no_problems() {
+    let project = TestProject::new();
+    project.create_file(
+        "src/domain/user.ts",
+        "import axios from 'axios';\nexport const user = axios;\n",
+    );
+    project.create_config(
+        "Hexagonal",
+        40,
+        r#"{ "from": "src/domain/", "to": "axios" }"#,
+    );
+
+    let report = check_config(project.path()).unwrap();
+    assert!(report.problems.is_empty(), "{}", report.summary);
+}
+
+#[test]
+fn test_check_reports_dead_rules() {
+    let project = TestProject::new();
+    project.create_file(
+        "src/domain/user.ts",
+        "import { db } from '../infra/db';\nexport const user = db;\n",
+    );
+    project.create_file("src/infra/db.ts", "export const db = 1;\n");
+    project.create_config(
+        "Hexagonal",
+        40,
+        r#"{ "from": "src/domain/", "to": "src/infra/" },
+    { "from": "src/domian/", "to": "src/infra/" },
+    { "from": "src/domain/", "to": "src/infrastructure/" },
+    { "from": "owner:@acme/web", "to": "owner:@acme/api" }"#,
+    );
+
+    let report = check_config(project.path()).unwrap();
+    let messages: Vec<&str> = report.problems.iter().map(|p| p.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        [
+            "Regla muerta: from 'src/domian/' no coincide con nada",
+            "Regla muerta: to 'src/infrastructure/' no coincide con nada",
+        ]
+    );
+    assert!(!report.has_errors());
+}
+
+#[test]
+fn test_extended_config_locates_its_own_problems() {
+    let project = TestProject::new();
+    project.create_file("src/domain/user.ts", "export const user = 1;\n");
+    project.create_file(
+        "org.architect.json",
+        r#"{
+  "forbidden_imports": [
+    { "from": "src/domain/", "to": "axios", "severity": "fatal" }
+  ]
+}"#,
+    );
+    project.create_file(
+        "architect.json",
+        r#"{
+  "extends": ["./org.architect.json"],
+  "max_lines_per_function": 40,
+  "architecture_pattern": "Hexagonal",
+  "forbidden_imports": [
+    { "from": "src/domain/", "to": "lodash", "severity": "warnign" }
+  ]
+}"#,
+    );
+
+    let report = check_config(project.path()).unwrap();
+    let own = report
+        .problems
+        .iter()
+        .find(|p| p.help.as_deref() == Some("¿Quisiste decir 'warning'?"))
+        .expect("own severity problem");
+    assert!(own.labels().is_some(), "{}", own.message);
+    assert!(!own.message.contains("(en /"), "{}", own.message);
+
+    // The base rule comes first in the merged config but lives in another file
+    let base = report
+        .problems
+        .iter()
+        .find(|p| p.message.contains("fatal"))
+        .expect("base severity problem");
+    assert!(base.labels().is_none());
+    assert!(
+        base.message.contains("(en /forbidden_imports/0/severity)"),
+        "{}",
+        base.message
+    );
+}
@@ -0,0 +1,115 @@
+//! Tests for YAML and TOML configuration files
+//!
+//! These tests verify that architect.yaml, architect.toml and the
+//! `[tool.architect]` section of pyproject.toml load like architect.json, and
+//! that schema errors point at the line and column of the original file.
+
+mod common;
+use architect_linter_pro::config;
+use common::TestProject;
+
+fn load_error(project: &TestProject) -> String {
+    match config::load_config(project.path()) {
+        Ok(_) => panic!("the configuration must be rejected"),
+        Err(err) => format!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_yaml_config_with_comments() {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.yaml",
+        r#"# Reglas de la organización
+max_lines_per_function: 45
+architecture_pattern: Hexagonal
+forbidden_imports:
+  # El dominio no conoce la infraestructura
+  - from: /domain/
+    to: /infrastructure/
+    reason: Domain must stay framework-free
+"#,
+    );
+
+    let ctx = config::load_config(project.path()).unwrap();
+    assert_eq!(ctx.max_lines, 45);
+    assert_eq!(ctx.pattern, config::ArchPattern::Hexagonal);
+    assert_eq!(ctx.forbidden_imports[0].to, "/infrastructure/");
+}
+
+#[test]
+fn test_toml_and_pyproject_configs() {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.toml",
+        r#"# Límite por función
+max_lines_per_function = 30
+architecture_pattern = "MVC"
+
+[[forbidden_imports]]
+from = "controller"
+to = "repository"
+severity = "warning"
+"#,
+    );
+    let ctx = config::load_config(project.path()).unwrap();
+    assert_eq!(ctx.max_lines, 30);
+    assert_eq!(
+        ctx.forbidden_imports[0].get_severity(),
+        config::Severity::Warning
+    );
+
+    let python = TestProject::new();
+    python.create_file(
+        "pyproject.toml",
+        r#"[project]
+name = "billing"
+
+[tool.architect]
+max_lines_per_function = 50
+architecture_pattern = "Clean"
+forbidden_imports = [{ from = "app/domain/", to = "app/adapters/" }]
+"#,
+    );
+    let ctx = config::load_config(python.path()).unwrap();
+    assert_eq!(ctx.max_lines, 50);
+    assert_eq!(ctx.forbidden_imports[0].from, "app/domain/");
+}
+
+#[test]
+fn test_schema_errors_point_to_source_lines() {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.yaml",
+        r#"max_lines_per_function: 40
+architecture_pattern: MVC
+forbidden_imports:
+  - from: controller
+    to: repository
+  - from: views
+    to: models
+    severity: fatal
+"#,
+    );
+    let err = load_error(&project);
+    assert!(err.contains("architect.yaml:8:15"), "{}", err);
+
+    let toml = TestProject::new();
+    toml.create_file(
+        "architect.toml",
+        "max_lines_per_function = \"forty\"\narchitecture_pattern = \"MVC\"\nforbidden_imports = []\n",
+    );
+    let err = load_error(&toml);
+    assert!(err.contains("architect.toml:1:26"), "{}", err);
+}
+
+#[test]
+fn test_syntax_errors_report_position() {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.toml",
+        "max_lines_per_function = 40\narchitecture_pattern = MVC\n",
+    );
+    let err = load_error(&project);
+    assert!(err.contains("architect.toml:2:"), "{}", err);
+}
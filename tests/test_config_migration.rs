@@ -0,0 +1,125 @@
+//! Tests for config migrations (`version` and the `migrate` command)
+//!
+//! These tests verify that configs in older formats still load, and that
+//! the file rewrite is in the current format and stable.
+
+mod common;
+use architect_linter_pro::config::formats::ConfigSource;
+use architect_linter_pro::config::load_config;
+use architect_linter_pro::config::migration::{FileMigration, CURRENT_VERSION};
+use common::TestProject;
+
+const LEGACY_CONFIG: &str = r#"{
+  "max_lines": 30,
+  "pattern": "Hexagonal",
+  "forbidden_imports": [
+    "src/domain/ -> src/infrastructure/",
+    { "pattern": "src/application/", "forbidden": ["axios", "pg"], "message": "Use ports" }
+  ]
+}"#;
+
+#[test]
+fn test_legacy_config_loads() {
+    let project = TestProject::new();
+    project.create_file("architect.json", LEGACY_CONFIG);
+
+    let ctx = load_config(project.path()).unwrap();
+    assert_eq!(ctx.max_lines, 30);
+    let rules: Vec<(&str, &str)> = ctx
+        .forbidden_imports
+        .iter()
+        .map(|r| (r.from.as_str(), r.to.as_str()))
+        .collect();
+    assert_eq!(
+        rules,
+        [
+            ("src/domain/", "src/infrastructure/"),
+            ("src/application/", "axios"),
+            ("src/application/", "pg"),
+        ]
+    );
+    assert_eq!(
+        ctx.forbidden_imports[1].reason.as_deref(),
+        Some("Use ports")
+    );
+}
+
+#[test]
+fn test_migrate_rewrites_file_once() {
+    let project = TestProject::new();
+    let path = project.create_file("architect.json", LEGACY_CONFIG);
+
+    let plan = FileMigration::plan(ConfigSource::read(&path).unwrap()).unwrap();
+    assert_eq!(plan.migrated.from_version, 1);
+    assert!(!plan.is_unchanged());
+    assert!(plan.diff().contains("+  \"max_lines_per_function\": 30,"));
+    plan.write().unwrap();
+
+    let written: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(written["version"], CURRENT_VERSION);
+    assert_eq!(written["forbidden_imports"].as_array().unwrap().len(), 3);
+
+    // The rewritten file is current: no warning and nothing left to migrate
+    let again = FileMigration::plan(ConfigSource::read(&path).unwrap()).unwrap();
+    assert!(again.is_unchanged());
+    assert!(!again.migrated.is_outdated());
+    assert!(load_config(project.path()).is_ok());
+}
+
+#[test]
+fn test_current_file_is_unchanged_whatever_its_formatting() {
+    let project = TestProject::new();
+    let yaml = project.create_file(
+        "architect.yaml",
+        &format!(
+            "# Reglas del equipo\nversion: {}\nmax_lines_per_function: 40  # límite\narchitecture_pattern: MVC\nforbidden_imports: []\n",
+            CURRENT_VERSION
+        ),
+    );
+    let plan = FileMigration::plan(ConfigSource::read(&yaml).unwrap()).unwrap();
+    assert!(plan.is_unchanged());
+
+    let json = project.create_file(
+        "architect.json",
+        &format!(
+            "{{\n    \"version\": {},\n    \"max_lines_per_function\": 40,\n    \"architecture_pattern\": \"MVC\",\n    \"forbidden_imports\": []\n}}\n",
+            CURRENT_VERSION
+        ),
+    );
+    let plan = FileMigration::plan(ConfigSource::read(&json).unwrap()).unwrap();
+    assert!(plan.is_unchanged());
+}
+
+#[test]
+fn test_missing_version_alone_is_not_unchanged() {
+    let project = TestProject::new();
+    let path = project.create_file(
+        "architect.toml",
+        "# Reglas del equipo\nmax_lines_per_function = 40\narchitecture_pattern = \"MVC\"\nforbidden_imports = []\n",
+    );
+
+    // Only `version` would change: migrate asks for --force before dropping comments
+    let plan = FileMigration::plan(ConfigSource::read(&path).unwrap()).unwrap();
+    assert!(!plan.is_unchanged());
+    assert!(plan.migrated.applied.is_empty());
+    assert!(plan.loses_comments());
+}
+
+#[test]
+fn test_newer_version_is_rejected() {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.json",
+        &format!(
+            r#"{{ "version": {}, "max_lines_per_function": 40, "architecture_pattern": "MVC", "forbidden_imports": [] }}"#,
+            CURRENT_VERSION + 1
+        ),
+    );
+
+    let err = match load_config(project.path()) {
+        Ok(_) => panic!("a config from a newer version should be rejected"),
+        Err(err) => err.to_string(),
+    };
+    assert!(err.contains("versión"), "{}", err);
+}
@@ -0,0 +1,99 @@
+//! Tests for the analysis daemon (`--daemon`)
+//!
+//! These tests run the server on a temporary project and verify that the
+//! socket answers queries and follows file changes.
+
+#![cfg(unix)]
+
+mod common;
+use architect_linter_pro::daemon::state::DaemonState;
+use architect_linter_pro::daemon::{self, socket_path};
+use common::TestProject;
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+
+fn wait_until(mut condition: impl FnMut() -> bool) {
+    let started = Instant::now();
+    while !condition() {
+        assert!(
+            started.elapsed() < Duration::from_secs(20),
+            "timed out waiting for the daemon"
+        );
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn test_daemon_answers_queries_and_follows_changes() {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.json",
+        r#"{
+  "max_lines_per_function": 40,
+  "architecture_pattern": "Hexagonal",
+  "forbidden_imports": [{ "from": "src/domain/", "to": "src/infra/", "reason": "El dominio es puro" }]
+}"#,
+    );
+    project.create_file("src/infra/db.ts", "export const db = 1;\n");
+    project.create_file(
+        "src/domain/user.ts",
+        "import { db } from '../infra/db';\nexport const user = db;\n",
+    );
+    let root = project.path().canonicalize().unwrap();
+
+    let server_root = root.clone();
+    let server = std::thread::spawn(move || daemon::serve(&server_root));
+    wait_until(|| socket_path(&root).exists());
+
+    let result =
+        daemon::request(&root, "violations", json!({ "file": "src/domain/user.ts" })).unwrap();
+    let violations = result["violations"].as_array().unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0]["rule"]["reason"], "El dominio es puro");
+    assert_eq!(violations[0]["line"], 1);
+
+    let score = daemon::request(&root, "score", Value::Null).unwrap();
+    assert!(score["total"].as_u64().unwrap() < 100);
+
+    // The watcher re-analyzes the file without any request
+    project.create_file("src/domain/user.ts", "export const user = 1;\n");
+    wait_until(|| {
+        daemon::request(&root, "violations", json!({ "file": "src/domain/user.ts" })).unwrap()
+            ["violations"]
+            .as_array()
+            .unwrap()
+            .is_empty()
+    });
+
+    let summary = daemon::request(&root, "analyze", Value::Null).unwrap();
+    assert_eq!(summary["files_analyzed"], 2);
+    assert_eq!(summary["total_violations"], 0);
+
+    assert!(daemon::request(&root, "lint", Value::Null)
+        .unwrap_err()
+        .to_string()
+        .contains("-32601"));
+
+    daemon::request(&root, "shutdown", Value::Null).unwrap();
+    server.join().unwrap().unwrap();
+    assert!(!socket_path(&root).exists());
+}
+
+#[test]
+fn test_state_reloads_ignore_rules_when_they_change() {
+    let project = TestProject::new();
+    project.create_minimal_config();
+    project.create_file("src/infra/db.ts", "export const db = 1;\n");
+    project.create_file("src/domain/user.ts", "export const user = 1;\n");
+    let root = project.path().canonicalize().unwrap();
+
+    let mut state = DaemonState::new(&root).unwrap();
+    assert_eq!(state.result().files_analyzed, 2);
+    assert!(state.is_relevant(&root.join("src/infra/db.ts")));
+
+    let ignore = project.create_file(".architectignore", "src/infra/\n");
+    assert!(state.is_relevant(&ignore));
+    state.apply_changes(&[ignore]).unwrap();
+    assert_eq!(state.result().files_analyzed, 1);
+    assert!(!state.is_relevant(&root.join("src/infra/db.ts")));
+}
@@ -0,0 +1,97 @@
+//! Tests for the declared-vs-used dependency audit (`deps`)
+//!
+//! These tests verify that undeclared, unused and dev-only packages are
+//! reported for npm and pip projects.
+
+mod common;
+use architect_linter_pro::config::DependencyAuditConfig;
+use architect_linter_pro::dependencies::{self, IssueKind};
+use common::TestProject;
+
+fn issues(audit: &dependencies::DependencyAudit, kind: IssueKind) -> Vec<String> {
+    audit
+        .issues_of(kind)
+        .map(|issue| format!("{}:{}", issue.ecosystem.as_str(), issue.package))
+        .collect()
+}
+
+fn audit(project: &TestProject, config: &DependencyAuditConfig) -> dependencies::DependencyAudit {
+    let ignored = architect_linter_pro::config::default_ignored_paths();
+    dependencies::audit(project.path(), &ignored, config).unwrap()
+}
+
+#[test]
+fn test_npm_audit() {
+    let project = TestProject::new();
+    project.create_file(
+        "package.json",
+        r#"{
+  "dependencies": { "@nestjs/core": "^10", "express": "^4", "left-pad": "1.0.0" },
+  "devDependencies": { "jest": "^29", "@types/express": "^4", "faker": "^6" }
+}"#,
+    );
+    project.create_file(
+        "src/app.ts",
+        "import { NestFactory } from '@nestjs/core/nest-factory';\nimport express from 'express';\nimport axios from 'axios';\nimport { readFile } from 'fs';\nimport { User } from './user';\n",
+    );
+    project.create_file(
+        "src/user.ts",
+        "import { faker } from 'faker';\nexport class User {}\n",
+    );
+    project.create_file(
+        "src/user.spec.ts",
+        "import { faker } from 'faker';\nimport { describe } from 'jest';\n",
+    );
+
+    let audit = audit(&project, &DependencyAuditConfig::default());
+
+    assert_eq!(audit.manifests, ["package.json"]);
+    assert_eq!(issues(&audit, IssueKind::Undeclared), ["npm:axios"]);
+    assert_eq!(issues(&audit, IssueKind::Unused), ["npm:left-pad"]);
+    assert_eq!(issues(&audit, IssueKind::DevInProduction), ["npm:faker"]);
+    let faker = audit.issues_of(IssueKind::DevInProduction).next().unwrap();
+    assert_eq!(faker.locations.len(), 1);
+    assert_eq!(faker.locations[0].file, "src/user.ts");
+    assert!(audit.has_errors());
+
+    let json: serde_json::Value = serde_json::from_str(&audit.to_json()).unwrap();
+    assert_eq!(json["issues"][0]["kind"], "undeclared");
+    assert!(audit.to_markdown().contains("## Unused (1)"));
+}
+
+#[test]
+fn test_pip_audit_with_pyproject() {
+    let project = TestProject::new();
+    project.create_file(
+        "pyproject.toml",
+        r#"[project]
+name = "shop"
+dependencies = ["PyYAML>=6", "requests", "python-dateutil"]
+
+[project.optional-dependencies]
+dev = ["pytest"]
+"#,
+    );
+    project.create_file(
+        "shop/orders.py",
+        "import yaml\nfrom dateutil import parser\nimport boto3.session\nimport os\nfrom shop import utils\n",
+    );
+    project.create_file("shop/utils.py", "X = 1\n");
+    project.create_file("tests/test_orders.py", "import pytest\n");
+
+    let audit = audit(&project, &DependencyAuditConfig::default());
+
+    assert_eq!(issues(&audit, IssueKind::Undeclared), ["pip:boto3"]);
+    assert_eq!(issues(&audit, IssueKind::Unused), ["pip:requests"]);
+    assert!(issues(&audit, IssueKind::DevInProduction).is_empty());
+
+    let config = DependencyAuditConfig {
+        ignore: vec!["boto3".to_string(), "requests".to_string()],
+        ..Default::default()
+    };
+    assert!(audit_is_clean(&project, &config));
+}
+
+fn audit_is_clean(project: &TestProject, config: &DependencyAuditConfig) -> bool {
+    audit(project, config).issues.is_empty()
+}
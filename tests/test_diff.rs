@@ -0,0 +1,98 @@
+//! Tests for diff mode (`--diff-base`)
+//!
+//! These tests verify that only findings introduced or fixed between the base
+//! ref and HEAD are reported, independently of unrelated line shifts.
+
+use architect_linter_pro::{analyzer, config, diff};
+use tempfile::TempDir;
+
+/// Helper to commit the given files on top of HEAD
+fn commit_files(repo: &git2::Repository, files: &[(&str, &str)], message: &str) {
+    let workdir = repo.workdir().unwrap().to_path_buf();
+    let mut index = repo.index().unwrap();
+    for (path, content) in files {
+        let full = workdir.join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(&full, content).unwrap();
+        index.add_path(std::path::Path::new(path)).unwrap();
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)
+        .unwrap();
+}
+
+#[test]
+fn test_diff_reports_introduced_and_fixed() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    let repo = git2::Repository::init(&root).unwrap();
+    let config = r#"{
+  "max_lines_per_function": 50,
+  "architecture_pattern": "MVC",
+  "forbidden_imports": [{ "from": "controller", "to": "repository" }]
+}"#;
+
+    commit_files(
+        &repo,
+        &[
+            ("architect.json", config),
+            (
+                "src/user.controller.ts",
+                "import { UserRepository } from './user.repository';\nexport class UserController {}\n",
+            ),
+            ("src/order.controller.ts", "export class OrderController {}\n"),
+            ("src/user.repository.ts", "export class UserRepository {}\n"),
+            ("src/order.repository.ts", "export class OrderRepository {}\n"),
+        ],
+        "base",
+    );
+    commit_files(
+        &repo,
+        &[
+            // Existing violation shifted down a line: still the same finding
+            (
+                "src/user.controller.ts",
+                "// users\nimport { UserRepository } from './user.repository';\nexport class UserController {}\n",
+            ),
+            (
+                "src/order.controller.ts",
+                "import { OrderRepository } from './order.repository';\nexport class OrderController {}\n",
+            ),
+        ],
+        "branch work",
+    );
+
+    let ctx = config::load_config(&root).unwrap();
+    let (base_commit, base) = diff::analyze_base(&root, "HEAD~1", &ctx).unwrap();
+    let head = analyzer::analyze_project(&root, &ctx).unwrap();
+    let report = diff::compare(&base, &head, &root, "HEAD~1", &base_commit);
+
+    assert_eq!(report.introduced.len(), 1);
+    assert!(report.introduced[0]
+        .violation
+        .file_path
+        .ends_with("src/order.controller.ts"));
+    assert!(report.fixed.is_empty());
+    assert!(report.has_critical_issues());
+
+    let total = &report.score_deltas()[0];
+    assert_eq!(total.metric, "total");
+    assert!(total.delta() <= 0);
+
+    // Reversing the comparison turns the new finding into a fix
+    let reverse = diff::compare(&head, &base, &root, "HEAD", &base_commit);
+    assert!(reverse.introduced.is_empty());
+    assert_eq!(reverse.fixed.len(), 1);
+    assert!(!reverse.has_critical_issues());
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+    assert_eq!(
+        json["introduced"]["violations"].as_array().unwrap().len(),
+        1
+    );
+    assert_eq!(json["base_commit"], base_commit);
+}
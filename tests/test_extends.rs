@@ -0,0 +1,84 @@
+//! Tests for configuration inheritance (`extends` in architect.json)
+//!
+//! These tests verify that bases and built-in presets are merged into the
+//! project configuration in order, and that broken references are reported.
+
+mod common;
+use architect_linter_pro::config;
+use common::TestProject;
+
+#[test]
+fn test_extends_file_and_preset() {
+    let project = TestProject::new();
+    project.create_file(
+        "org.architect.json",
+        r#"{
+  "max_lines_per_function": 30,
+  "forbidden_imports": [
+    { "from": "/domain/", "to": "/infrastructure/", "severity": "warning" },
+    { "from": "/domain/", "to": "axios" }
+  ],
+  "ignored_paths": ["generated/"]
+}"#,
+    );
+    project.create_file(
+        "architect.json",
+        r#"{
+  "extends": ["preset:nestjs-hexagonal", "./org.architect.json"],
+  "max_lines_per_function": 60,
+  "forbidden_imports": [{ "from": "/domain/", "to": "axios", "severity": "info" }]
+}"#,
+    );
+
+    let ctx = config::load_config(project.path()).unwrap();
+    assert_eq!(ctx.max_lines, 60);
+    assert_eq!(ctx.pattern, config::ArchPattern::Hexagonal);
+
+    let rules: Vec<(&str, &str, config::Severity)> = ctx
+        .forbidden_imports
+        .iter()
+        .map(|r| (r.from.as_str(), r.to.as_str(), r.get_severity()))
+        .collect();
+    assert_eq!(
+        rules,
+        [
+            ("/domain/", "/application/", config::Severity::Error),
+            ("/domain/", "/infrastructure/", config::Severity::Warning),
+            ("/application/", "/infrastructure/", config::Severity::Error),
+            ("/domain/", "axios", config::Severity::Info),
+        ]
+    );
+    assert!(ctx.ignored_paths.contains(&"node_modules/".to_string()));
+    assert_eq!(ctx.ignored_paths.last().unwrap(), "generated/");
+}
+
+#[test]
+fn test_extends_npm_package() {
+    let project = TestProject::new();
+    project.create_file(
+        "node_modules/@acme/architect-config/architect.json",
+        r#"{ "max_lines_per_function": 25, "architecture_pattern": "Clean", "forbidden_imports": [] }"#,
+    );
+    project.create_file(
+        "services/billing/architect.json",
+        r#"{ "extends": "@acme/architect-config" }"#,
+    );
+
+    let ctx = config::load_config(&project.path().join("services/billing")).unwrap();
+    assert_eq!(ctx.max_lines, 25);
+    assert_eq!(ctx.pattern, config::ArchPattern::Clean);
+}
+
+#[test]
+fn test_unknown_preset_is_an_error() {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.json",
+        r#"{ "extends": "preset:rails-mvc", "max_lines_per_function": 40, "architecture_pattern": "MVC", "forbidden_imports": [] }"#,
+    );
+
+    match config::load_config(project.path()) {
+        Ok(_) => panic!("an unknown preset must be rejected"),
+        Err(err) => assert!(err.to_string().contains("preset:rails-mvc")),
+    }
+}
@@ -0,0 +1,114 @@
+//! Tests for third-party package rules (`external_imports` in architect.json)
+//!
+//! These tests verify deny-lists, allow-lists and `except` paths for npm and
+//! pip packages, including scoped packages and subpath imports.
+
+mod common;
+use architect_linter_pro::{analyzer, config};
+use common::TestProject;
+
+fn create_project(rules: &str) -> TestProject {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.json",
+        &format!(
+            r#"{{
+  "max_lines_per_function": 50,
+  "architecture_pattern": "Hexagonal",
+  "forbidden_imports": [],
+  "external_imports": {}
+}}"#,
+            rules
+        ),
+    );
+    project.create_file(
+        "src/domain/order.ts",
+        "import { Entity } from 'typeorm';\nimport { Injectable } from '@nestjs/common/decorators';\nimport { v4 } from 'uuid';\nimport { readFile } from 'node:fs/promises';\nimport { Money } from './money';\nexport class Order {}\n",
+    );
+    project.create_file("src/domain/money.ts", "export class Money {}\n");
+    project.create_file(
+        "src/infrastructure/db.ts",
+        "import { Pool } from 'pg';\nexport const pool = new Pool();\n",
+    );
+    project.create_file(
+        "src/application/report.ts",
+        "import { Pool } from 'pg';\nimport axios from 'axios/dist/node/axios.cjs';\n",
+    );
+    project.create_file(
+        "app/services/billing.py",
+        "import yaml.constructor\nfrom requests.adapters import HTTPAdapter\nfrom app.services import helpers\nimport os\n",
+    );
+    project.create_file("app/services/helpers.py", "X = 1\n");
+    project
+}
+
+fn offending(project: &TestProject) -> Vec<String> {
+    let root = project.path().canonicalize().unwrap();
+    let ctx = config::load_config(&root).unwrap();
+    let result = analyzer::analyze_project(&root, &ctx).unwrap();
+    let mut found: Vec<String> = result
+        .violations
+        .iter()
+        .map(|cv| {
+            format!(
+                "{}:{}",
+                cv.violation
+                    .file_path
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy(),
+                cv.violation.rule.to
+            )
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+#[test]
+fn test_deny_list_and_only_in_paths() {
+    let project = create_project(
+        r#"[
+    { "from": "src/domain/", "deny": ["typeorm", "@nestjs/*", "axios"] },
+    { "deny": ["pg"], "except": ["src/infrastructure/"], "severity": "warning" },
+    { "from": "app/services/", "deny": ["PyYAML", "yaml"] }
+  ]"#,
+    );
+
+    assert_eq!(
+        offending(&project),
+        [
+            "billing.py:yaml",
+            "order.ts:@nestjs/common",
+            "order.ts:typeorm",
+            "report.ts:pg",
+        ]
+    );
+}
+
+#[test]
+fn test_allow_list_mode() {
+    let project = create_project(
+        r#"[
+    { "from": "src/domain/", "allow": ["uuid"] },
+    { "from": "app/", "allow": [] }
+  ]"#,
+    );
+
+    // Built-ins (node:fs, os) and project modules are always allowed
+    assert_eq!(
+        offending(&project),
+        [
+            "billing.py:requests",
+            "billing.py:yaml",
+            "order.ts:@nestjs/common",
+            "order.ts:typeorm",
+        ]
+    );
+}
+
+#[test]
+fn test_rule_without_deny_or_allow_is_rejected() {
+    let project = create_project(r#"[{ "from": "src/domain/" }]"#);
+    assert!(config::load_config(project.path()).is_err());
+}
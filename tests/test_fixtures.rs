@@ -25,6 +25,8 @@ fn analyze_fixture_json(name: &str) -> serde_json::Value {
     // Run analysis with JSON report
     let _ = Command::new(env!("CARGO_BIN_EXE_architect-linter-pro"))
         .current_dir(&fixture_dir)
+        // Keep the fixtures free of cache files
+        .arg("--no-cache")
         .arg("--report")
         .arg("json")
         .arg("--output")
@@ -73,6 +75,7 @@ fn test_fixture_perfect_project_exit_code() {
     // Perfect project should exit with success (0)
     Command::new(env!("CARGO_BIN_EXE_architect-linter-pro"))
         .current_dir(&fixture_dir)
+        .arg("--no-cache")
         .arg(".")
         .assert()
         .success();
@@ -127,6 +130,7 @@ fn test_fixture_circular_deps_exit_code() {
     // Project with cycles should exit with failure (non-zero)
     Command::new(env!("CARGO_BIN_EXE_architect-linter-pro"))
         .current_dir(&fixture_dir)
+        .arg("--no-cache")
         .arg(".")
         .assert()
         .failure();
@@ -234,6 +238,7 @@ fn test_fixture_forbidden_imports_exit_code() {
     // Project with violations should exit with failure
     Command::new(env!("CARGO_BIN_EXE_architect-linter-pro"))
         .current_dir(&fixture_dir)
+        .arg("--no-cache")
         .arg(".")
         .assert()
         .failure();
@@ -0,0 +1,122 @@
+//! Tests for generated and vendored code detection (`generated_code`)
+//!
+//! These tests verify that generated, minified and vendored files are kept
+//! out of the violations and complexity stats, or downgraded when configured.
+
+mod common;
+use architect_linter_pro::analysis_result::ViolationCategory;
+use architect_linter_pro::analyzer::analyze_project;
+use architect_linter_pro::config::{load_config, Severity};
+use architect_linter_pro::watch::incremental::IncrementalAnalyzer;
+use common::TestProject;
+use std::sync::Arc;
+
+fn setup(mode: &str) -> TestProject {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.json",
+        &format!(
+            r#"{{
+  "max_lines_per_function": 40,
+  "architecture_pattern": "MVC",
+  "forbidden_imports": [{{ "from": "src/", "to": "axios" }}],
+  "generated_code": {{ "mode": "{}" }}
+}}"#,
+            mode
+        ),
+    );
+    project.create_file(
+        "src/app.ts",
+        "import axios from 'axios';\nexport const app = axios;\n",
+    );
+    project.create_file(
+        "src/api/client.ts",
+        "// This file is auto-generated by openapi-generator. DO NOT EDIT.\nimport axios from 'axios';\nexport const client = axios;\n",
+    );
+    project.create_file(
+        "src/third_party/lib.ts",
+        "import axios from 'axios';\nexport const lib = axios;\n",
+    );
+    project
+}
+
+#[test]
+fn test_generated_files_are_skipped_by_default() {
+    let project = setup("skip");
+    let ctx = load_config(project.path()).unwrap();
+    let result = analyze_project(project.path(), &ctx).unwrap();
+
+    assert_eq!(result.files_analyzed, 1);
+    assert_eq!(result.generated_files.len(), 2);
+    assert_eq!(result.violations.len(), 1);
+    assert!(result.violations[0]
+        .violation
+        .file_path
+        .ends_with("src/app.ts"));
+}
+
+#[test]
+fn test_downweighted_files_report_info_only() {
+    let project = setup("downweight");
+    let ctx = load_config(project.path()).unwrap();
+    let result = analyze_project(project.path(), &ctx).unwrap();
+
+    assert_eq!(result.files_analyzed, 3);
+    assert_eq!(result.generated_files.len(), 2);
+    let mut categories: Vec<ViolationCategory> =
+        result.violations.iter().map(|cv| cv.category).collect();
+    categories.sort_by_key(|c| c.as_str());
+    assert_eq!(
+        categories,
+        [
+            ViolationCategory::Blocked,
+            ViolationCategory::Info,
+            ViolationCategory::Info
+        ]
+    );
+    assert_eq!(result.complexity_stats.total_functions, 0);
+}
+
+#[test]
+fn test_analyze_mode_disables_detection() {
+    let project = setup("analyze");
+    let ctx = load_config(project.path()).unwrap();
+    let result = analyze_project(project.path(), &ctx).unwrap();
+
+    assert!(result.generated_files.is_empty());
+    assert_eq!(result.blocked_count(), 3);
+}
+
+/// `setup` with a cycle app -> client -> app through the generated client
+fn setup_cycle(mode: &str) -> TestProject {
+    let project = setup(mode);
+    project.create_file(
+        "src/app.ts",
+        "import { client } from './api/client';\nexport const app = client;\n",
+    );
+    project.create_file(
+        "src/api/client.ts",
+        "// This file is auto-generated by openapi-generator. DO NOT EDIT.\nimport { app } from '../app';\nexport const client = app;\n",
+    );
+    project
+}
+
+#[test]
+fn test_skipped_files_are_left_out_of_cycles() {
+    let project = setup_cycle("skip");
+    let ctx = load_config(project.path()).unwrap();
+    let result = analyze_project(project.path(), &ctx).unwrap();
+    assert!(result.circular_dependencies.is_empty());
+
+    let watcher =
+        IncrementalAnalyzer::new(project.path(), Arc::new(ctx), Severity::Info, None).unwrap();
+    assert!(watcher.result().circular_dependencies.is_empty());
+}
+
+#[test]
+fn test_downweighted_files_still_form_cycles() {
+    let project = setup_cycle("downweight");
+    let ctx = load_config(project.path()).unwrap();
+    let result = analyze_project(project.path(), &ctx).unwrap();
+    assert_eq!(result.circular_dependencies.len(), 1);
+}
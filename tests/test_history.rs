@@ -0,0 +1,167 @@
+//! Tests for health score history tracking
+//!
+//! These tests verify that analysis results are persisted to
+//! `.architect/history.jsonl`, and that regressions and charts are computed
+//! from the stored time series.
+
+use architect_linter_pro::analysis_result::AnalysisResult;
+use architect_linter_pro::config::ArchPattern;
+use architect_linter_pro::history::{self, HistoryEntry};
+use architect_linter_pro::metrics::{HealthScore, ScoreComponents};
+use tempfile::TempDir;
+
+/// Helper to build a scored entry for a commit
+fn entry(commit: &str, layer_isolation: u8) -> HistoryEntry {
+    let mut result = AnalysisResult::new("demo".to_string(), ArchPattern::MVC);
+    result.files_analyzed = 12;
+    result.health_score = Some(HealthScore::new(ScoreComponents {
+        layer_isolation,
+        circular_deps: 100,
+        complexity: 100,
+        violations: 100,
+    }));
+    HistoryEntry::from_result(&result, Some(commit.to_string())).unwrap()
+}
+
+#[test]
+fn test_unscored_result_is_not_recorded() {
+    let result = AnalysisResult::new("demo".to_string(), ArchPattern::MVC);
+    assert!(HistoryEntry::from_result(&result, None).is_none());
+}
+
+#[test]
+fn test_record_and_load_roundtrip() {
+    let dir = TempDir::new().unwrap();
+
+    history::record(dir.path(), entry("aaaaaaa111", 100)).unwrap();
+    history::record(dir.path(), entry("bbbbbbb222", 90)).unwrap();
+
+    let entries = history::load(dir.path()).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].short_commit(), "aaaaaaa");
+    assert_eq!(entries[1].components.layer_isolation, 90);
+    assert_eq!(entries[1].files_analyzed, 12);
+    assert!(history::history_path(dir.path()).ends_with(".architect/history.jsonl"));
+}
+
+#[test]
+fn test_record_same_commit_replaces_last_entry() {
+    let dir = TempDir::new().unwrap();
+
+    history::record(dir.path(), entry("aaaaaaa111", 100)).unwrap();
+    history::record(dir.path(), entry("aaaaaaa111", 80)).unwrap();
+
+    let entries = history::load(dir.path()).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].components.layer_isolation, 80);
+}
+
+#[test]
+fn test_regression_threshold() {
+    let entries = vec![entry("a", 100), entry("b", 96), entry("c", 70)];
+
+    let regressions = history::find_regressions(&entries, 5);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].index, 2);
+    assert!(regressions[0]
+        .drops
+        .iter()
+        .any(|(metric, before, after)| *metric == "layer_isolation"
+            && *before == 96
+            && *after == 70));
+
+    assert!(history::find_regressions(&entries, 50).is_empty());
+}
+
+#[test]
+fn test_ascii_chart_marks_regressions() {
+    let entries = vec![entry("a", 100), entry("b", 100), entry("c", 0)];
+    let chart = history::render_ascii_chart(&entries, 8, 5);
+
+    assert_eq!(chart.matches('●').count(), 2);
+    assert_eq!(chart.matches('▼').count(), 1);
+    assert!(chart.contains("a … c"));
+}
+
+// ============================================================================
+// Backfill from git history
+// ============================================================================
+
+/// Helper to commit the given files on top of HEAD
+fn commit_files(repo: &git2::Repository, files: &[(&str, &str)], message: &str) {
+    let workdir = repo.workdir().unwrap().to_path_buf();
+    let mut index = repo.index().unwrap();
+    for (path, content) in files {
+        let full = workdir.join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(&full, content).unwrap();
+        index.add_path(std::path::Path::new(path)).unwrap();
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)
+        .unwrap();
+}
+
+#[test]
+fn test_backfill_reads_commits_without_touching_worktree() {
+    use architect_linter_pro::history::backfill::{backfill, BackfillOptions};
+
+    let dir = TempDir::new().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    let config = r#"{
+  "max_lines_per_function": 50,
+  "architecture_pattern": "MVC",
+  "forbidden_imports": [{ "from": "controller", "to": "repository" }]
+}"#;
+
+    commit_files(
+        &repo,
+        &[
+            ("architect.json", config),
+            ("src/user.controller.ts", "export class UserController {}\n"),
+            ("src/user.repository.ts", "export class UserRepository {}\n"),
+        ],
+        "clean start",
+    );
+    commit_files(
+        &repo,
+        &[(
+            "src/user.controller.ts",
+            "import { UserRepository } from './user.repository';\nexport class UserController {}\n",
+        )],
+        "introduce violation",
+    );
+    commit_files(&repo, &[("README.md", "# demo\n")], "docs");
+
+    // Uncommitted change in the working tree must survive the backfill
+    std::fs::write(dir.path().join("src/user.controller.ts"), "// local edit\n").unwrap();
+
+    let fallback = architect_linter_pro::config::load_config(dir.path()).unwrap();
+    let options = BackfillOptions {
+        every: 2,
+        ..Default::default()
+    };
+    let summary = backfill(dir.path(), &options, &fallback, |_, _, _| {}).unwrap();
+
+    // HEAD and HEAD~2 are selected
+    assert_eq!(summary.added, 2);
+    assert!(summary.failed.is_empty());
+
+    let entries = history::load(dir.path()).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].total_violations, 0);
+    assert_eq!(entries[1].total_violations, 1);
+    assert!(entries[0].timestamp <= entries[1].timestamp);
+
+    let local = std::fs::read_to_string(dir.path().join("src/user.controller.ts")).unwrap();
+    assert_eq!(local, "// local edit\n");
+
+    // Running again does not duplicate entries
+    let again = backfill(dir.path(), &options, &fallback, |_, _, _| {}).unwrap();
+    assert_eq!(again.added, 0);
+    assert_eq!(again.skipped, 2);
+}
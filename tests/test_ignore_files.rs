@@ -0,0 +1,87 @@
+//! Tests for ignore files during discovery
+//!
+//! These tests verify that `.gitignore`, `.git/info/exclude` and
+//! `.architectignore` exclude files with gitignore semantics, and that
+//! `explain_ignore` names the rule that decided.
+
+mod common;
+use architect_linter_pro::config::default_ignored_paths;
+use architect_linter_pro::discovery::{collect_files, explain_ignore, IgnoreExplanation};
+use architect_linter_pro::ignore_rules::IgnoreFileKind;
+use common::TestProject;
+
+fn collected(project: &TestProject) -> Vec<String> {
+    let mut files: Vec<String> = collect_files(project.path(), &default_ignored_paths())
+        .iter()
+        .map(|f| {
+            f.strip_prefix(project.path())
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+fn test_collect_files_honors_ignore_files() {
+    let project = TestProject::new();
+    git2::Repository::init(project.path()).unwrap();
+    project.create_file("src/app.ts", "export const app = 1;\n");
+    project.create_file("src/api.gen.ts", "export const api = 1;\n");
+    project.create_file("src/keep.gen.ts", "export const keep = 1;\n");
+    project.create_file("src/generated/client.ts", "export const client = 1;\n");
+    project.create_file("scripts/tmp.ts", "export const tmp = 1;\n");
+    project.create_file("legacy/old.ts", "export const old = 1;\n");
+    project.create_file(".gitignore", "generated/\n*.gen.ts\n");
+    project.create_file("src/.architectignore", "!keep.gen.ts\n");
+    project.create_file(".git/info/exclude", "/scripts/\n");
+    project.create_file(".architectignore", "legacy/\n!legacy/old.ts\n");
+
+    // A file inside an excluded directory cannot be re-included
+    assert_eq!(collected(&project), ["src/app.ts", "src/keep.gen.ts"]);
+}
+
+#[test]
+fn test_explain_ignore_names_the_rule() {
+    let project = TestProject::new();
+    project.create_file("src/app.ts", "export const app = 1;\n");
+    project.create_file(".gitignore", "# generated code\nsrc/generated/\n*.gen.ts\n");
+    project.create_file("src/.architectignore", "!keep.gen.ts\n");
+    let ignored = default_ignored_paths();
+
+    match explain_ignore(project.path(), &ignored, "src/generated/client.ts", false) {
+        IgnoreExplanation::IgnoreFile(found) => {
+            assert_eq!(found.path, "src/generated");
+            assert_eq!(found.rule.kind, IgnoreFileKind::GitIgnore);
+            assert_eq!(found.rule.line, 2);
+            assert!(found.rule.location().ends_with(".gitignore:2"));
+        }
+        other => panic!("expected a .gitignore rule, got {:?}", other),
+    }
+    match explain_ignore(project.path(), &ignored, "src/keep.gen.ts", false) {
+        IgnoreExplanation::NotIgnored {
+            reincluded: Some(found),
+        } => {
+            assert_eq!(found.rule.kind, IgnoreFileKind::ArchitectIgnore);
+            assert_eq!(found.rule.pattern.source, "!keep.gen.ts");
+        }
+        other => panic!("expected a negation, got {:?}", other),
+    }
+    match explain_ignore(project.path(), &ignored, "node_modules/lib/index.js", false) {
+        IgnoreExplanation::IgnoredPaths { path, pattern } => {
+            assert_eq!(path, "node_modules");
+            assert_eq!(pattern, "node_modules/");
+        }
+        other => panic!("expected ignored_paths, got {:?}", other),
+    }
+    assert!(matches!(
+        explain_ignore(project.path(), &ignored, "src/app.ts", false),
+        IgnoreExplanation::NotIgnored { reincluded: None }
+    ));
+    assert!(matches!(
+        explain_ignore(project.path(), &ignored, "README.md", false),
+        IgnoreExplanation::Unsupported
+    ));
+}
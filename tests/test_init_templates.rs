@@ -35,3 +35,15 @@ fn test_all_frameworks_have_templates() {
         assert!(result.is_some(), "Missing template: {:?} / {}", fw, pattern);
     }
 }
+
+#[test]
+fn test_every_preset_name_resolves_to_a_template() {
+    use architect_linter_pro::init::templates::{get_preset, preset_names};
+    let names = preset_names();
+    assert!(names.contains(&"nestjs-hexagonal".to_string()));
+    assert!(names.contains(&"django-service-layer".to_string()));
+    for name in &names {
+        assert!(get_preset(name).is_some(), "Missing preset: {}", name);
+    }
+    assert!(get_preset("nestjs-unknown").is_none());
+}
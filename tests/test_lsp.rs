@@ -0,0 +1,96 @@
+//! Tests for the language server (`architect-linter-pro lsp`)
+//!
+//! These tests drive the binary over stdio with framed JSON-RPC messages and
+//! verify that diagnostics are published for unsaved buffers.
+
+mod common;
+use common::TestProject;
+use lsp_types::Url;
+use serde_json::{json, Value};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn frame(message: Value) -> String {
+    let body = message.to_string();
+    format!("Content-Length: {}\r\n\r\n{}", body.len(), body)
+}
+
+/// Parse the framed messages the server wrote to stdout
+fn messages(output: &str) -> Vec<Value> {
+    output
+        .split("Content-Length: ")
+        .filter_map(|chunk| chunk.split_once("\r\n\r\n"))
+        .map(|(_, body)| serde_json::from_str(body).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_lsp_publishes_diagnostics_for_open_buffers() {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.json",
+        r#"{
+  "max_lines_per_function": 40,
+  "architecture_pattern": "Hexagonal",
+  "forbidden_imports": [{ "from": "src/domain/", "to": "src/infra/", "reason": "El dominio es puro" }]
+}"#,
+    );
+    project.create_file("src/infra/db.ts", "export const db = 1;\n");
+    // On disk the file is clean; only the editor buffer imports infra
+    project.create_file("src/domain/user.ts", "export const user = 1;\n");
+
+    let root = project.path().canonicalize().unwrap();
+    let root_uri = Url::from_directory_path(&root).unwrap();
+    let uri = Url::from_file_path(root.join("src/domain/user.ts")).unwrap();
+
+    let input = [
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize",
+                "params": { "capabilities": {}, "rootUri": root_uri } }),
+        json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }),
+        json!({ "jsonrpc": "2.0", "method": "textDocument/didOpen",
+                "params": { "textDocument": { "uri": uri, "languageId": "typescript", "version": 1,
+                    "text": "import { db } from '../infra/db';\nexport const user = db;\n" } } }),
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "textDocument/hover",
+                "params": { "textDocument": { "uri": uri }, "position": { "line": 0, "character": 2 } } }),
+        json!({ "jsonrpc": "2.0", "id": 3, "method": "shutdown" }),
+        json!({ "jsonrpc": "2.0", "method": "exit" }),
+    ]
+    .into_iter()
+    .map(frame)
+    .collect::<String>();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_architect-linter-pro"))
+        .arg("lsp")
+        .current_dir(project.path())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let messages = messages(&String::from_utf8(output.stdout).unwrap());
+    let published = messages
+        .iter()
+        .find(|m| {
+            m["method"] == "textDocument/publishDiagnostics" && m["params"]["uri"] == uri.as_str()
+        })
+        .expect("diagnostics for the open buffer");
+    let diagnostics = published["params"]["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["code"], "architecture");
+    assert_eq!(diagnostics[0]["range"]["start"]["line"], 0);
+
+    let hover = messages.iter().find(|m| m["id"] == 2).unwrap();
+    assert!(hover["result"]["contents"]["value"]
+        .as_str()
+        .unwrap()
+        .contains("El dominio es puro"));
+}
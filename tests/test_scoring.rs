@@ -38,6 +38,8 @@ fn create_test_result() -> AnalysisResult {
         },
         health_score: None,
         timestamp: Utc::now(),
+        generated_files: vec![],
+        import_sources: Default::default(),
     }
 }
 
@@ -0,0 +1,221 @@
+//! Tests for monorepo workspaces (`workspace` in architect.json)
+//!
+//! These tests verify package discovery from workspace manifests, config
+//! inheritance from the root and rules between packages.
+
+mod common;
+use architect_linter_pro::config::Severity;
+use architect_linter_pro::workspace::{self, Workspace};
+use common::TestProject;
+use std::sync::Arc;
+
+fn create_npm_monorepo() -> TestProject {
+    let project = TestProject::new();
+    project.create_file(
+        "architect.json",
+        r#"{
+  "max_lines_per_function": 50,
+  "architecture_pattern": "Ninguno",
+  "forbidden_imports": [{ "from": "src/domain/", "to": "axios" }],
+  "ignored_paths": ["node_modules/"],
+  "workspace": {
+    "rules": [{ "from": "libs/*", "to": "apps/*" }]
+  }
+}"#,
+    );
+    project.create_file(
+        "package.json",
+        r#"{ "name": "acme", "private": true, "workspaces": ["apps/*", "libs/*"] }"#,
+    );
+    project.create_file("apps/web/package.json", r#"{ "name": "@acme/web" }"#);
+    project.create_file(
+        "apps/web/src/main.ts",
+        "import { Button } from '@acme/ui';\nexport const app = Button;\n",
+    );
+    project.create_file("libs/ui/package.json", r#"{ "name": "@acme/ui" }"#);
+    project.create_file(
+        "libs/ui/architect.json",
+        r#"{
+  "max_lines_per_function": 20,
+  "forbidden_imports": [{ "from": "src/", "to": "lodash" }],
+  "ignored_paths": ["storybook-static/"]
+}"#,
+    );
+    project.create_file(
+        "libs/ui/src/button.ts",
+        "import { app } from '@acme/web';\nimport { config } from '../../../apps/web/src/main';\nexport const Button = 1;\n",
+    );
+    project.create_file("scripts/release.ts", "export const release = 1;\n");
+    project
+}
+
+#[test]
+fn test_discovers_npm_workspace_packages() {
+    let project = create_npm_monorepo();
+    let ws = Workspace::discover(project.path()).unwrap().unwrap();
+
+    let packages: Vec<(&str, &str)> = ws
+        .packages
+        .iter()
+        .map(|p| (p.path.as_str(), p.name.as_str()))
+        .collect();
+    assert_eq!(
+        packages,
+        [("apps/web", "@acme/web"), ("libs/ui", "@acme/ui")]
+    );
+    assert!(ws.packages[1].config.is_some());
+}
+
+#[test]
+fn test_package_config_inherits_root() {
+    let project = create_npm_monorepo();
+    let ws = Workspace::discover(project.path()).unwrap().unwrap();
+
+    let ui = ws.load_package_config(&ws.packages[1]).unwrap();
+    assert_eq!(ui.max_lines, 20);
+    let rules: Vec<(&str, &str)> = ui
+        .forbidden_imports
+        .iter()
+        .map(|r| (r.from.as_str(), r.to.as_str()))
+        .collect();
+    assert_eq!(rules, [("src/domain/", "axios"), ("src/", "lodash")]);
+    assert_eq!(ui.ignored_paths, ["node_modules/", "storybook-static/"]);
+
+    let web = ws.load_package_config(&ws.packages[0]).unwrap();
+    assert_eq!(web.max_lines, 50);
+    assert_eq!(web.forbidden_imports.len(), 1);
+}
+
+#[test]
+fn test_rules_between_packages_and_per_package_scores() {
+    let project = create_npm_monorepo();
+    let ws = Workspace::discover(project.path()).unwrap().unwrap();
+    let result = workspace::analyze_workspace(Arc::new(ws), Severity::Info, false).unwrap();
+
+    let names: Vec<&str> = result.packages.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, [workspace::ROOT_PACKAGE, "@acme/web", "@acme/ui"]);
+
+    // libs may not import apps, by package name or by relative path
+    let ui = &result.packages[2].result;
+    assert_eq!(ui.violations.len(), 2);
+    assert!(ui
+        .violations
+        .iter()
+        .all(|cv| cv.violation.rule.to == "apps/*"));
+    // apps may import libs
+    assert!(result.packages[1].result.violations.is_empty());
+
+    assert_eq!(result.combined.files_analyzed, 3);
+    assert_eq!(result.combined.violations.len(), 2);
+    assert!(result
+        .packages
+        .iter()
+        .all(|p| p.result.health_score.is_some()));
+
+    let report: serde_json::Value = serde_json::from_str(&result.to_json()).unwrap();
+    assert_eq!(report["packages"].as_array().unwrap().len(), 3);
+    assert_eq!(report["packages"][2]["violations"], 2);
+}
+
+#[test]
+fn test_skipped_generated_code_stays_out_of_workspace_cycles() {
+    let project = create_npm_monorepo();
+    project.create_file(
+        "libs/ui/src/theme.ts",
+        "import { tokens } from './tokens';\nexport const theme = tokens;\n",
+    );
+    project.create_file(
+        "libs/ui/src/tokens.ts",
+        "// This file is auto-generated by style-dictionary. DO NOT EDIT.\nimport { theme } from './theme';\nexport const tokens = theme;\n",
+    );
+    let ws = Workspace::discover(project.path()).unwrap().unwrap();
+    let result = workspace::analyze_workspace(Arc::new(ws), Severity::Info, false).unwrap();
+
+    let ui = &result.packages[2];
+    assert_eq!(ui.result.generated_files.len(), 1);
+    assert!(ui.result.circular_dependencies.is_empty());
+    assert_eq!(result.combined.generated_files.len(), 1);
+}
+
+#[test]
+fn test_packages_keep_their_own_cache_and_dead_rules() {
+    let project = create_npm_monorepo();
+    let ws = Workspace::discover(project.path()).unwrap().unwrap();
+    let result = workspace::analyze_workspace(Arc::new(ws), Severity::Info, true).unwrap();
+
+    assert!(project.path().join("libs/ui/.architect-cache").is_dir());
+    assert!(project.path().join(".architect-cache").is_dir());
+    // Neither `src/domain/` nor `lodash` appear in the ui package
+    let dead: Vec<(&str, &str)> = result.packages[2]
+        .dead_rules
+        .iter()
+        .map(|d| (d.rule.from.as_str(), d.side.key()))
+        .collect();
+    assert_eq!(dead, [("src/domain/", "from"), ("src/", "to")]);
+
+    let uncached = create_npm_monorepo();
+    let ws = Workspace::discover(uncached.path()).unwrap().unwrap();
+    workspace::analyze_workspace(Arc::new(ws), Severity::Info, false).unwrap();
+    assert!(!uncached.path().join("libs/ui/.architect-cache").exists());
+}
+
+#[test]
+fn test_discovers_cargo_and_pnpm_workspaces() {
+    let project = TestProject::new();
+    project.create_config("Ninguno", 40, "");
+    project.create_file(
+        "Cargo.toml",
+        "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/experimental\"]\n",
+    );
+    project.create_file(
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"acme-core\"\n",
+    );
+    project.create_file(
+        "crates/experimental/Cargo.toml",
+        "[package]\nname = \"exp\"\n",
+    );
+    project.create_file(
+        "pnpm-workspace.yaml",
+        "packages:\n  - 'packages/*'\n  - '!packages/internal'\n",
+    );
+    project.create_file("packages/cli/package.json", r#"{ "name": "acme-cli" }"#);
+    project.create_file(
+        "packages/internal/package.json",
+        r#"{ "name": "internal" }"#,
+    );
+
+    let ws = Workspace::discover(project.path()).unwrap().unwrap();
+    let names: Vec<&str> = ws.packages.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["acme-core", "acme-cli"]);
+}
+
+#[test]
+fn test_single_project_is_not_a_workspace() {
+    let project = TestProject::new();
+    project.create_config("Ninguno", 40, "");
+    project.create_file("package.json", r#"{ "name": "app" }"#);
+    project.create_file("src/index.ts", "export const x = 1;\n");
+
+    assert!(Workspace::discover(project.path()).unwrap().is_none());
+}
+
+#[test]
+fn test_nested_config_needs_a_declared_workspace() {
+    let project = TestProject::new();
+    project.create_config("Ninguno", 40, "");
+    project.create_file("src/index.ts", "export const x = 1;\n");
+    project.create_file(
+        "tests/fixtures/demo/architect.json",
+        r#"{ "max_lines_per_function": 10, "architecture_pattern": "Ninguno", "forbidden_imports": [] }"#,
+    );
+    assert!(Workspace::discover(project.path()).unwrap().is_none());
+
+    project.create_file(
+        "architect.json",
+        r#"{ "max_lines_per_function": 40, "architecture_pattern": "Ninguno", "forbidden_imports": [], "workspace": {} }"#,
+    );
+    let ws = Workspace::discover(project.path()).unwrap().unwrap();
+    let paths: Vec<&str> = ws.packages.iter().map(|p| p.path.as_str()).collect();
+    assert_eq!(paths, ["tests/fixtures/demo"]);
+}